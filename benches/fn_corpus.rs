@@ -0,0 +1,59 @@
+/*!
+# Benchmark: Dirty Corpora
+*/
+
+use brunch::{
+	Bench,
+	benches,
+};
+use trimothy::{
+	bench_support::{
+		control_mix,
+		unicode_space_soup,
+		whitespace_runs,
+	},
+	Normalizer,
+	TrimNormal,
+	WsKind,
+	WsPolicy,
+};
+
+
+
+benches!(
+	Bench::new("&str::trim_and_normalize() (whitespace runs)")
+		.run({
+			let src = whitespace_runs(1000, 16);
+			move || src.as_str().trim_and_normalize().len()
+		}),
+
+	Bench::new("&[u8]::trim_and_normalize() (whitespace runs)")
+		.run({
+			let src = whitespace_runs(1000, 16);
+			move || src.as_bytes().trim_and_normalize().len()
+		}),
+
+	Bench::spacer(),
+
+	Bench::new("&str::trim_and_normalize() (unicode space soup)")
+		.run({
+			let src = unicode_space_soup(16_000);
+			move || src.as_str().trim_and_normalize().len()
+		}),
+
+	Bench::new("Normalizer::normalize() (unicode space soup)")
+		.run({
+			let src = unicode_space_soup(16_000);
+			let normalizer = Normalizer::new()
+				.with_policy(WsKind::ZeroWidth, WsPolicy::Remove);
+			move || normalizer.normalize(&src).len()
+		}),
+
+	Bench::spacer(),
+
+	Bench::new("&str::trim_and_normalize() (control mix)")
+		.run({
+			let src = control_mix(16_000);
+			move || src.as_str().trim_and_normalize().len()
+		}),
+);
@@ -0,0 +1,62 @@
+/*!
+# Benchmark: Patterns
+*/
+
+use brunch::{
+	Bench,
+	benches,
+};
+use std::collections::BTreeSet;
+use trimothy::{
+	ByteSet,
+	SortedSlice,
+	TrimSliceMatches,
+};
+
+
+
+const BYTES: &[u8] = b"  \t\n-_-Hello World!-_-\n\t  ";
+
+/// # Punctuation (Sorted).
+const PUNCT: &[u8] = b"!\"#$%&'()*+,-./:;<=>?@[\\]^_`{|}~";
+
+/// # Punctuation-Heavy Source.
+const PUNCT_BYTES: &[u8] = b"~~}}||{{``Hello World__^^]]\\[[@@";
+
+
+
+benches!(
+	Bench::new("&[u8]::trim_matches(&BTreeSet<u8>)")
+		.run_seeded(
+			BTreeSet::from([b' ', b'\t', b'\n', b'-', b'_']),
+			|set| BYTES.trim_matches(&set).len(),
+		),
+
+	Bench::new("&[u8]::trim_matches(ByteSet::from(&BTreeSet<u8>))")
+		.run_seeded(
+			BTreeSet::from([b' ', b'\t', b'\n', b'-', b'_']),
+			|set| BYTES.trim_matches(ByteSet::from(&set)).len(),
+		),
+
+	Bench::new("&[u8]::trim_matches(ByteSet)")
+		.run_seeded(
+			ByteSet::from_bytes(b" \t\n-_"),
+			|set| BYTES.trim_matches(set).len(),
+		),
+
+	Bench::spacer(),
+
+	Bench::new("&[u8]::trim_matches(&[u8; 5])")
+		.run(|| BYTES.trim_matches(b" \t\n-_").len()),
+
+	Bench::spacer(),
+
+	Bench::new("&[u8]::trim_matches(&[u8]) (32 punct)")
+		.run(|| PUNCT_BYTES.trim_matches(PUNCT).len()),
+
+	Bench::new("&[u8]::trim_matches(SortedSlice) (32 punct)")
+		.run_seeded(
+			SortedSlice::new(PUNCT).unwrap(),
+			|pat| PUNCT_BYTES.trim_matches(pat).len(),
+		),
+);
@@ -6,19 +6,58 @@ use brunch::{
 	Bench,
 	benches,
 };
-use trimothy::TrimMut;
+use trimothy::{
+	ShrinkPolicy,
+	TrimMatchesMut,
+	TrimMut,
+	TrimShrinkMut,
+};
 
 
 
 const BYTES: &[u8] = b"  \t\nHello World!\n\t  ";
 const STR: &str = "  \t\nHello World!\n\t  ";
 
+/// # Long Buffer.
+///
+/// Lots of padding on either end of lots of content.
+fn long_bytes() -> Vec<u8> {
+	let mut out = vec![b' '; 4096];
+	out.extend(b"Hello World! ".iter().copied().cycle().take(65_536));
+	out.resize(out.len() + 4096, b'\t');
+	out
+}
+
 
 
 benches!(
 	Bench::new("Vec<u8>::trim_mut()")
 		.run_seeded(BYTES.to_vec(), |mut v| v.trim_mut()),
 
+	Bench::new("Vec<u8>::trim_matches_mut(long)")
+		.run_seeded_with(long_bytes, |mut v| {
+			v.trim_matches_mut(|b: u8| b.is_ascii_whitespace());
+			v
+		}),
+
+	Bench::new("Vec<u8>::trim_mut_and_shrink(long, Never)")
+		.run_seeded_with(long_bytes, |mut v| {
+			v.trim_mut_and_shrink(ShrinkPolicy::Never);
+			v
+		}),
+
+	Bench::new("Vec<u8>::trim_mut_and_shrink(long, Waste(4096))")
+		.run_seeded_with(long_bytes, |mut v| {
+			v.trim_mut_and_shrink(ShrinkPolicy::Waste(4096));
+			v
+		}),
+
+	Bench::new("Vec<u8>::trim_mut_and_shrink(long, Always)")
+		.run_seeded_with(long_bytes, |mut v| {
+			v.trim_mut_and_shrink(ShrinkPolicy::Always);
+			v
+		}),
+
 	Bench::spacer(),
 
 	Bench::new("String::trim_mut()")
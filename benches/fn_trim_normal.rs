@@ -6,6 +6,7 @@ use brunch::{
 	Bench,
 	benches,
 };
+use std::borrow::Cow;
 use trimothy::{
 	TrimNormal,
 	TrimNormalBytes,
@@ -16,6 +17,7 @@ use trimothy::{
 
 const BYTES: &[u8] = b" H\r\nE\tL    L\tO  ";
 const STR: &str = " H\r\nE\u{2001}L  \u{3000}\u{205f}L\tO  ";
+const CLEAN: &str = "H E L L O";
 
 
 
@@ -26,6 +28,12 @@ benches!(
 	Bench::new("Iterator::<Item=u8>::trim_and_normalize()")
 		.run(|| BYTES.iter().copied().trim_and_normalize().collect::<Vec<_>>()),
 
+	Bench::new("Cow::<[u8]>::Owned::trim_and_normalize() (dirty)")
+		.run_seeded(Cow::<[u8]>::Owned(BYTES.to_vec()), TrimNormal::trim_and_normalize),
+
+	Bench::new("Cow::<[u8]>::Owned::trim_and_normalize() (clean)")
+		.run_seeded(Cow::<[u8]>::Owned(CLEAN.as_bytes().to_vec()), TrimNormal::trim_and_normalize),
+
 	Bench::spacer(),
 
 	Bench::new("&str::trim_and_normalize()")
@@ -33,4 +41,10 @@ benches!(
 
 	Bench::new("Iterator::<Item=char>::trim_and_normalize()")
 		.run(|| STR.chars().trim_and_normalize().collect::<String>()),
+
+	Bench::new("Cow::<str>::Owned::trim_and_normalize() (dirty)")
+		.run_seeded(Cow::<str>::Owned(STR.to_owned()), TrimNormal::trim_and_normalize),
+
+	Bench::new("Cow::<str>::Owned::trim_and_normalize() (clean)")
+		.run_seeded(Cow::<str>::Owned(CLEAN.to_owned()), TrimNormal::trim_and_normalize),
 );
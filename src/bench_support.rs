@@ -0,0 +1,194 @@
+/*!
+# Trimothy: Benchmark Support.
+
+This module provides generators for the representative "dirty" inputs used
+by trimothy's own benchmarks, so downstream users can measure their
+integrations against the same corpora.
+
+Each generator is deterministic — the same arguments always produce the same
+output — so results remain comparable from run to run, and machine to
+machine.
+
+This module is only available when the `bench_support` crate feature is
+enabled.
+
+## Examples
+
+```
+use trimothy::{
+    bench_support::unicode_space_soup,
+    TrimNormal,
+};
+
+let soup = unicode_space_soup(10_000);
+assert_eq!(soup.chars().count(), 10_000);
+assert_eq!(soup, unicode_space_soup(10_000)); // Same every time.
+
+let normal = soup.as_str().trim_and_normalize();
+assert!(normal.len() < soup.len());
+```
+*/
+
+use alloc::string::String;
+
+
+
+/// # ASCII Whitespace.
+const ASCII_WS: [char; 5] = ['\t', '\n', '\x0C', '\r', ' '];
+
+/// # Unicode Whitespace (and Friends).
+///
+/// All the non-ASCII [`char::is_whitespace`] characters, plus a few
+/// zero-width lookalikes that _aren't_ whitespace, to keep things honest.
+const UNICODE_WS: [char; 24] = [
+	'\u{85}', '\u{A0}', '\u{1680}', '\u{2000}', '\u{2001}', '\u{2002}',
+	'\u{2003}', '\u{2004}', '\u{2005}', '\u{2006}', '\u{2007}', '\u{2008}',
+	'\u{2009}', '\u{200A}', '\u{2028}', '\u{2029}', '\u{202F}', '\u{205F}',
+	'\u{3000}', '\u{180E}', '\u{200B}', '\u{200C}', '\u{200D}', '\u{FEFF}',
+];
+
+/// # Control Characters.
+const CONTROLS: [char; 10] = [
+	'\0', '\x07', '\x08', '\t', '\n', '\r', '\x1B', '\x7F', '\u{85}', '\u{9F}',
+];
+
+/// # Word Characters.
+const WORD: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+
+/// # Seed.
+const SEED: u32 = 0x7141_07E1;
+
+
+
+#[must_use]
+/// # Long Whitespace Runs.
+///
+/// Return `words` short ASCII words separated — and surrounded — by runs of
+/// `run` mixed ASCII whitespace characters.
+///
+/// This is the worst case for run-collapsing, and a good one for edge
+/// trimming.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::bench_support::whitespace_runs;
+///
+/// let s = whitespace_runs(3, 8);
+/// assert_eq!(s.split_whitespace().count(), 3);
+/// assert!(s.starts_with(char::is_whitespace));
+/// assert!(s.ends_with(char::is_whitespace));
+/// ```
+pub fn whitespace_runs(words: usize, run: usize) -> String {
+	let mut rng = Rng::new();
+	let mut out = String::new();
+	for _ in 0..words {
+		for _ in 0..run { out.push(rng.pick(&ASCII_WS)); }
+		rng.word(&mut out);
+	}
+	for _ in 0..run { out.push(rng.pick(&ASCII_WS)); }
+	out
+}
+
+#[must_use]
+/// # Unicode Space Soup.
+///
+/// Return a string of `len` characters, roughly a third of which are drawn
+/// from the full range of Unicode whitespace — plus a few zero-width
+/// non-whitespace lookalikes — with the rest being ASCII word characters.
+///
+/// This exercises the slow (multi-byte) paths of string normalization.
+pub fn unicode_space_soup(len: usize) -> String {
+	let mut rng = Rng::new();
+	(0..len).map(|_|
+		if rng.next() % 3 == 0 { rng.pick(&UNICODE_WS) }
+		else { char::from(rng.pick(WORD)) }
+	).collect()
+}
+
+#[must_use]
+/// # Mixed Control Characters.
+///
+/// Return a string of `len` characters, roughly a quarter of which are
+/// control characters — some whitespace, some not — with the rest being
+/// ASCII word characters and spaces.
+///
+/// This is representative of terminal output and other "binary-ish" text.
+pub fn control_mix(len: usize) -> String {
+	let mut rng = Rng::new();
+	(0..len).map(|_| match rng.next() % 8 {
+		0 | 1 => rng.pick(&CONTROLS),
+		2 => ' ',
+		_ => char::from(rng.pick(WORD)),
+	}).collect()
+}
+
+
+
+/// # Random Number Generator.
+///
+/// A simple, seeded `xorshift32` generator; more than random enough for
+/// our purposes.
+struct Rng(u32);
+
+impl Rng {
+	/// # New.
+	const fn new() -> Self { Self(SEED) }
+
+	/// # Next.
+	fn next(&mut self) -> usize {
+		self.0 ^= self.0 << 13;
+		self.0 ^= self.0 >> 17;
+		self.0 ^= self.0 << 5;
+		self.0 as usize
+	}
+
+	/// # Pick.
+	fn pick<T: Copy>(&mut self, set: &[T]) -> T { set[self.next() % set.len()] }
+
+	/// # Word.
+	///
+	/// Push a random three-to-eight-character word.
+	fn word(&mut self, out: &mut String) {
+		let len = 3 + self.next() % 6;
+		for _ in 0..len { out.push(char::from(self.pick(WORD))); }
+	}
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::TrimNormal;
+
+	#[test]
+	fn t_bench_support() {
+		// Runs.
+		let s = whitespace_runs(100, 16);
+		assert_eq!(s.split_whitespace().count(), 100);
+		assert_eq!(s, whitespace_runs(100, 16));
+		assert_eq!(s.trim_and_normalize().split(' ').count(), 100);
+		assert!(whitespace_runs(0, 0).is_empty());
+
+		// Soup.
+		let s = unicode_space_soup(1000);
+		assert_eq!(s.chars().count(), 1000);
+		assert!(s.chars().any(char::is_whitespace));
+		assert!(s.chars().any(|c| c.is_ascii_alphanumeric()));
+		assert_eq!(s, unicode_space_soup(1000));
+
+		// Controls.
+		let s = control_mix(1000);
+		assert_eq!(s.chars().count(), 1000);
+		assert!(s.chars().any(|c| c.is_control() && ! c.is_whitespace()));
+		assert!(s.chars().any(|c| c.is_control() && c.is_whitespace()));
+		assert_eq!(s, control_mix(1000));
+
+		// The tables should be what they say they are.
+		assert!(ASCII_WS.iter().all(char::is_ascii_whitespace));
+		assert!(UNICODE_WS[..19].iter().all(|c| c.is_whitespace() && ! c.is_ascii()));
+		assert!(UNICODE_WS[19..].iter().all(|c| ! c.is_whitespace()));
+		assert!(CONTROLS.iter().all(|c| c.is_control()));
+	}
+}
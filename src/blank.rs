@@ -0,0 +1,195 @@
+/*!
+# Trimothy: Blank Detection.
+*/
+
+use alloc::{
+	borrow::Cow,
+	boxed::Box,
+	string::String,
+	vec::Vec,
+};
+use crate::pattern::MatchPattern;
+
+
+
+/// # Blank Detection.
+///
+/// The [`IsBlank`] trait adds cheap, single-pass whitespace-only detection
+/// to string and byte slices — owned, borrowed, or boxed — answering the
+/// age-old "is this empty after trimming?" question without actually
+/// trimming (or allocating) anything.
+///
+/// | Method | Description |
+/// | ------ | ----------- |
+/// | `is_blank` | Empty or entirely whitespace? |
+/// | `is_ascii_blank` | Empty or entirely ASCII whitespace? |
+/// | `is_blank_matches` | Empty or entirely matching an arbitrary pattern? |
+///
+/// In keeping with the rest of the library, "whitespace" here means
+/// [`char::is_whitespace`] for string sources, and [`u8::is_ascii_whitespace`]
+/// for byte sources. (For byte sources, the two methods are equivalent.)
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::IsBlank;
+///
+/// assert!("".is_blank());
+/// assert!(" \t\r\n".is_blank());
+/// assert!("\u{3000}".is_blank());
+/// assert!(! "\u{3000}".is_ascii_blank());
+/// assert!(! " Hello ".is_blank());
+///
+/// assert!(b" \t\r\n".is_blank());
+/// assert!(! b" Hello ".to_vec().is_blank());
+///
+/// // Arbitrary patterns work too.
+/// assert!("----".is_blank_matches('-'));
+/// assert!(b"000 000".is_blank_matches(b"0 ".as_slice()));
+/// ```
+pub trait IsBlank {
+	/// # Matches Type.
+	///
+	/// This is the "unit" type of the collection, e.g. `char` for strings,
+	/// `u8` for bytes.
+	type MatchUnit: Copy + Eq + Ord + Sized;
+
+	/// # Is Blank?
+	///
+	/// Returns `true` if the source is empty or contains only whitespace.
+	fn is_blank(&self) -> bool;
+
+	/// # Is ASCII Blank?
+	///
+	/// Returns `true` if the source is empty or contains only ASCII
+	/// whitespace.
+	fn is_ascii_blank(&self) -> bool;
+
+	/// # Is Blank (Matches)?
+	///
+	/// Returns `true` if the source is empty or contains only units matching
+	/// the provided pattern, which can be:
+	/// * A single `char`/`u8`;
+	/// * An array or slice of `char`/`u8`;
+	/// * A `&BTreeSet<char>`/`&BTreeSet<u8>`;
+	/// * A callback with the signature `Fn(char) -> bool`/`Fn(u8) -> bool`;
+	fn is_blank_matches<P: MatchPattern<Self::MatchUnit>>(&self, pat: P) -> bool;
+}
+
+impl IsBlank for str {
+	type MatchUnit = char;
+
+	#[inline]
+	fn is_blank(&self) -> bool { self.chars().all(char::is_whitespace) }
+
+	#[inline]
+	fn is_ascii_blank(&self) -> bool { self.as_bytes().is_ascii_blank() }
+
+	#[inline]
+	fn is_blank_matches<P: MatchPattern<char>>(&self, pat: P) -> bool {
+		self.chars().all(|c| pat.is_match(c))
+	}
+}
+
+impl IsBlank for [u8] {
+	type MatchUnit = u8;
+
+	#[inline]
+	fn is_blank(&self) -> bool { self.is_ascii_blank() }
+
+	#[inline]
+	fn is_ascii_blank(&self) -> bool { self.iter().all(u8::is_ascii_whitespace) }
+
+	#[inline]
+	fn is_blank_matches<P: MatchPattern<u8>>(&self, pat: P) -> bool {
+		self.iter().all(|&b| pat.is_match(b))
+	}
+}
+
+/// # Helper: Passthrough Implementations.
+macro_rules! blank {
+	($unit:ty: $($ty:ty),+ $(,)?) => ($(
+		impl IsBlank for $ty {
+			type MatchUnit = $unit;
+
+			#[inline]
+			fn is_blank(&self) -> bool { (**self).is_blank() }
+
+			#[inline]
+			fn is_ascii_blank(&self) -> bool { (**self).is_ascii_blank() }
+
+			#[inline]
+			fn is_blank_matches<P: MatchPattern<$unit>>(&self, pat: P) -> bool {
+				(**self).is_blank_matches(pat)
+			}
+		}
+	)+);
+}
+
+blank!(char: String, Box<str>, Cow<'_, str>);
+blank!(u8: Vec<u8>, Box<[u8]>, Cow<'_, [u8]>);
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn t_is_blank() {
+		for (raw, blank, ascii) in [
+			("", true, true),
+			(" ", true, true),
+			(" \t\r\n\x0C", true, true),
+			("\u{0B}", true, false), // Vertical tab isn't ASCII whitespace.
+			("\u{3000}", true, false),
+			(" \u{2003} ", true, false),
+			("a", false, false),
+			("  a  ", false, false),
+			("\0", false, false),
+		] {
+			assert_eq!(raw.is_blank(), blank, "{raw:?}");
+			assert_eq!(raw.is_blank(), raw.trim().is_empty(), "{raw:?}");
+			assert_eq!(raw.is_ascii_blank(), ascii, "{raw:?}");
+			assert_eq!(String::from(raw).is_blank(), blank, "{raw:?}");
+			assert_eq!(Box::<str>::from(raw).is_ascii_blank(), ascii, "{raw:?}");
+			assert_eq!(Cow::Borrowed(raw).is_blank(), blank, "{raw:?}");
+
+			let raw = raw.as_bytes();
+			assert_eq!(raw.is_blank(), ascii, "{raw:?}");
+			assert_eq!(raw.is_ascii_blank(), ascii, "{raw:?}");
+			assert_eq!(raw.to_vec().is_blank(), ascii, "{raw:?}");
+			assert_eq!(Box::<[u8]>::from(raw).is_blank(), ascii, "{raw:?}");
+			assert_eq!(Cow::Borrowed(raw).is_ascii_blank(), ascii, "{raw:?}");
+		}
+	}
+
+	#[test]
+	fn t_is_blank_matches() {
+		for (raw, pat, expected) in [
+			("", &['-'][..], true),
+			("", &[], true),
+			(" ", &[], false),
+			("---", &['-'], true),
+			("-_-", &['-'], false),
+			("-_-", &['-', '_'], true),
+			("00.00", &['0', '.'], true),
+			("00.01", &['0', '.'], false),
+		] {
+			assert_eq!(raw.is_blank_matches(pat), expected, "{raw:?}");
+			assert_eq!(String::from(raw).is_blank_matches(pat), expected, "{raw:?}");
+
+			let pat: Vec<u8> = pat.iter().map(|&c| u8::try_from(c).unwrap()).collect();
+			assert_eq!(raw.as_bytes().is_blank_matches(pat.as_slice()), expected, "{raw:?}");
+			assert_eq!(
+				Cow::Borrowed(raw.as_bytes()).is_blank_matches(pat.as_slice()),
+				expected,
+				"{raw:?}",
+			);
+		}
+
+		// Callbacks.
+		assert!("\u{3000}Björk".is_blank_matches(|c: char| c.is_whitespace() || c.is_alphabetic()));
+		assert!(! "Björk!".is_blank_matches(char::is_alphabetic));
+	}
+}
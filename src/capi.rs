@@ -0,0 +1,266 @@
+/*!
+# Trimothy: C API.
+
+This module exposes a handful of `extern "C"` functions so non-Rust code
+can reuse the same trimming semantics.
+
+Buffer ownership never crosses the boundary: trimming returns a range
+within the caller's buffer, and normalization works in place, shrinking
+the caller's buffer rather than allocating a new one. The byte-based (ASCII
+whitespace) variants are used throughout, so valid UTF-8 stays valid UTF-8.
+
+Because Cargo crate types can't be feature-dependent, C consumers should
+build a static or dynamic library explicitly, e.g.
+`cargo rustc --release --features capi --crate-type staticlib`, and declare
+the functions themselves:
+
+```c
+typedef struct { size_t start; size_t len; } TrimothyRange;
+
+TrimothyRange trimothy_trim(const uint8_t *src, size_t len);
+TrimothyRange trimothy_trim_start(const uint8_t *src, size_t len);
+TrimothyRange trimothy_trim_end(const uint8_t *src, size_t len);
+size_t trimothy_normalize(uint8_t *buf, size_t len);
+bool trimothy_normalized_eq(const uint8_t *a, size_t a_len, const uint8_t *b, size_t b_len);
+```
+*/
+
+#![allow(unsafe_code, reason = "FFI requires it.")]
+
+use crate::NormalizedCompare;
+
+
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, PartialEq)]
+/// # Trimmed Range (C).
+///
+/// The result of the C trimming functions: the offset and length of the
+/// trimmed portion within the original buffer.
+pub struct TrimothyRange {
+	/// # Start Offset.
+	pub start: usize,
+
+	/// # Length.
+	pub len: usize,
+}
+
+impl TrimothyRange {
+	/// # From Trimmed Start.
+	///
+	/// Compute the range of `sub` — a suffix of `src` with some portion of
+	/// its end possibly removed — within `src`.
+	const fn new(src: &[u8], start: &[u8], sub: &[u8]) -> Self {
+		Self {
+			start: src.len() - start.len(),
+			len: sub.len(),
+		}
+	}
+}
+
+
+
+#[no_mangle]
+#[must_use]
+/// # Trim (C).
+///
+/// Return the range of `src` remaining after trimming leading and trailing
+/// ASCII whitespace.
+///
+/// This function is only available when the `capi` crate feature is
+/// enabled.
+///
+/// ## Safety
+///
+/// `src` must be null or point to `len` readable bytes. (Null pointers are
+/// treated as empty.)
+pub unsafe extern "C" fn trimothy_trim(src: *const u8, len: usize) -> TrimothyRange {
+	// Safety: the caller is responsible for the pointer/length.
+	let src = unsafe { slice(src, len) };
+	let start = src.trim_ascii_start();
+	TrimothyRange::new(src, start, start.trim_ascii_end())
+}
+
+#[no_mangle]
+#[must_use]
+/// # Trim Start (C).
+///
+/// Return the range of `src` remaining after trimming leading ASCII
+/// whitespace.
+///
+/// This function is only available when the `capi` crate feature is
+/// enabled.
+///
+/// ## Safety
+///
+/// `src` must be null or point to `len` readable bytes. (Null pointers are
+/// treated as empty.)
+pub unsafe extern "C" fn trimothy_trim_start(src: *const u8, len: usize) -> TrimothyRange {
+	// Safety: the caller is responsible for the pointer/length.
+	let src = unsafe { slice(src, len) };
+	let start = src.trim_ascii_start();
+	TrimothyRange::new(src, start, start)
+}
+
+#[no_mangle]
+#[must_use]
+/// # Trim End (C).
+///
+/// Return the range of `src` remaining after trimming trailing ASCII
+/// whitespace.
+///
+/// This function is only available when the `capi` crate feature is
+/// enabled.
+///
+/// ## Safety
+///
+/// `src` must be null or point to `len` readable bytes. (Null pointers are
+/// treated as empty.)
+pub unsafe extern "C" fn trimothy_trim_end(src: *const u8, len: usize) -> TrimothyRange {
+	// Safety: the caller is responsible for the pointer/length.
+	let src = unsafe { slice(src, len) };
+	TrimothyRange::new(src, src, src.trim_ascii_end())
+}
+
+#[no_mangle]
+#[must_use]
+/// # Normalize (C).
+///
+/// Trim the leading/trailing ASCII whitespace from `buf`, and compact spans
+/// of inner ASCII whitespace to a single horizontal space, in place.
+///
+/// The normalized value is moved to the start of the buffer, and its new
+/// length returned. (The normalized value is never longer than the
+/// original.)
+///
+/// This function is only available when the `capi` crate feature is
+/// enabled.
+///
+/// ## Safety
+///
+/// `buf` must be null or point to `len` readable and writable bytes not
+/// aliased elsewhere for the duration of the call. (Null pointers are
+/// treated as empty.)
+pub unsafe extern "C" fn trimothy_normalize(buf: *mut u8, len: usize) -> usize {
+	if buf.is_null() || len == 0 { return 0; }
+	// Safety: the caller is responsible for the pointer/length.
+	let buf = unsafe { core::slice::from_raw_parts_mut(buf, len) };
+
+	// Same as the Vec<u8> implementation, minus the Vec.
+	let mut out = 0;
+	let mut ws = true;
+	for idx in 0..buf.len() {
+		let b = buf[idx];
+		if b.is_ascii_whitespace() {
+			if ws { continue; }
+			ws = true;
+			buf[out] = b' ';
+		}
+		else {
+			ws = false;
+			buf[out] = b;
+		}
+		out += 1;
+	}
+
+	// Drop the trailing space, if any.
+	if ws && out != 0 { out -= 1; }
+	out
+}
+
+#[no_mangle]
+#[must_use]
+/// # Normalized Equality (C).
+///
+/// Returns `true` if the two buffers are equal after trimming and
+/// normalizing their ASCII whitespace.
+///
+/// This function is only available when the `capi` crate feature is
+/// enabled.
+///
+/// ## Safety
+///
+/// `a` and `b` must each be null or point to `a_len` and `b_len` readable
+/// bytes respectively. (Null pointers are treated as empty.)
+pub unsafe extern "C" fn trimothy_normalized_eq(
+	a: *const u8,
+	a_len: usize,
+	b: *const u8,
+	b_len: usize,
+) -> bool {
+	// Safety: the caller is responsible for the pointers/lengths.
+	let (a, b) = unsafe { (slice(a, a_len), slice(b, b_len)) };
+	a.normalized_eq(b)
+}
+
+
+
+/// # Slice From Raw Parts.
+///
+/// Null pointers are treated as empty slices.
+///
+/// ## Safety
+///
+/// `src` must be null or point to `len` readable bytes.
+unsafe fn slice<'a>(src: *const u8, len: usize) -> &'a [u8] {
+	if src.is_null() { &[] }
+	else {
+		// Safety: the caller is responsible for the pointer/length.
+		unsafe { core::slice::from_raw_parts(src, len) }
+	}
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use alloc::vec::Vec;
+	use crate::TrimNormal;
+
+	#[test]
+	fn t_capi() {
+		for raw in [
+			"", " ", " \t\n", "Hello", "  Hello  ", "\tHello\r\n\r\nWorld! ",
+			" Björk  Guðmundsdóttir ",
+		] {
+			let src = raw.as_bytes();
+
+			// Trimming.
+			// Safety: the pointers and lengths come from a valid slice.
+			let ranges = unsafe {[
+				trimothy_trim(src.as_ptr(), src.len()),
+				trimothy_trim_start(src.as_ptr(), src.len()),
+				trimothy_trim_end(src.as_ptr(), src.len()),
+			]};
+			for (range, expected) in ranges.into_iter().zip([
+				src.trim_ascii(),
+				src.trim_ascii_start(),
+				src.trim_ascii_end(),
+			]) {
+				assert_eq!(&src[range.start..range.start + range.len], expected, "{raw:?}");
+			}
+
+			// Normalization.
+			let expected = src.trim_and_normalize();
+			let mut buf: Vec<u8> = src.to_vec();
+			// Safety: the pointer and length come from a valid Vec.
+			let len = unsafe { trimothy_normalize(buf.as_mut_ptr(), buf.len()) };
+			assert_eq!(&buf[..len], &*expected, "{raw:?}");
+
+			// Comparison.
+			// Safety: the pointers and lengths come from valid slices.
+			assert!(unsafe {
+				trimothy_normalized_eq(src.as_ptr(), src.len(), expected.as_ptr(), expected.len())
+			});
+		}
+
+		// Nulls.
+		// Safety: null pointers are explicitly supported.
+		unsafe {
+			assert_eq!(trimothy_trim(core::ptr::null(), 5), TrimothyRange::default());
+			assert_eq!(trimothy_normalize(core::ptr::null_mut(), 5), 0);
+			assert!(trimothy_normalized_eq(core::ptr::null(), 0, b" ".as_ptr(), 1));
+		}
+	}
+}
@@ -0,0 +1,258 @@
+/*!
+# Trimothy: Collections.
+*/
+
+use alloc::{
+	collections::{
+		BTreeMap,
+		VecDeque,
+	},
+	string::String,
+	vec::Vec,
+};
+use crate::{
+	TrimMut,
+	TrimNormal,
+};
+
+
+
+/// # Bulk Trimming (Sequences).
+///
+/// This trait brings in-place bulk trimming to collections of owned strings
+/// and byte vectors — `Vec<String>`, `Vec<Vec<u8>>`, `VecDeque<String>`, and
+/// `VecDeque<Vec<u8>>` — optionally dropping any entries left empty
+/// afterwards.
+///
+/// | Method | Description |
+/// | ------ | ----------- |
+/// | `trim_all_mut` | Trim each entry (mutably). |
+/// | `normalize_all_mut` | Trim and normalize each entry (mutably). |
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::TrimAllMut;
+///
+/// let mut v = vec![
+///     String::from(" Hello "),
+///     String::from("\t"),
+///     String::from("\nWorld!"),
+/// ];
+///
+/// // Keep the empties.
+/// v.trim_all_mut(false);
+/// assert_eq!(v, ["Hello", "", "World!"]);
+///
+/// // Drop the empties.
+/// v.trim_all_mut(true);
+/// assert_eq!(v, ["Hello", "World!"]);
+/// ```
+pub trait TrimAllMut {
+	/// # Trim All Mut.
+	///
+	/// Trim the leading/trailing whitespace from each entry, in place,
+	/// removing any that wind up empty if `drop_empty` is `true`.
+	fn trim_all_mut(&mut self, drop_empty: bool);
+
+	/// # Normalize All Mut.
+	///
+	/// Trim the leading/trailing whitespace from each entry and compact spans
+	/// of inner whitespace to a single horizontal space, in place, removing
+	/// any that wind up empty if `drop_empty` is `true`.
+	fn normalize_all_mut(&mut self, drop_empty: bool);
+}
+
+/// # Bulk Trimming (Maps).
+///
+/// This trait brings in-place bulk trimming to the _values_ of
+/// `BTreeMap<K, String>` and `BTreeMap<K, Vec<u8>>`, optionally dropping any
+/// entries left empty afterwards.
+///
+/// | Method | Description |
+/// | ------ | ----------- |
+/// | `trim_values_mut` | Trim each value (mutably). |
+/// | `normalize_values_mut` | Trim and normalize each value (mutably). |
+///
+/// ## Examples
+///
+/// ```
+/// use std::collections::BTreeMap;
+/// use trimothy::TrimValuesMut;
+///
+/// let mut map = BTreeMap::from([
+///     ("a", String::from(" Hello\n\tWorld! ")),
+///     ("b", String::from("\r\n")),
+/// ]);
+///
+/// map.normalize_values_mut(true);
+/// assert_eq!(map.len(), 1);
+/// assert_eq!(map["a"], "Hello World!");
+/// ```
+pub trait TrimValuesMut {
+	/// # Trim Values Mut.
+	///
+	/// Trim the leading/trailing whitespace from each value, in place,
+	/// removing any entries that wind up empty if `drop_empty` is `true`.
+	fn trim_values_mut(&mut self, drop_empty: bool);
+
+	/// # Normalize Values Mut.
+	///
+	/// Trim the leading/trailing whitespace from each value and compact spans
+	/// of inner whitespace to a single horizontal space, in place, removing
+	/// any entries that wind up empty if `drop_empty` is `true`.
+	fn normalize_values_mut(&mut self, drop_empty: bool);
+}
+
+
+
+/// # Helper: Sequence Implementations.
+macro_rules! seq {
+	($($outer:ident<$inner:ty>),+ $(,)?) => ($(
+		impl TrimAllMut for $outer<$inner> {
+			/// # Trim All Mut.
+			///
+			/// Trim the leading/trailing whitespace from each entry, in
+			/// place, removing any that wind up empty if `drop_empty` is
+			/// `true`.
+			fn trim_all_mut(&mut self, drop_empty: bool) {
+				if drop_empty {
+					self.retain_mut(|v| {
+						v.trim_mut();
+						! v.is_empty()
+					});
+				}
+				else {
+					for v in self { v.trim_mut(); }
+				}
+			}
+
+			/// # Normalize All Mut.
+			///
+			/// Trim the leading/trailing whitespace from each entry and
+			/// compact spans of inner whitespace to a single horizontal
+			/// space, in place, removing any that wind up empty if
+			/// `drop_empty` is `true`.
+			fn normalize_all_mut(&mut self, drop_empty: bool) {
+				if drop_empty {
+					self.retain_mut(|v| ! v.trim_and_normalize().is_empty());
+				}
+				else {
+					for v in self { v.trim_and_normalize(); }
+				}
+			}
+		}
+	)+);
+}
+
+seq!(Vec<String>, Vec<Vec<u8>>, VecDeque<String>, VecDeque<Vec<u8>>);
+
+/// # Helper: Map Implementations.
+macro_rules! map {
+	($($inner:ty),+ $(,)?) => ($(
+		impl<K: Ord> TrimValuesMut for BTreeMap<K, $inner> {
+			/// # Trim Values Mut.
+			///
+			/// Trim the leading/trailing whitespace from each value, in
+			/// place, removing any entries that wind up empty if `drop_empty`
+			/// is `true`.
+			fn trim_values_mut(&mut self, drop_empty: bool) {
+				if drop_empty {
+					self.retain(|_, v| {
+						v.trim_mut();
+						! v.is_empty()
+					});
+				}
+				else {
+					for v in self.values_mut() { v.trim_mut(); }
+				}
+			}
+
+			/// # Normalize Values Mut.
+			///
+			/// Trim the leading/trailing whitespace from each value and
+			/// compact spans of inner whitespace to a single horizontal
+			/// space, in place, removing any entries that wind up empty if
+			/// `drop_empty` is `true`.
+			fn normalize_values_mut(&mut self, drop_empty: bool) {
+				if drop_empty {
+					self.retain(|_, v| ! v.trim_and_normalize().is_empty());
+				}
+				else {
+					for v in self.values_mut() { v.trim_and_normalize(); }
+				}
+			}
+		}
+	)+);
+}
+
+map!(String, Vec<u8>);
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	/// # Raw Strings.
+	const RAW: [&str; 5] = ["", " Hello ", "\t\n", " Hello \n World ", "World"];
+
+	/// # Trimmed Strings.
+	const TRIMMED: [&str; 5] = ["", "Hello", "", "Hello \n World", "World"];
+
+	/// # Normalized Strings.
+	const NORMALIZED: [&str; 5] = ["", "Hello", "", "Hello World", "World"];
+
+	#[test]
+	fn t_trim_all_mut() {
+		for drop_empty in [false, true] {
+			let expected: Vec<&str> = TRIMMED.into_iter()
+				.filter(|v| ! drop_empty || ! v.is_empty())
+				.collect();
+
+			let mut v: Vec<String> = RAW.into_iter().map(String::from).collect();
+			v.trim_all_mut(drop_empty);
+			assert_eq!(v, expected);
+
+			let mut v: VecDeque<String> = RAW.into_iter().map(String::from).collect();
+			v.trim_all_mut(drop_empty);
+			assert_eq!(v, expected);
+
+			let mut v: Vec<Vec<u8>> = RAW.into_iter().map(|v| v.as_bytes().to_vec()).collect();
+			v.trim_all_mut(drop_empty);
+			assert!(v.iter().map(Vec::as_slice).eq(expected.iter().map(|v| v.as_bytes())));
+
+			let mut v: BTreeMap<usize, String> = RAW.into_iter()
+				.map(String::from)
+				.enumerate()
+				.collect();
+			v.trim_values_mut(drop_empty);
+			assert!(v.values().eq(expected.iter()));
+			assert_eq!(v.contains_key(&2), ! drop_empty);
+		}
+	}
+
+	#[test]
+	fn t_normalize_all_mut() {
+		for drop_empty in [false, true] {
+			let expected: Vec<&str> = NORMALIZED.into_iter()
+				.filter(|v| ! drop_empty || ! v.is_empty())
+				.collect();
+
+			let mut v: Vec<String> = RAW.into_iter().map(String::from).collect();
+			v.normalize_all_mut(drop_empty);
+			assert_eq!(v, expected);
+
+			let mut v: VecDeque<Vec<u8>> = RAW.into_iter().map(|v| v.as_bytes().to_vec()).collect();
+			v.normalize_all_mut(drop_empty);
+			assert!(v.iter().map(Vec::as_slice).eq(expected.iter().map(|v| v.as_bytes())));
+
+			let mut v: BTreeMap<usize, Vec<u8>> = RAW.into_iter()
+				.map(|v| v.as_bytes().to_vec())
+				.enumerate()
+				.collect();
+			v.normalize_values_mut(drop_empty);
+			assert!(v.values().map(Vec::as_slice).eq(expected.iter().map(|v| v.as_bytes())));
+		}
+	}
+}
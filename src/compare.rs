@@ -0,0 +1,978 @@
+/*!
+# Trimothy: Normalized Comparison.
+*/
+
+use core::{
+	cmp::Ordering,
+	error::Error,
+	fmt,
+	hash::Hasher,
+	iter::FusedIterator,
+	ops::Range,
+};
+use crate::{
+	TrimNormalBytes,
+	TrimNormalChars,
+};
+
+
+
+/// # Normalized Comparison.
+///
+/// This trait adds whitespace-insensitive equality and hashing to string and
+/// byte slices, treating each as if it had been passed through
+/// [`TrimNormal`](crate::TrimNormal), but without allocating any normalized
+/// copies.
+///
+/// | Method | Description |
+/// | ------ | ----------- |
+/// | `normalized_eq` | Compare normalized forms for equality. |
+/// | `normalized_eq_ignore_ascii_case` | Compare normalized forms for equality, ignoring ASCII case. |
+/// | `hash_normalized` | Feed the normalized form to a `Hasher`. |
+///
+/// The two are consistent with one another — values that are
+/// `normalized_eq` always hash the same — making them a good foundation for
+/// content-addressed deduplication of messy text.
+///
+/// For streaming hashers like the standard library's `DefaultHasher`, the
+/// result is also the same as hashing the normalized string directly.
+///
+/// As with `TrimNormal`, "whitespace" here means [`char::is_whitespace`]
+/// for string sources, and [`u8::is_ascii_whitespace`] for byte sources.
+///
+/// ## Examples
+///
+/// ```
+/// use std::{
+///     collections::hash_map::DefaultHasher,
+///     hash::{Hash, Hasher},
+/// };
+/// use trimothy::NormalizedCompare;
+///
+/// let a = "  Hello\n\tWorld!\n";
+/// let b = "Hello World!";
+/// assert!(a.normalized_eq(b));
+///
+/// // Hash the normalized form.
+/// let mut h1 = DefaultHasher::new();
+/// a.hash_normalized(&mut h1);
+///
+/// // This is the same as hashing the normalized string itself.
+/// let mut h2 = DefaultHasher::new();
+/// b.hash(&mut h2);
+///
+/// assert_eq!(h1.finish(), h2.finish());
+/// ```
+pub trait NormalizedCompare {
+	/// # Normalized Equality.
+	///
+	/// Returns `true` if the normalized forms of `self` and `other` are
+	/// equal.
+	fn normalized_eq(&self, other: &Self) -> bool;
+
+	/// # Normalized Equality (Ignoring ASCII Case).
+	///
+	/// Returns `true` if the normalized forms of `self` and `other` are
+	/// equal, ignoring any differences in ASCII case.
+	fn normalized_eq_ignore_ascii_case(&self, other: &Self) -> bool;
+
+	/// # Hash Normalized.
+	///
+	/// Feed the normalized form of the source into the hasher, word by
+	/// word, followed by the same `0xff` terminator used by [`str`].
+	fn hash_normalized<H: Hasher>(&self, state: &mut H);
+}
+
+impl NormalizedCompare for str {
+	#[inline]
+	/// # Normalized Equality.
+	///
+	/// Returns `true` if the normalized forms of `self` and `other` are
+	/// equal.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::NormalizedCompare;
+	///
+	/// assert!("Björk\u{3000} Guðmundsdóttir".normalized_eq(" Björk Guðmundsdóttir "));
+	/// assert!(! "Björk".normalized_eq("Bj örk"));
+	/// ```
+	fn normalized_eq(&self, other: &Self) -> bool {
+		self.split_whitespace().eq(other.split_whitespace())
+	}
+
+	#[inline]
+	/// # Normalized Equality (Ignoring ASCII Case).
+	///
+	/// Returns `true` if the normalized forms of `self` and `other` are
+	/// equal, ignoring any differences in ASCII case.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::NormalizedCompare;
+	///
+	/// assert!(" Jane\tDOE ".normalized_eq_ignore_ascii_case("jane doe"));
+	///
+	/// // Non-ASCII case differences still matter.
+	/// assert!(! "BJÖRK".normalized_eq_ignore_ascii_case("björk"));
+	/// ```
+	fn normalized_eq_ignore_ascii_case(&self, other: &Self) -> bool {
+		self.chars().trim_and_normalize().map(|c| c.to_ascii_lowercase())
+			.eq(other.chars().trim_and_normalize().map(|c| c.to_ascii_lowercase()))
+	}
+
+	/// # Hash Normalized.
+	///
+	/// Feed the normalized form of the string into the hasher, word by word,
+	/// followed by the same `0xff` terminator used by [`str`].
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use std::collections::hash_map::DefaultHasher;
+	/// use std::hash::Hasher;
+	/// use trimothy::NormalizedCompare;
+	///
+	/// let mut h1 = DefaultHasher::new();
+	/// "Hello\u{2003}World".hash_normalized(&mut h1);
+	///
+	/// let mut h2 = DefaultHasher::new();
+	/// " Hello\n\nWorld ".hash_normalized(&mut h2);
+	///
+	/// assert_eq!(h1.finish(), h2.finish());
+	/// ```
+	fn hash_normalized<H: Hasher>(&self, state: &mut H) {
+		let mut iter = self.split_whitespace();
+		if let Some(next) = iter.next() {
+			state.write(next.as_bytes());
+			for next in iter {
+				state.write(b" ");
+				state.write(next.as_bytes());
+			}
+		}
+
+		// Same as str.
+		state.write_u8(0xff);
+	}
+}
+
+impl NormalizedCompare for [u8] {
+	#[inline]
+	/// # Normalized Equality.
+	///
+	/// Returns `true` if the normalized forms of `self` and `other` are
+	/// equal.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::NormalizedCompare;
+	///
+	/// assert!(b"Hello\r\nWorld".normalized_eq(b" Hello World "));
+	/// assert!(! b"Hello".normalized_eq(b"Hell o"));
+	/// ```
+	fn normalized_eq(&self, other: &Self) -> bool {
+		ascii_words(self).eq(ascii_words(other))
+	}
+
+	#[inline]
+	/// # Normalized Equality (Ignoring ASCII Case).
+	///
+	/// Returns `true` if the normalized forms of `self` and `other` are
+	/// equal, ignoring any differences in ASCII case.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::NormalizedCompare;
+	///
+	/// assert!(b" Jane\tDOE ".normalized_eq_ignore_ascii_case(b"jane doe"));
+	/// ```
+	fn normalized_eq_ignore_ascii_case(&self, other: &Self) -> bool {
+		self.iter().trim_and_normalize().map(|b| b.to_ascii_lowercase())
+			.eq(other.iter().trim_and_normalize().map(|b| b.to_ascii_lowercase()))
+	}
+
+	/// # Hash Normalized.
+	///
+	/// Feed the normalized form of the slice into the hasher, word by word,
+	/// followed by the same `0xff` terminator used by [`str`].
+	///
+	/// Note that unlike `[u8]`'s own `Hash` implementation, the length is
+	/// _not_ written, so `"Hello".hash_normalized` and
+	/// `b"Hello".hash_normalized` produce the same result.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use std::collections::hash_map::DefaultHasher;
+	/// use std::hash::Hasher;
+	/// use trimothy::NormalizedCompare;
+	///
+	/// let mut h1 = DefaultHasher::new();
+	/// b"Hello\tWorld".hash_normalized(&mut h1);
+	///
+	/// let mut h2 = DefaultHasher::new();
+	/// "Hello World".hash_normalized(&mut h2);
+	///
+	/// assert_eq!(h1.finish(), h2.finish());
+	/// ```
+	fn hash_normalized<H: Hasher>(&self, state: &mut H) {
+		let mut hasher = NormalizedHasher::new(&mut *state);
+		hasher.write(self);
+		state.write_u8(0xff);
+	}
+}
+
+
+
+#[cfg(feature = "casefold")]
+/// # Normalized Comparison (Case Folded).
+///
+/// This trait extends [`NormalizedCompare`]-style equality to string slices
+/// with Unicode _simple_ case folding, for matching user-entered names,
+/// emails, and the like against stored values.
+///
+/// | Method | Description |
+/// | ------ | ----------- |
+/// | `normalized_eq_casefold` | Compare normalized forms for equality, ignoring (Unicode) case. |
+///
+/// Simple folding maps each `char` to exactly one other `char`, so it can be
+/// done on the fly without allocating, but it won't equate expansions like
+/// `"ß"` and `"ss"`; those require _full_ folding.
+///
+/// This trait is only available when the `casefold` crate feature is
+/// enabled.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::NormalizedCompareFold;
+///
+/// assert!(" BJÖRK\u{3000}Guðmundsdóttir ".normalized_eq_casefold("björk guðmundsdóttir"));
+///
+/// // Final sigma folds too, unlike with to_lowercase.
+/// assert!("ΟΔΥΣΣΕΥΣ".normalized_eq_casefold("οδυσσευς"));
+///
+/// // Multi-char foldings are out of scope.
+/// assert!(! "STRASSE".normalized_eq_casefold("straße"));
+/// ```
+pub trait NormalizedCompareFold {
+	/// # Normalized Equality (Case Folded).
+	///
+	/// Returns `true` if the normalized forms of `self` and `other` are
+	/// equal, ignoring any differences in case per Unicode simple case
+	/// folding.
+	fn normalized_eq_casefold(&self, other: &Self) -> bool;
+}
+
+#[cfg(feature = "casefold")]
+impl NormalizedCompareFold for str {
+	#[inline]
+	/// # Normalized Equality (Case Folded).
+	///
+	/// Returns `true` if the normalized forms of `self` and `other` are
+	/// equal, ignoring any differences in case per Unicode simple case
+	/// folding.
+	fn normalized_eq_casefold(&self, other: &Self) -> bool {
+		self.chars().trim_and_normalize().map(simple_fold)
+			.eq(other.chars().trim_and_normalize().map(simple_fold))
+	}
+}
+
+
+
+#[derive(Debug, Clone, Default)]
+/// # Normalized Hasher.
+///
+/// This wraps any [`Hasher`], normalizing the bytes written to it on the
+/// fly — trimming leading/trailing ASCII whitespace and compacting spans of
+/// inner whitespace to a single horizontal space — before passing them
+/// along.
+///
+/// State is carried across writes, so content can be fed through in
+/// arbitrary chunks, though as with any `Hasher`, the final result may
+/// depend on _how_ the data was chunked.
+///
+/// Note that only the byte-oriented methods are normalized; integers and the
+/// like pass through untouched.
+///
+/// ## Examples
+///
+/// ```
+/// use std::collections::hash_map::DefaultHasher;
+/// use std::hash::Hasher;
+/// use trimothy::NormalizedHasher;
+///
+/// let mut h1 = NormalizedHasher::new(DefaultHasher::new());
+/// h1.write(b"  Hello\n\n\tWorld!  ");
+///
+/// let mut h2 = DefaultHasher::new();
+/// h2.write(b"Hello");
+/// h2.write(b" ");
+/// h2.write(b"World!");
+///
+/// assert_eq!(h1.finish(), h2.finish());
+/// ```
+pub struct NormalizedHasher<H> {
+	/// # Inner Hasher.
+	inner: H,
+
+	/// # Anything Written Yet?
+	started: bool,
+
+	/// # Pending Whitespace?
+	pending: bool,
+}
+
+impl<H: Hasher> NormalizedHasher<H> {
+	#[must_use]
+	/// # New.
+	///
+	/// Wrap a hasher.
+	pub const fn new(inner: H) -> Self {
+		Self { inner, started: false, pending: false }
+	}
+
+	#[must_use]
+	/// # Into Inner.
+	///
+	/// Unwrap and return the inner hasher.
+	pub fn into_inner(self) -> H { self.inner }
+}
+
+/// # Helper: Integer Passthrough.
+macro_rules! passthrough {
+	($($fn:ident $ty:ty),+ $(,)?) => ($(
+		#[inline]
+		fn $fn(&mut self, i: $ty) { self.inner.$fn(i); }
+	)+);
+}
+
+impl<H: Hasher> Hasher for NormalizedHasher<H> {
+	#[inline]
+	fn finish(&self) -> u64 { self.inner.finish() }
+
+	passthrough!(
+		write_u8 u8, write_u16 u16, write_u32 u32, write_u64 u64,
+		write_u128 u128, write_usize usize,
+		write_i8 i8, write_i16 i16, write_i32 i32, write_i64 i64,
+		write_i128 i128, write_isize isize,
+	);
+
+	fn write(&mut self, mut bytes: &[u8]) {
+		while ! bytes.is_empty() {
+			// Skip over (and remember) whitespace.
+			let ws = bytes.iter().take_while(|b| b.is_ascii_whitespace()).count();
+			if ws != 0 {
+				if self.started { self.pending = true; }
+				bytes = &bytes[ws..];
+				continue;
+			}
+
+			// Write the next word, preceded by a space if needed.
+			let len = bytes.iter().position(u8::is_ascii_whitespace).unwrap_or(bytes.len());
+			if self.pending {
+				self.inner.write(b" ");
+				self.pending = false;
+			}
+			self.inner.write(&bytes[..len]);
+			self.started = true;
+			bytes = &bytes[len..];
+		}
+	}
+}
+
+
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+/// # Normalization Mismatch.
+///
+/// This is the error returned by [`verify_normalized`] and
+/// [`verify_normalized_bytes`] when a claimed normalization is inconsistent
+/// with its original.
+///
+/// Each variant holds the byte offset _within the normalized value_ at which
+/// the problem was detected.
+pub enum NormalizeMismatch {
+	/// # Illegal Whitespace.
+	///
+	/// The normalized value contains leading, trailing, consecutive, or
+	/// non-space whitespace.
+	Whitespace(usize),
+
+	/// # Content Mismatch.
+	///
+	/// The normalized value differs from — or runs longer than — the
+	/// original.
+	Content(usize),
+
+	/// # Truncated.
+	///
+	/// The normalized value ends before the original does.
+	Truncated(usize),
+}
+
+impl fmt::Display for NormalizeMismatch {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let (msg, idx) = match self {
+			Self::Whitespace(idx) => ("illegal whitespace", idx),
+			Self::Content(idx) => ("content mismatch", idx),
+			Self::Truncated(idx) => ("truncated value", idx),
+		};
+		write!(f, "{msg} at byte {idx}")
+	}
+}
+
+impl Error for NormalizeMismatch {}
+
+impl NormalizeMismatch {
+	#[must_use]
+	/// # Offset.
+	///
+	/// Return the byte offset within the normalized value at which the
+	/// mismatch was detected.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::{NormalizeMismatch, verify_normalized};
+	///
+	/// let err = verify_normalized("Hello  World", "Hello  World").unwrap_err();
+	/// assert_eq!(err, NormalizeMismatch::Whitespace(6));
+	/// assert_eq!(err.offset(), 6);
+	/// ```
+	pub const fn offset(self) -> usize {
+		match self { Self::Whitespace(idx) | Self::Content(idx) | Self::Truncated(idx) => idx }
+	}
+}
+
+
+
+/// # Verify Normalization.
+///
+/// Confirm that `normalized` is exactly what
+/// [`TrimNormal::trim_and_normalize`](crate::TrimNormal::trim_and_normalize)
+/// would produce for `original`, i.e. that the two share the same
+/// non-whitespace content and every whitespace span was legally collapsed
+/// to a single horizontal space.
+///
+/// This is useful for pipelines that receive both values from untrusted
+/// components. The check is streamed, char by char, without allocating.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::{NormalizeMismatch, verify_normalized};
+///
+/// assert!(verify_normalized(" Hello\r\n\tWorld! ", "Hello World!").is_ok());
+///
+/// // Whitespace must be collapsed…
+/// assert_eq!(
+///     verify_normalized(" Hello\r\n\tWorld! ", "Hello\nWorld!"),
+///     Err(NormalizeMismatch::Whitespace(5)),
+/// );
+///
+/// // …but not removed.
+/// assert_eq!(
+///     verify_normalized(" Hello\r\n\tWorld! ", "HelloWorld!"),
+///     Err(NormalizeMismatch::Content(5)),
+/// );
+///
+/// // Nor can the content be changed.
+/// assert_eq!(
+///     verify_normalized(" Hello\r\n\tWorld! ", "Hello"),
+///     Err(NormalizeMismatch::Truncated(5)),
+/// );
+/// ```
+///
+/// ## Errors
+///
+/// If `normalized` is not the normalized form of `original`, the first
+/// discrepancy is returned as an error.
+pub fn verify_normalized(original: &str, normalized: &str)
+-> Result<(), NormalizeMismatch> {
+	verify(
+		original.chars().trim_and_normalize(),
+		normalized.char_indices(),
+		normalized.len(),
+		|c: &char| c.is_whitespace(),
+		' ',
+	)
+}
+
+/// # Verify Normalization (Bytes).
+///
+/// Same as [`verify_normalized`], but for byte slices, using
+/// [`u8::is_ascii_whitespace`] for whitespace.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::{NormalizeMismatch, verify_normalized_bytes};
+///
+/// assert!(verify_normalized_bytes(b" Hello\tWorld! ", b"Hello World!").is_ok());
+/// assert_eq!(
+///     verify_normalized_bytes(b" Hello\tWorld! ", b" Hello World!"),
+///     Err(NormalizeMismatch::Whitespace(0)),
+/// );
+/// ```
+///
+/// ## Errors
+///
+/// If `normalized` is not the normalized form of `original`, the first
+/// discrepancy is returned as an error.
+pub fn verify_normalized_bytes(original: &[u8], normalized: &[u8])
+-> Result<(), NormalizeMismatch> {
+	verify(
+		original.iter().trim_and_normalize(),
+		normalized.iter().copied().enumerate(),
+		normalized.len(),
+		u8::is_ascii_whitespace,
+		b' ',
+	)
+}
+
+
+
+#[must_use]
+/// # Compare Trimmed (Numeric-Aware).
+///
+/// Compare two strings — ignoring any leading or trailing whitespace — in
+/// "natural" order, treating each run of ASCII digits as a number, so that
+/// `"v2"` comes before `"v10"`, `"1.9"` before `"1.10"`, etc.
+///
+/// Everything else is compared `char` by `char`. Values that differ only
+/// in leading zeros, like `"v01"` and `"v1"`, fall back to a plain string
+/// comparison to break the tie, keeping the ordering total.
+///
+/// This is particularly handy for sorting version numbers and labels
+/// pulled from user input.
+///
+/// ## Examples
+///
+/// ```
+/// use std::cmp::Ordering;
+/// use trimothy::compare_trimmed_numeric;
+///
+/// assert_eq!(compare_trimmed_numeric(" v2 ", "v10\n"), Ordering::Less);
+/// assert_eq!(compare_trimmed_numeric("1.10.0", "1.9.12"), Ordering::Greater);
+/// assert_eq!(compare_trimmed_numeric("\tv1.2.3", "v1.2.3"), Ordering::Equal);
+///
+/// let mut versions = ["1.10", " 1.2", "1.9 ", "1.2.1"];
+/// versions.sort_by(|a, b| compare_trimmed_numeric(a, b));
+/// assert_eq!(versions, [" 1.2", "1.2.1", "1.9 ", "1.10"]);
+/// ```
+pub fn compare_trimmed_numeric(a: &str, b: &str) -> Ordering {
+	let a = a.trim();
+	let b = b.trim();
+	let mut x = a;
+	let mut y = b;
+	loop {
+		match (x.chars().next(), y.chars().next()) {
+			(Some(c1), Some(c2)) =>
+				// Numbers are compared by value.
+				if c1.is_ascii_digit() && c2.is_ascii_digit() {
+					let (n1, rest1) = split_digits(x);
+					let (n2, rest2) = split_digits(y);
+					let ord = cmp_digits(n1, n2);
+					if ord.is_ne() { return ord; }
+					x = rest1;
+					y = rest2;
+				}
+				else if c1 == c2 {
+					x = &x[c1.len_utf8()..];
+					y = &y[c2.len_utf8()..];
+				}
+				else { return c1.cmp(&c2); },
+			(Some(_), None) => return Ordering::Greater,
+			(None, Some(_)) => return Ordering::Less,
+			(None, None) => return a.cmp(b),
+		}
+	}
+}
+
+#[must_use]
+/// # Whitespace-Only Difference?
+///
+/// Returns `true` if `a` and `b` differ _only_ in their whitespace — or not
+/// at all — i.e. if they are [`NormalizedCompare::normalized_eq`].
+///
+/// This is useful for formatters and the like deciding whether or not a
+/// change is purely cosmetic. To find out exactly where the whitespace
+/// differs, see [`ws_diff_spans`].
+///
+/// Note that the _presence_ of whitespace between words is still
+/// significant: `"ab"` and `"a b"` differ in content.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::ws_only_diff;
+///
+/// assert!(ws_only_diff("fn main() {}", "  fn  main()\n{}\n"));
+/// assert!(! ws_only_diff("fn main() {}", "fn main(){}"));
+/// assert!(! ws_only_diff("fn main() {}", "fn main() { }!"));
+/// ```
+pub fn ws_only_diff(a: &str, b: &str) -> bool { a.normalized_eq(b) }
+
+#[must_use]
+/// # Whitespace Difference Spans.
+///
+/// Return an iterator over the whitespace spans that differ between `a` and
+/// `b`, yielding the byte range of each within `a` and `b` respectively.
+///
+/// Spans are matched up positionally — leading, between each word, and
+/// trailing — so one side's range may be empty if, say, only the other has
+/// leading whitespace.
+///
+/// If the two differ in more than just whitespace, iteration stops at the
+/// first non-whitespace difference; use [`ws_only_diff`] to check that
+/// first.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::ws_diff_spans;
+///
+/// let a = "Hello World!";
+/// let b = "  Hello\tWorld!";
+/// let spans: Vec<_> = ws_diff_spans(a, b).collect();
+/// assert_eq!(spans, [(0..0, 0..2), (5..6, 7..8)]);
+/// ```
+pub const fn ws_diff_spans<'a, 'b>(a: &'a str, b: &'b str) -> WsDiffSpans<'a, 'b> {
+	WsDiffSpans { a, b, pos: Some((0, 0)) }
+}
+
+
+
+#[derive(Debug, Clone)]
+/// # Whitespace Difference Spans.
+///
+/// This iterator is returned by [`ws_diff_spans`], yielding the byte ranges
+/// of each differing whitespace span within the two sources.
+pub struct WsDiffSpans<'a, 'b> {
+	/// # Source A.
+	a: &'a str,
+
+	/// # Source B.
+	b: &'b str,
+
+	/// # Positions.
+	///
+	/// This is `None` once the comparison has been exhausted.
+	pos: Option<(usize, usize)>,
+}
+
+impl Iterator for WsDiffSpans<'_, '_> {
+	type Item = (Range<usize>, Range<usize>);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		while let Some((a_pos, b_pos)) = self.pos {
+			// The gaps.
+			let a_gap = a_pos..run_end(self.a, a_pos, true);
+			let b_gap = b_pos..run_end(self.b, b_pos, true);
+
+			// The words that follow.
+			let a_word = a_gap.end..run_end(self.a, a_gap.end, false);
+			let b_word = b_gap.end..run_end(self.b, b_gap.end, false);
+
+			// Keep going so long as the words match and there are any.
+			self.pos =
+				if a_word.is_empty() || self.a[a_word.clone()] != self.b[b_word.clone()] { None }
+				else { Some((a_word.end, b_word.end)) };
+
+			if self.a[a_gap.clone()] != self.b[b_gap.clone()] {
+				return Some((a_gap, b_gap));
+			}
+		}
+
+		None
+	}
+}
+
+impl FusedIterator for WsDiffSpans<'_, '_> {}
+
+
+
+/// # ASCII Words.
+///
+/// Split a byte slice on ASCII whitespace, skipping empty entries.
+fn ascii_words(src: &[u8]) -> impl Iterator<Item=&[u8]> {
+	src.split(u8::is_ascii_whitespace).filter(|w| ! w.is_empty())
+}
+
+/// # Compare Digits.
+///
+/// Compare two runs of ASCII digits by numeric value, without any risk of
+/// overflow.
+fn cmp_digits(a: &str, b: &str) -> Ordering {
+	let a = a.trim_start_matches('0');
+	let b = b.trim_start_matches('0');
+	a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+}
+
+/// # Run End.
+///
+/// Return the (exclusive) end of the whitespace — or non-whitespace — run
+/// beginning at `from`.
+fn run_end(src: &str, from: usize, ws: bool) -> usize {
+	src[from..].find(|c: char| c.is_whitespace() != ws).map_or(src.len(), |idx| from + idx)
+}
+
+#[cfg(feature = "casefold")]
+/// # Simple Case Fold.
+///
+/// Map a `char` to its Unicode simple case folding, or itself if it has
+/// none.
+fn simple_fold(c: char) -> char {
+	unicode_case_mapping::case_folded(c)
+		.and_then(|n| char::from_u32(n.get()))
+		.unwrap_or(c)
+}
+
+/// # Split Digits.
+///
+/// Split the leading run of ASCII digits from the rest of the string.
+fn split_digits(src: &str) -> (&str, &str) {
+	let idx = src.bytes().position(|b| ! b.is_ascii_digit()).unwrap_or(src.len());
+	src.split_at(idx)
+}
+
+/// # Verify (Generic).
+///
+/// Walk the claimed normalization alongside the expected one, returning the
+/// first discrepancy, if any.
+fn verify<T, E, N, F>(mut expected: E, normalized: N, len: usize, is_ws: F, space: T)
+-> Result<(), NormalizeMismatch>
+where
+	T: Copy + PartialEq,
+	E: Iterator<Item=T>,
+	N: Iterator<Item=(usize, T)>,
+	F: Fn(&T) -> bool,
+{
+	let mut ws = true;
+	for (idx, v) in normalized {
+		if is_ws(&v) {
+			// Leading, trailing, consecutive, or non-space whitespace is
+			// never legal. (Spaces are always one byte.)
+			if ws || v != space || idx + 1 == len { return Err(NormalizeMismatch::Whitespace(idx)); }
+			ws = true;
+		}
+		else { ws = false; }
+
+		if expected.next() != Some(v) { return Err(NormalizeMismatch::Content(idx)); }
+	}
+
+	if expected.next().is_some() { Err(NormalizeMismatch::Truncated(len)) }
+	else { Ok(()) }
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::TrimNormal;
+	use alloc::vec::Vec;
+	use core::hash::Hash;
+
+	#[derive(Default)]
+	/// # Recording Hasher.
+	///
+	/// This simply collects everything written to it.
+	struct Recorder(Vec<u8>);
+
+	impl Hasher for Recorder {
+		fn finish(&self) -> u64 { 0 }
+		fn write(&mut self, bytes: &[u8]) { self.0.extend_from_slice(bytes); }
+	}
+
+	/// # Hash It.
+	fn hash<T: Hash + ?Sized>(src: &T) -> Vec<u8> {
+		let mut h = Recorder::default();
+		src.hash(&mut h);
+		h.0
+	}
+
+	/// # Hash It (Normalized).
+	fn hash_normalized<T: NormalizedCompare + ?Sized>(src: &T) -> Vec<u8> {
+		let mut h = Recorder::default();
+		src.hash_normalized(&mut h);
+		h.0
+	}
+
+	#[test]
+	fn t_normalized_compare() {
+		for (a, b, eq) in [
+			("", "", true),
+			("", " \t\n", true),
+			("Hello", " Hello ", true),
+			("Hello World", "Hello\r\n\tWorld", true),
+			("Hello World", "HelloWorld", false),
+			("Hello World", "Hello World!", false),
+			("a b c", " a  b  c ", true),
+			("a\u{3000}b", "a b", true),
+		] {
+			assert_eq!(a.normalized_eq(b), eq, "{a:?} {b:?}");
+			assert_eq!(b.normalized_eq(a), eq, "{a:?} {b:?}");
+
+			let ha = hash_normalized(a);
+			assert_eq!(ha, hash(&*a.trim_and_normalize()), "{a:?}");
+			assert_eq!(ha == hash_normalized(b), eq, "{a:?} {b:?}");
+
+			if a.is_ascii() && b.is_ascii() {
+				assert_eq!(a.as_bytes().normalized_eq(b.as_bytes()), eq, "{a:?} {b:?}");
+				assert_eq!(hash_normalized(a.as_bytes()), ha, "{a:?}");
+			}
+		}
+	}
+
+	#[test]
+	fn t_normalized_eq_ignore_ascii_case() {
+		for (a, b, expected) in [
+			("", " ", true),
+			("Hello World", " hello\nWORLD ", true),
+			("Hello World", "HelloWorld", false),
+			("Hello World", "Hello World!", false),
+			("ÀB", "àb", false),
+			("àB", "àb", true),
+			("\u{212a}elvin", "kelvin", false),
+			("ß", "SS", false),
+		] {
+			assert_eq!(a.normalized_eq_ignore_ascii_case(b), expected, "{a:?} {b:?}");
+			assert_eq!(b.normalized_eq_ignore_ascii_case(a), expected, "{a:?} {b:?}");
+
+			let (a, b) = (a.as_bytes(), b.as_bytes());
+			assert_eq!(a.normalized_eq_ignore_ascii_case(b), expected, "{a:?} {b:?}");
+		}
+	}
+
+	#[cfg(feature = "casefold")]
+	#[test]
+	fn t_normalized_eq_casefold() {
+		for (a, b, expected) in [
+			("", " ", true),
+			("Hello World", " hello\nWORLD ", true),
+			("Hello World", "HelloWorld", false),
+			("ÀB", "àb", true),
+			("\u{212a}elvin", "kelvin", true),
+			("ΣΊΣΥΦΟΣ", "σίσυφος", true),
+			("\u{1e9e}", "ß", true),
+			("ß", "SS", false),
+			("ﬁ", "fi", false),
+			("İ", "i", false),
+		] {
+			assert_eq!(a.normalized_eq_casefold(b), expected, "{a:?} {b:?}");
+			assert_eq!(b.normalized_eq_casefold(a), expected, "{a:?} {b:?}");
+		}
+	}
+
+	#[test]
+	fn t_normalized_hasher() {
+		let raw = b"  Hello \n World\t! ";
+
+		// Chunking shouldn't affect the normalization.
+		for n in 1..raw.len() {
+			let mut h = NormalizedHasher::new(Recorder::default());
+			for chunk in raw.chunks(n) { h.write(chunk); }
+			assert_eq!(h.into_inner().0, b"Hello World !", "Chunk size {n}.");
+		}
+
+		// Integers pass straight through.
+		let mut h = NormalizedHasher::new(Recorder::default());
+		h.write(b" A ");
+		h.write_u8(b' ');
+		h.write(b" B ");
+		assert_eq!(h.into_inner().0, b"A  B");
+	}
+
+	#[test]
+	fn t_ws_diff() {
+		for (a, b, spans) in [
+			("", "", &[][..]),
+			("Hello", "Hello", &[]),
+			("Hello", " Hello\n", &[(0..0, 0..1), (5..5, 6..7)]),
+			(" a b ", " a b ", &[]),
+			("a b c", "a\u{3000}b  c", &[(1..2, 1..4), (3..4, 5..7)]),
+			(" \t ", "", &[(0..3, 0..0)]),
+		] {
+			assert!(ws_only_diff(a, b), "{a:?} {b:?}");
+			assert_eq!(ws_diff_spans(a, b).collect::<Vec<_>>(), spans, "{a:?} {b:?}");
+
+			// Flipped.
+			let flipped: Vec<_> = spans.iter().cloned().map(|(a, b)| (b, a)).collect();
+			assert_eq!(ws_diff_spans(b, a).collect::<Vec<_>>(), flipped, "{b:?} {a:?}");
+		}
+
+		// Content differences stop the iterator.
+		assert!(! ws_only_diff("a b c", "a\tb d"));
+		assert_eq!(ws_diff_spans("a b c", "a\tb  d").collect::<Vec<_>>(), [(1..2, 1..2), (3..4, 3..5)]);
+		assert!(! ws_only_diff("ab", "a b"));
+		assert_eq!(ws_diff_spans("ab", "a b").count(), 0);
+	}
+
+	#[test]
+	fn t_compare_trimmed_numeric() {
+		use Ordering::{Equal, Greater, Less};
+
+		for (a, b, expected) in [
+			("", "", Equal),
+			(" ", "\t", Equal),
+			("", "a", Less),
+			("v2", "v10", Less),
+			(" v10 ", "v2", Greater),
+			("1.9", "1.10", Less),
+			("1.2", "1.2.1", Less),
+			("1.2.3", "\n1.2.3\n", Equal),
+			("v01", "v1", Less),
+			("v010", "v9", Greater),
+			("file2b", "file2a", Greater),
+			("a 2", "a 10", Less),
+			("99999999999999999999999", "100000000000000000000000", Less),
+			("Björk 2", "Björk 10", Less),
+			("10", "a", Less),
+		] {
+			assert_eq!(compare_trimmed_numeric(a, b), expected, "{a:?} {b:?}");
+			assert_eq!(compare_trimmed_numeric(b, a), expected.reverse(), "{b:?} {a:?}");
+		}
+	}
+
+	#[test]
+	fn t_verify_normalized() {
+		for raw in [
+			"", " ", "Hello", " Hello World ", "\u{2003}HEL\u{2003} LO\r\u{2003}",
+			"H\tE  L\n\rL\x0CO ", " 🌎\u{a0}🌍\u{85}🌏 ",
+		] {
+			let normal = raw.trim_and_normalize();
+			assert_eq!(verify_normalized(raw, &normal), Ok(()), "{raw:?}");
+			assert_eq!(verify_normalized(&normal, &normal), Ok(()), "{raw:?}");
+
+			if raw.is_ascii() {
+				let normal = raw.as_bytes().trim_and_normalize();
+				assert_eq!(verify_normalized_bytes(raw.as_bytes(), &normal), Ok(()), "{raw:?}");
+			}
+		}
+
+		for (a, b, err) in [
+			("Hello", " Hello", NormalizeMismatch::Whitespace(0)),
+			("Hello", "Hello ", NormalizeMismatch::Whitespace(5)),
+			("a b", "a  b", NormalizeMismatch::Whitespace(2)),
+			("a b", "a\u{3000}b", NormalizeMismatch::Whitespace(1)),
+			("a\u{3000}b", "ab", NormalizeMismatch::Content(1)),
+			("ab", "a b", NormalizeMismatch::Content(1)),
+			("Björk", "Bjork", NormalizeMismatch::Content(2)),
+			("Hello", "Hello!", NormalizeMismatch::Content(5)),
+			("Hello World", "Hello", NormalizeMismatch::Truncated(5)),
+			("Hello", "", NormalizeMismatch::Truncated(0)),
+			("", " ", NormalizeMismatch::Whitespace(0)),
+		] {
+			assert_eq!(verify_normalized(a, b), Err(err), "{a:?} {b:?}");
+			if a.is_ascii() && b.is_ascii() {
+				assert_eq!(verify_normalized_bytes(a.as_bytes(), b.as_bytes()), Err(err), "{a:?} {b:?}");
+			}
+		}
+	}
+}
@@ -0,0 +1,243 @@
+/*!
+# Trimothy: Config Files.
+*/
+
+use alloc::vec::Vec;
+use crate::TrimMut;
+#[cfg(feature = "toml")]
+use toml::{
+	Table,
+	Value,
+};
+
+
+
+#[cfg(feature = "toml")]
+/// # Trim TOML Strings.
+///
+/// Walk a [`toml::Value`] tree, trimming the leading and trailing whitespace
+/// from every string scalar in place, so config loaders can forgive stray
+/// spaces without having to special-case each field.
+///
+/// Unlike [`normalize_json_strings`](crate::normalize_json_strings), inner
+/// whitespace is left alone, as are table keys.
+///
+/// This function is only available when the `toml` crate feature is enabled.
+///
+/// ## Examples
+///
+/// ```
+/// use toml::Value;
+/// use trimothy::trim_toml_strings;
+///
+/// let mut value = Value::Array(vec![
+///     Value::String(" Björk\tGuðmundsdóttir ".to_owned()),
+///     Value::Integer(42),
+/// ]);
+/// trim_toml_strings(&mut value);
+/// assert_eq!(value, Value::Array(vec![
+///     Value::String("Björk\tGuðmundsdóttir".to_owned()),
+///     Value::Integer(42),
+/// ]));
+/// ```
+pub fn trim_toml_strings(value: &mut Value) { walk_toml(Vec::from([value])); }
+
+#[cfg(feature = "toml")]
+/// # Trim TOML Table Strings.
+///
+/// Same as [`trim_toml_strings`], but for a top-level [`toml::Table`], i.e.
+/// a parsed document.
+///
+/// This function is only available when the `toml` crate feature is enabled.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::trim_toml_table;
+///
+/// let mut table: toml::Table = r#"
+///     name = "  My App "
+///     port = 8080
+///
+///     [paths]
+///     cache = ["/tmp/cache ", " /var/cache"]
+/// "#.parse().unwrap();
+/// trim_toml_table(&mut table);
+///
+/// assert_eq!(table["name"].as_str(), Some("My App"));
+/// assert_eq!(table["paths"]["cache"][0].as_str(), Some("/tmp/cache"));
+/// assert_eq!(table["paths"]["cache"][1].as_str(), Some("/var/cache"));
+/// ```
+pub fn trim_toml_table(table: &mut Table) {
+	walk_toml(table.iter_mut().map(|(_, v)| v).collect());
+}
+
+#[cfg(feature = "yaml")]
+/// # Trim YAML Strings.
+///
+/// Walk a [`serde_yaml::Value`] tree, trimming the leading and trailing
+/// whitespace from every string scalar in place — including those inside
+/// tagged values — the same as [`trim_toml_strings`] does for TOML.
+///
+/// Mapping keys are left alone.
+///
+/// This function is only available when the `yaml` crate feature is enabled.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::trim_yaml_strings;
+///
+/// let mut value: serde_yaml::Value = serde_yaml::from_str("
+/// name: '  My App '
+/// port: 8080
+/// paths:
+///   cache: ['/tmp/cache ', ' /var/cache']
+/// ").unwrap();
+/// trim_yaml_strings(&mut value);
+///
+/// assert_eq!(value["name"].as_str(), Some("My App"));
+/// assert_eq!(value["paths"]["cache"][0].as_str(), Some("/tmp/cache"));
+/// assert_eq!(value["paths"]["cache"][1].as_str(), Some("/var/cache"));
+/// ```
+pub fn trim_yaml_strings(value: &mut serde_yaml::Value) { walk_yaml(Vec::from([value])); }
+
+
+
+#[cfg(feature = "toml")]
+/// # Walk the Tree (TOML).
+///
+/// This uses an explicit stack rather than recursion so deeply-nested
+/// values can't blow the real one.
+fn walk_toml(mut stack: Vec<&mut Value>) {
+	while let Some(value) = stack.pop() {
+		match value {
+			Value::String(s) => { s.trim_mut(); },
+			Value::Array(arr) => { stack.extend(arr.iter_mut()); },
+			Value::Table(table) => { stack.extend(table.iter_mut().map(|(_, v)| v)); },
+			Value::Integer(_) | Value::Float(_) | Value::Boolean(_) | Value::Datetime(_) => {},
+		}
+	}
+}
+
+#[cfg(feature = "yaml")]
+/// # Walk the Tree (YAML).
+///
+/// Same as [`walk_toml`], but for YAML.
+fn walk_yaml(mut stack: Vec<&mut serde_yaml::Value>) {
+	use serde_yaml::Value;
+
+	while let Some(value) = stack.pop() {
+		match value {
+			Value::String(s) => { s.trim_mut(); },
+			Value::Sequence(arr) => { stack.extend(arr.iter_mut()); },
+			Value::Mapping(map) => { stack.extend(map.iter_mut().map(|(_, v)| v)); },
+			Value::Tagged(tagged) => { stack.push(&mut tagged.value); },
+			Value::Null | Value::Bool(_) | Value::Number(_) => {},
+		}
+	}
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use alloc::string::String;
+
+	#[cfg(feature = "toml")]
+	#[test]
+	fn t_trim_toml() {
+		let mut table: Table = r#"
+			" key " = " Hello\tWorld\n"
+			clean = "Clean"
+			empty = " \t "
+			number = 1.5
+			flag = true
+			when = 1979-05-27T07:32:00Z
+
+			[nested]
+			list = [" a", ["b ", { deep = " c " }]]
+		"#.parse().expect("Invalid TOML.");
+
+		let expected: Table = r#"
+			" key " = "Hello\tWorld"
+			clean = "Clean"
+			empty = ""
+			number = 1.5
+			flag = true
+			when = 1979-05-27T07:32:00Z
+
+			[nested]
+			list = ["a", ["b", { deep = "c" }]]
+		"#.parse().expect("Invalid TOML.");
+
+		let mut value = Value::Table(table.clone());
+		trim_toml_strings(&mut value);
+		assert_eq!(value, Value::Table(expected.clone()));
+
+		trim_toml_table(&mut table);
+		assert_eq!(table, expected);
+
+		// Deep nesting shouldn't be a problem.
+		let mut value = Value::String(String::from(" deep "));
+		for _ in 0..10_000 { value = Value::Array(Vec::from([value])); }
+		trim_toml_strings(&mut value);
+		let mut inner = &value;
+		while let Value::Array(arr) = inner { inner = &arr[0]; }
+		assert_eq!(inner.as_str(), Some("deep"));
+
+		// Drop it iteratively too, to be safe.
+		let mut stack = Vec::from([value]);
+		while let Some(v) = stack.pop() {
+			if let Value::Array(arr) = v { stack.extend(arr); }
+		}
+	}
+
+	#[cfg(feature = "yaml")]
+	#[test]
+	fn t_trim_yaml() {
+		use serde_yaml::Value;
+
+		let mut value: Value = serde_yaml::from_str("
+			' key ': \" Hello\\tWorld\\n\"
+			clean: Clean
+			empty: ' '
+			number: 1.5
+			flag: true
+			nothing: ~
+			tagged: !Thing ' x '
+			nested:
+			  list: [' a', ['b ', { deep: ' c ' }]]
+		".replace('\t', "").as_str()).expect("Invalid YAML.");
+
+		let expected: Value = serde_yaml::from_str("
+			' key ': \"Hello\\tWorld\"
+			clean: Clean
+			empty: ''
+			number: 1.5
+			flag: true
+			nothing: ~
+			tagged: !Thing 'x'
+			nested:
+			  list: ['a', ['b', { deep: 'c' }]]
+		".replace('\t', "").as_str()).expect("Invalid YAML.");
+
+		trim_yaml_strings(&mut value);
+		assert_eq!(value, expected);
+
+		// Deep nesting shouldn't be a problem.
+		let mut value = Value::String(String::from(" deep "));
+		for _ in 0..10_000 { value = Value::Sequence(Vec::from([value])); }
+		trim_yaml_strings(&mut value);
+		let mut inner = &value;
+		while let Value::Sequence(arr) = inner { inner = &arr[0]; }
+		assert_eq!(inner.as_str(), Some("deep"));
+
+		// Drop it iteratively too, to be safe.
+		let mut stack = Vec::from([value]);
+		while let Some(v) = stack.pop() {
+			if let Value::Sequence(arr) = v { stack.extend(arr); }
+		}
+	}
+}
@@ -0,0 +1,258 @@
+/*!
+# Trimothy: Cow Helpers.
+*/
+
+use alloc::borrow::{
+	Cow,
+	ToOwned,
+};
+use crate::TrimNormal;
+
+
+
+#[inline]
+#[must_use]
+/// # Trimmed Cow (String).
+///
+/// Return the string slice with its leading/trailing whitespace trimmed as a
+/// `Cow`. (Trimming never requires allocation, so this will always be
+/// borrowed, but can save some conversions when the rest of the pipeline is
+/// `Cow`-based.)
+///
+/// ## Examples
+///
+/// ```
+/// # extern crate alloc;
+/// # use alloc::borrow::Cow;
+/// use trimothy::cow_trimmed;
+///
+/// let cow = cow_trimmed(" Hello World ");
+/// assert_eq!(cow, "Hello World");
+/// assert!(matches!(cow, Cow::Borrowed(_)));
+/// ```
+pub fn cow_trimmed(src: &str) -> Cow<'_, str> { Cow::Borrowed(src.trim()) }
+
+#[inline]
+#[must_use]
+/// # Trimmed Cow (Bytes).
+///
+/// Return the byte slice with its leading/trailing ASCII whitespace trimmed
+/// as a (borrowed) `Cow`.
+///
+/// ## Examples
+///
+/// ```
+/// # extern crate alloc;
+/// # use alloc::borrow::Cow;
+/// use trimothy::cow_trimmed_bytes;
+///
+/// let cow = cow_trimmed_bytes(b" Hello World ");
+/// assert_eq!(cow.as_ref(), b"Hello World");
+/// assert!(matches!(cow, Cow::Borrowed(_)));
+/// ```
+pub const fn cow_trimmed_bytes(src: &[u8]) -> Cow<'_, [u8]> {
+	Cow::Borrowed(src.trim_ascii())
+}
+
+#[inline]
+#[must_use]
+/// # Normalized Cow (String).
+///
+/// Trim the leading/trailing whitespace, and compact/normalize spans of
+/// _inner_ whitespace to a single horizontal space, borrowing if the source
+/// is already clean, and allocating only if it is not.
+///
+/// This is equivalent to calling [`TrimNormal::trim_and_normalize`] on the
+/// slice.
+///
+/// ## Examples
+///
+/// ```
+/// # extern crate alloc;
+/// # use alloc::borrow::Cow;
+/// use trimothy::cow_normalized;
+///
+/// let cow = cow_normalized(" Hello World ");
+/// assert_eq!(cow, "Hello World");
+/// assert!(matches!(cow, Cow::Borrowed(_)));
+///
+/// let cow = cow_normalized(" Hello\n\tWorld ");
+/// assert_eq!(cow, "Hello World");
+/// assert!(matches!(cow, Cow::Owned(_)));
+/// ```
+pub fn cow_normalized(src: &str) -> Cow<'_, str> { src.trim_and_normalize() }
+
+#[inline]
+#[must_use]
+/// # Normalized Cow (Bytes).
+///
+/// Trim the leading/trailing ASCII whitespace, and compact/normalize spans
+/// of _inner_ ASCII whitespace to a single horizontal space, borrowing if
+/// the source is already clean, and allocating only if it is not.
+///
+/// This is equivalent to calling [`TrimNormal::trim_and_normalize`] on the
+/// slice.
+///
+/// ## Examples
+///
+/// ```
+/// # extern crate alloc;
+/// # use alloc::borrow::Cow;
+/// use trimothy::cow_normalized_bytes;
+///
+/// let cow = cow_normalized_bytes(b" Hello World ");
+/// assert_eq!(cow.as_ref(), b"Hello World");
+/// assert!(matches!(cow, Cow::Borrowed(_)));
+///
+/// let cow = cow_normalized_bytes(b" Hello\n\tWorld ");
+/// assert_eq!(cow.as_ref(), b"Hello World");
+/// assert!(matches!(cow, Cow::Owned(_)));
+/// ```
+pub fn cow_normalized_bytes(src: &[u8]) -> Cow<'_, [u8]> { src.trim_and_normalize() }
+
+#[must_use]
+/// # Normalize Within (Byte Budget).
+///
+/// Trim and normalize the string slice — the same as [`cow_normalized`] —
+/// and if the result is still longer than `n` bytes, truncate it at a word
+/// boundary (or, failing that, a char boundary) and append `ellipsis`.
+///
+/// The result, ellipsis included, never exceeds `n` bytes. If `ellipsis`
+/// is itself too long to fit, it is left off. Pass `""` to skip it
+/// altogether.
+///
+/// ## Examples
+///
+/// ```
+/// # extern crate alloc;
+/// # use alloc::borrow::Cow;
+/// use trimothy::normalize_within;
+///
+/// let raw = "  The quick\n\tbrown fox jumps over the lazy dog. ";
+/// assert_eq!(normalize_within(raw, 100, "…"), "The quick brown fox jumps over the lazy dog.");
+/// assert_eq!(normalize_within(raw, 20, "…"), "The quick brown…");
+/// assert_eq!(normalize_within(raw, 20, ""), "The quick brown fox");
+///
+/// // Really long words are split if they must be.
+/// assert_eq!(normalize_within("Supercalifragilistic", 8, "..."), "Super...");
+///
+/// // Clean sources are borrowed when possible.
+/// assert!(matches!(normalize_within("Hello World", 5, ""), Cow::Borrowed("Hello")));
+/// ```
+pub fn normalize_within<'a>(src: &'a str, n: usize, ellipsis: &str) -> Cow<'a, str> {
+	let normal = src.trim_and_normalize();
+	if normal.len() <= n { return normal; }
+
+	// Figure out how much room there is for the content.
+	let ellipsis = if ellipsis.len() <= n { ellipsis } else { "" };
+	let mut cut = n - ellipsis.len();
+	while ! normal.is_char_boundary(cut) { cut -= 1; }
+
+	// Back up to the previous word boundary, if we're mid-word and there is
+	// one.
+	if normal.as_bytes()[cut] != b' ' {
+		if let Some(idx) = normal[..cut].rfind(' ') { cut = idx; }
+	}
+	let cut = normal[..cut].trim_end().len();
+
+	match normal {
+		Cow::Borrowed(s) if ellipsis.is_empty() => Cow::Borrowed(&s[..cut]),
+		normal => {
+			let mut out = normal.into_owned();
+			out.truncate(cut);
+			out.push_str(ellipsis);
+			Cow::Owned(out)
+		},
+	}
+}
+
+#[must_use]
+/// # Into Owned (If Changed).
+///
+/// Return the owned value of `cow` if (and only if) it differs from the
+/// `original`, otherwise `None`.
+///
+/// This is useful for writing cleaned values back to their source only when
+/// cleaning actually accomplished something.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::{cow_normalized, into_owned_if_changed};
+///
+/// let mut fields = vec![
+///     String::from("Hello World"),
+///     String::from(" Hello\n World "),
+/// ];
+///
+/// let mut changed = 0;
+/// for field in &mut fields {
+///     if let Some(new) = into_owned_if_changed(cow_normalized(field), field) {
+///         *field = new;
+///         changed += 1;
+///     }
+/// }
+///
+/// assert_eq!(fields, ["Hello World", "Hello World"]);
+/// assert_eq!(changed, 1);
+/// ```
+pub fn into_owned_if_changed<B>(cow: Cow<'_, B>, original: &B) -> Option<B::Owned>
+where B: ToOwned + PartialEq + ?Sized {
+	if *cow == *original { None }
+	else { Some(cow.into_owned()) }
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn t_normalize_within() {
+		for (raw, n, ellipsis, expected) in [
+			("", 0, "…", ""),
+			(" Hello ", 5, "…", "Hello"),
+			("Hello World", 10, "…", "Hello…"),
+			("Hello World", 10, "", "Hello"),
+			("Hello World", 6, "", "Hello"),
+			("Hello World", 3, "…", "…"),
+			("Hello World", 2, "…", "He"),
+			("Björk", 2, "", "Bj"),
+			("Björk", 3, "", "Bj"),
+			("a b c", 4, "", "a b"),
+			("a\n\nb\tc", 4, ".", "a b."),
+			("a\n\nbb\tc", 4, ".", "a."),
+			(" 🌎 🌍 🌏 ", 5, "", "🌎"),
+			(" 🌎 🌍 🌏 ", 3, "", ""),
+		] {
+			let out = normalize_within(raw, n, ellipsis);
+			assert_eq!(out, expected, "{raw:?} {n} {ellipsis:?}");
+			assert!(out.len() <= n, "{raw:?} {n} {ellipsis:?}");
+		}
+	}
+
+	#[test]
+	fn t_cows() {
+		for (raw, trimmed, normalized) in [
+			("", "", ""),
+			(" \t\n", "", ""),
+			("Hello", "Hello", "Hello"),
+			(" Hello  World\n", "Hello  World", "Hello World"),
+		] {
+			assert_eq!(cow_trimmed(raw), trimmed);
+			assert_eq!(cow_trimmed_bytes(raw.as_bytes()).as_ref(), trimmed.as_bytes());
+			assert_eq!(cow_normalized(raw), normalized);
+			assert_eq!(cow_normalized_bytes(raw.as_bytes()).as_ref(), normalized.as_bytes());
+
+			assert_eq!(
+				into_owned_if_changed(cow_trimmed(raw), raw).is_some(),
+				raw != trimmed,
+			);
+			assert_eq!(
+				into_owned_if_changed(cow_normalized_bytes(raw.as_bytes()), raw.as_bytes()),
+				if raw == normalized { None } else { Some(normalized.as_bytes().to_vec()) },
+			);
+		}
+	}
+}
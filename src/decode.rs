@@ -0,0 +1,312 @@
+/*!
+# Trimothy: Text Decoding.
+*/
+
+use alloc::{
+	borrow::Cow,
+	string::String,
+};
+use core::str::Utf8Error;
+use crate::{
+	TrimMut,
+	TrimNormal,
+};
+
+
+
+/// # UTF-8 Byte-Order Mark.
+const BOM: &[u8] = b"\xEF\xBB\xBF";
+
+
+
+#[inline]
+#[must_use]
+/// # Decode and Trim.
+///
+/// Strip the UTF-8 byte-order mark (if any) from raw bytes, decode them
+/// (lossily), and trim the leading/trailing whitespace, all in one go.
+///
+/// This is shorthand for `Decoder::new().with_lossy(true).decode(src)`; see
+/// [`Decoder`] for strict validation, normalization, etc.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::decode_trimmed;
+///
+/// let raw = b"\xEF\xBB\xBF  Hello World!\r\n";
+/// assert_eq!(decode_trimmed(raw), "Hello World!");
+///
+/// // Invalid UTF-8 is replaced.
+/// assert_eq!(decode_trimmed(b" Hello\xFF "), "Hello\u{FFFD}");
+/// ```
+pub fn decode_trimmed(src: &[u8]) -> Cow<'_, str> {
+	trim_cow(String::from_utf8_lossy(strip_bom(src)))
+}
+
+
+
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, PartialEq)]
+/// # Text Decoder.
+///
+/// This struct is the canonical "read a text file and clean it up" entry
+/// point, fusing byte-order-mark removal, UTF-8 decoding, trimming, and
+/// (optionally) normalization into a single call.
+///
+/// Clean, valid input is returned borrowed; allocations are only made when
+/// the content actually needs to change.
+///
+/// | Setting | Default | Description |
+/// | ------- | ------- | ----------- |
+/// | `lossy` | `false` | Replace invalid UTF-8 sequences instead of failing. |
+/// | `normalize` | `false` | Compact/normalize inner whitespace too. |
+/// | `fallback` | `false` | Decode invalid UTF-8 as Windows-1252 instead. (Requires the `encoding` crate feature.) |
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::Decoder;
+///
+/// let raw = b"\xEF\xBB\xBF\tHello\r\n\r\nWorld!\n";
+///
+/// // Strict, trim-only.
+/// let decoder = Decoder::new();
+/// assert_eq!(decoder.decode(raw).unwrap(), "Hello\r\n\r\nWorld!");
+/// assert!(decoder.decode(b"Hello\xFF").is_err());
+///
+/// // Lossy, with normalization.
+/// let decoder = Decoder::new().with_lossy(true).with_normalize(true);
+/// assert_eq!(decoder.decode(raw).unwrap(), "Hello World!");
+/// assert_eq!(decoder.decode(b"Hello\xFF").unwrap(), "Hello\u{FFFD}");
+/// ```
+pub struct Decoder {
+	/// # Lossy Decoding?
+	lossy: bool,
+
+	/// # Normalize Whitespace?
+	normalize: bool,
+
+	#[cfg(feature = "encoding")]
+	/// # Windows-1252 Fallback?
+	fallback: bool,
+}
+
+/// ## Setup.
+impl Decoder {
+	#[must_use]
+	/// # New.
+	///
+	/// Return a new strict, trim-only decoder.
+	pub const fn new() -> Self {
+		Self {
+			lossy: false,
+			normalize: false,
+			#[cfg(feature = "encoding")]
+			fallback: false,
+		}
+	}
+
+	#[must_use]
+	/// # With Lossy.
+	///
+	/// Enable or disable lossy decoding, i.e. replacing invalid UTF-8
+	/// sequences with `U+FFFD` rather than returning an error.
+	pub const fn with_lossy(mut self, lossy: bool) -> Self {
+		self.lossy = lossy;
+		self
+	}
+
+	#[must_use]
+	/// # With Normalize.
+	///
+	/// Enable or disable the compaction/normalization of inner whitespace,
+	/// à la [`TrimNormal`].
+	pub const fn with_normalize(mut self, normalize: bool) -> Self {
+		self.normalize = normalize;
+		self
+	}
+
+	#[cfg(feature = "encoding")]
+	#[must_use]
+	/// # With Fallback.
+	///
+	/// Enable or disable the Windows-1252 fallback. When enabled, sources
+	/// that fail UTF-8 validation are decoded as Windows-1252 (a superset of
+	/// Latin-1) instead, taking precedence over lossy decoding.
+	///
+	/// This method is only available when the `encoding` crate feature is
+	/// enabled.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::Decoder;
+	///
+	/// let raw = b"\x93Caf\xE9\x94\xA0\r\n";
+	/// assert!(Decoder::new().decode(raw).is_err());
+	///
+	/// let decoder = Decoder::new().with_fallback(true);
+	/// assert_eq!(decoder.decode(raw).unwrap(), "“Café”");
+	/// ```
+	pub const fn with_fallback(mut self, fallback: bool) -> Self {
+		self.fallback = fallback;
+		self
+	}
+}
+
+/// ## Getters.
+impl Decoder {
+	#[must_use]
+	/// # Lossy Decoding?
+	pub const fn lossy(&self) -> bool { self.lossy }
+
+	#[must_use]
+	/// # Normalize Whitespace?
+	pub const fn normalize(&self) -> bool { self.normalize }
+
+	#[cfg(feature = "encoding")]
+	#[must_use]
+	/// # Windows-1252 Fallback?
+	pub const fn fallback(&self) -> bool { self.fallback }
+}
+
+/// ## Decoding.
+impl Decoder {
+	/// # Decode.
+	///
+	/// Strip the UTF-8 byte-order mark (if any) from the raw bytes, decode
+	/// them, then trim (and maybe normalize) the result.
+	///
+	/// ## Errors
+	///
+	/// Unless lossy decoding (or the Windows-1252 fallback) is enabled, an
+	/// error will be returned if the bytes are not valid UTF-8.
+	pub fn decode<'a>(&self, src: &'a [u8]) -> Result<Cow<'a, str>, Utf8Error> {
+		// Decode.
+		let src = strip_bom(src);
+		let out = match core::str::from_utf8(src) {
+			Ok(s) => Cow::Borrowed(s),
+			#[cfg(feature = "encoding")]
+			Err(_) if self.fallback => Cow::Owned(decode_cp1252(src)),
+			Err(_) if self.lossy => String::from_utf8_lossy(src),
+			Err(e) => return Err(e),
+		};
+
+		// Trim and/or normalize.
+		if self.normalize { Ok(out.trim_and_normalize()) }
+		else { Ok(trim_cow(out)) }
+	}
+}
+
+
+
+#[cfg(feature = "encoding")]
+/// # Windows-1252 Decode.
+///
+/// Decode the bytes as Windows-1252. ASCII and the Latin-1 range map to
+/// themselves; the handful of undefined `0x80..=0x9F` slots map to their C1
+/// control counterparts, same as browsers do.
+fn decode_cp1252(src: &[u8]) -> String {
+	/// # Windows-1252 `0x80..=0x9F`.
+	const HIGH: [char; 32] = [
+		'€', '\u{81}', '‚', 'ƒ', '„', '…', '†', '‡',
+		'ˆ', '‰', 'Š', '‹', 'Œ', '\u{8D}', 'Ž', '\u{8F}',
+		'\u{90}', '‘', '’', '“', '”', '•', '–', '—',
+		'˜', '™', 'š', '›', 'œ', '\u{9D}', 'ž', 'Ÿ',
+	];
+
+	src.iter()
+		.map(|&b| match b {
+			0x80..=0x9F => HIGH[usize::from(b - 0x80)],
+			_ => char::from(b),
+		})
+		.collect()
+}
+
+#[inline]
+/// # Strip BOM.
+///
+/// Return the slice without its leading UTF-8 byte-order mark, if any.
+fn strip_bom(src: &[u8]) -> &[u8] { src.strip_prefix(BOM).unwrap_or(src) }
+
+/// # Trim Cow.
+///
+/// Trim a `Cow<str>`, in place if owned.
+fn trim_cow(src: Cow<'_, str>) -> Cow<'_, str> {
+	match src {
+		Cow::Borrowed(s) => Cow::Borrowed(s.trim()),
+		Cow::Owned(mut s) => {
+			s.trim_mut();
+			Cow::Owned(s)
+		},
+	}
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn t_decode() {
+		for (raw, trimmed, normal) in [
+			(&b""[..], "", ""),
+			(BOM, "", ""),
+			(b"\xEF\xBB\xBF\xEF\xBB\xBF", "\u{FEFF}", "\u{FEFF}"),
+			(b"Hello", "Hello", "Hello"),
+			(b"\xEF\xBB\xBF Hello \n", "Hello", "Hello"),
+			(b" Hello\r\n\tWorld ", "Hello\r\n\tWorld", "Hello World"),
+			(b"\xEF\xBB\xBF\xE3\x80\x80Bj\xC3\xB6rk\xE3\x80\x80", "Björk", "Björk"),
+		] {
+			let out = Decoder::new().decode(raw).unwrap();
+			assert_eq!(out, trimmed, "{raw:?}");
+			assert!(matches!(out, Cow::Borrowed(_)), "{raw:?}");
+			assert_eq!(decode_trimmed(raw), trimmed, "{raw:?}");
+
+			let out = Decoder::new().with_normalize(true).decode(raw).unwrap();
+			assert_eq!(out, normal, "{raw:?}");
+		}
+
+		// Invalid UTF-8.
+		for (raw, trimmed, normal) in [
+			(&b"\xFF"[..], "\u{FFFD}", "\u{FFFD}"),
+			(b"\xEF\xBB\xBF Hello\xFF\n\nWorld ", "Hello\u{FFFD}\n\nWorld", "Hello\u{FFFD} World"),
+		] {
+			assert!(Decoder::new().decode(raw).is_err(), "{raw:?}");
+			assert_eq!(decode_trimmed(raw), trimmed, "{raw:?}");
+
+			let decoder = Decoder::new().with_lossy(true).with_normalize(true);
+			assert_eq!(decoder.decode(raw).unwrap(), normal, "{raw:?}");
+		}
+	}
+
+	#[cfg(feature = "encoding")]
+	#[test]
+	fn t_decode_cp1252() {
+		// Every byte should map to something, and the round trip should
+		// agree with the (Latin-1) char values outside the special range.
+		let all: alloc::vec::Vec<u8> = (0..=u8::MAX).collect();
+		let out = decode_cp1252(&all);
+		assert_eq!(out.chars().count(), 256);
+		for (b, c) in all.iter().copied().zip(out.chars()) {
+			if ! (0x80..=0x9F).contains(&b) { assert_eq!(u32::from(b), u32::from(c)); }
+		}
+
+		let decoder = Decoder::new().with_fallback(true);
+		for (raw, expected) in [
+			(&b"Hello"[..], "Hello"),
+			(b"\xEF\xBB\xBF Hello ", "Hello"),
+			(b" \x80100 ", "€100"),
+			(b"\xA0Na\xEFve\xA0", "Naïve"),
+			(b"\x93Hi\x94\r\n", "“Hi”"),
+		] {
+			assert_eq!(decoder.decode(raw).unwrap(), expected, "{raw:?}");
+			assert_eq!(decoder.with_lossy(true).decode(raw).unwrap(), expected, "{raw:?}");
+		}
+
+		// Normalization still works.
+		let decoder = decoder.with_normalize(true);
+		assert_eq!(decoder.decode(b"\xA0A\xA0\xA0B\x85\t").unwrap(), "A B…");
+	}
+}
@@ -0,0 +1,236 @@
+/*!
+# Trimothy: Environment.
+*/
+
+use crate::{
+	TrimMatchesMut,
+	TrimMut,
+	TrimNormal,
+};
+use std::{
+	ffi::{
+		OsStr,
+		OsString,
+	},
+	string::String,
+};
+
+
+
+#[must_use]
+/// # Trimmed Environment Variable.
+///
+/// Fetch the environment variable `key`, trimming its leading and trailing
+/// whitespace.
+///
+/// Variables that are unset, not valid UTF-8, or empty after trimming are all
+/// treated as missing, returning `None`, so a stray `FOO=" "` can't sneak
+/// through as a "real" value.
+///
+/// This function is only available when the `std` crate feature is enabled.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::env_trimmed;
+///
+/// # std::env::set_var("TRIMOTHY_DOC_PORT", " 8080\n");
+/// # std::env::set_var("TRIMOTHY_DOC_BLANK", " \t ");
+/// // TRIMOTHY_DOC_PORT=" 8080\n"
+/// assert_eq!(env_trimmed("TRIMOTHY_DOC_PORT").as_deref(), Some("8080"));
+///
+/// // TRIMOTHY_DOC_BLANK=" \t "
+/// assert!(env_trimmed("TRIMOTHY_DOC_BLANK").is_none());
+/// ```
+pub fn env_trimmed<K: AsRef<OsStr>>(key: K) -> Option<String> {
+	let mut out = std::env::var(key).ok()?;
+	out.trim_mut();
+	if out.is_empty() { None }
+	else { Some(out) }
+}
+
+#[must_use]
+/// # Normalized Environment Variable.
+///
+/// Same as [`env_trimmed`], but inner whitespace is compacted/normalized as
+/// well, as with [`TrimNormal`].
+///
+/// This function is only available when the `std` crate feature is enabled.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::env_normalized;
+///
+/// # std::env::set_var("TRIMOTHY_DOC_NAME", "\tMy  App\r\n");
+/// // TRIMOTHY_DOC_NAME="\tMy  App\r\n"
+/// assert_eq!(env_normalized("TRIMOTHY_DOC_NAME").as_deref(), Some("My App"));
+/// ```
+pub fn env_normalized<K: AsRef<OsStr>>(key: K) -> Option<String> {
+	let mut out = std::env::var(key).ok()?;
+	(&mut out).trim_and_normalize();
+	if out.is_empty() { None }
+	else { Some(out) }
+}
+
+/// # Trimmed Arguments.
+///
+/// Return an iterator over the program's command-line arguments — as with
+/// [`std::env::args`] — with any leading and trailing whitespace and stray
+/// double quotes trimmed from each, such as those Windows `cmd` leaves
+/// behind when a quoted path ends in a backslash.
+///
+/// Arguments that trim down to nothing are yielded as empty strings rather
+/// than skipped, so positions are preserved.
+///
+/// This function is only available when the `std` crate feature is enabled.
+///
+/// ## Panics
+///
+/// As with [`std::env::args`], this will panic if any argument is not valid
+/// Unicode; use [`args_os_trimmed`] if that's a possibility.
+///
+/// ## Examples
+///
+/// ```no_run
+/// // e.g. my-app " --verbose" "C:\Program Files\"
+/// for arg in trimothy::args_trimmed().skip(1) {
+///     println!("{arg}");
+/// }
+/// ```
+pub fn args_trimmed() -> impl Iterator<Item=String> {
+	std::env::args().map(trim_arg)
+}
+
+/// # Trimmed Arguments (`OsString`).
+///
+/// Same as [`args_trimmed`], but for [`std::env::args_os`], so arguments
+/// needn't be valid Unicode.
+///
+/// Arguments that _are_ valid Unicode are trimmed exactly like
+/// [`args_trimmed`]. The rest are trimmed of ASCII whitespace and double
+/// quotes on Unix and Windows, and passed through as-is elsewhere.
+///
+/// This function is only available when the `std` crate feature is enabled.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use std::path::PathBuf;
+///
+/// let paths: Vec<PathBuf> = trimothy::args_os_trimmed()
+///     .skip(1)
+///     .map(PathBuf::from)
+///     .collect();
+/// ```
+pub fn args_os_trimmed() -> impl Iterator<Item=OsString> {
+	std::env::args_os().map(trim_arg_os)
+}
+
+
+
+/// # Trim Argument.
+fn trim_arg(mut arg: String) -> String {
+	arg.trim_matches_mut(|c: char| c.is_whitespace() || c == '"');
+	arg
+}
+
+/// # Trim Argument (`OsString`).
+fn trim_arg_os(arg: OsString) -> OsString {
+	match arg.into_string() {
+		Ok(arg) => OsString::from(trim_arg(arg)),
+		Err(arg) => trim_arg_os_raw(arg),
+	}
+}
+
+#[cfg(unix)]
+/// # Trim Argument (Non-Unicode).
+fn trim_arg_os_raw(arg: OsString) -> OsString {
+	use std::os::unix::ffi::OsStringExt;
+	let mut arg = arg.into_vec();
+	arg.trim_matches_mut(|b: u8| b.is_ascii_whitespace() || b == b'"');
+	OsString::from_vec(arg)
+}
+
+#[cfg(windows)]
+/// # Trim Argument (Non-Unicode).
+fn trim_arg_os_raw(arg: OsString) -> OsString {
+	use std::{
+		os::windows::ffi::{
+			OsStrExt,
+			OsStringExt,
+		},
+		vec::Vec,
+	};
+
+	/// # Trimmable?
+	const fn trimmable(u: u16) -> bool {
+		matches!(u, 0x09 | 0x0A | 0x0C | 0x0D | 0x20 | 0x22)
+	}
+
+	let arg: Vec<u16> = arg.encode_wide().collect();
+	let start = arg.iter().position(|u| ! trimmable(*u)).unwrap_or(arg.len());
+	let end = arg.iter().rposition(|u| ! trimmable(*u)).map_or(start, |e| e + 1);
+	OsString::from_wide(&arg[start..end])
+}
+
+#[cfg(not(any(unix, windows)))]
+/// # Trim Argument (Non-Unicode).
+const fn trim_arg_os_raw(arg: OsString) -> OsString { arg }
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn t_env() {
+		for (key, raw, trimmed, normal) in [
+			("TRIMOTHY_T_ENV_CLEAN", "Hello World", Some("Hello World"), Some("Hello World")),
+			("TRIMOTHY_T_ENV_DIRTY", " Hello\t\tWorld\n", Some("Hello\t\tWorld"), Some("Hello World")),
+			("TRIMOTHY_T_ENV_SPACE", " ", None, None),
+			("TRIMOTHY_T_ENV_EMPTY", "", None, None),
+		] {
+			std::env::set_var(key, raw);
+			assert_eq!(env_trimmed(key).as_deref(), trimmed, "{raw:?}");
+			assert_eq!(env_normalized(key).as_deref(), normal, "{raw:?}");
+		}
+
+		// Unset.
+		std::env::remove_var("TRIMOTHY_T_ENV_UNSET");
+		assert!(env_trimmed("TRIMOTHY_T_ENV_UNSET").is_none());
+		assert!(env_normalized("TRIMOTHY_T_ENV_UNSET").is_none());
+	}
+
+	#[test]
+	fn t_trim_arg() {
+		for (raw, expected) in [
+			("--verbose", "--verbose"),
+			(" --verbose\t", "--verbose"),
+			("C:\\Program Files\\\"", "C:\\Program Files\\"),
+			("\"hello world\"", "hello world"),
+			("it's", "it's"),
+			(" \" ", ""),
+			("", ""),
+		] {
+			assert_eq!(trim_arg(String::from(raw)), expected, "{raw:?}");
+			assert_eq!(trim_arg_os(OsString::from(raw)), OsString::from(expected), "{raw:?}");
+		}
+
+		// Args should be passed through too.
+		assert_eq!(args_trimmed().count(), std::env::args_os().count());
+		assert_eq!(args_os_trimmed().count(), std::env::args_os().count());
+	}
+
+	#[cfg(unix)]
+	#[test]
+	fn t_trim_arg_os_raw() {
+		use std::os::unix::ffi::OsStringExt;
+		let raw = OsString::from_vec(b" \"Hello\xFFWorld\"\n".to_vec());
+		assert_eq!(
+			trim_arg_os(raw).into_vec(),
+			b"Hello\xFFWorld",
+		);
+	}
+}
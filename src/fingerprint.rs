@@ -0,0 +1,129 @@
+/*!
+# Trimothy: Fingerprinting.
+*/
+
+use alloc::{
+	borrow::Cow,
+	string::String,
+};
+
+
+
+/// # Default Placeholder.
+const PLACEHOLDER: &str = "#";
+
+
+
+#[must_use]
+/// # Fingerprint Line.
+///
+/// Trim and normalize the whitespace of a log line — as with
+/// [`TrimNormal`](crate::TrimNormal) — while also replacing each run of ASCII
+/// digits with a `#`, all in a single pass, producing stable keys for
+/// clustering lines that differ only by timestamps, IDs, durations, and the
+/// like.
+///
+/// The original is borrowed if nothing needs to change. See
+/// [`fingerprint_line_with`] to use a different placeholder.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::fingerprint_line;
+///
+/// assert_eq!(
+///     fingerprint_line("  [2024-01-02]  user42 logged in\tafter 350ms\n"),
+///     "[#-#-#] user# logged in after #ms",
+/// );
+/// assert_eq!(
+///     fingerprint_line("[2025-12-31] user7  logged in after 5ms"),
+///     fingerprint_line("[2024-01-02] user42 logged in after 350ms"),
+/// );
+/// ```
+pub fn fingerprint_line(src: &str) -> Cow<'_, str> {
+	fingerprint_line_with(src, PLACEHOLDER)
+}
+
+#[must_use]
+/// # Fingerprint Line (Custom Placeholder).
+///
+/// Same as [`fingerprint_line`], but digit runs are replaced with an
+/// arbitrary `placeholder` instead of `#`.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::fingerprint_line_with;
+///
+/// assert_eq!(
+///     fingerprint_line_with("GET /items/123?page=2  200", "<N>"),
+///     "GET /items/<N>?page=<N> <N>",
+/// );
+/// ```
+pub fn fingerprint_line_with<'a>(src: &'a str, placeholder: &str) -> Cow<'a, str> {
+	let src = src.trim();
+	let mut out: Option<String> = None;
+	let mut iter = src.char_indices().peekable();
+	while let Some((idx, c)) = iter.next() {
+		// Whitespace and digits are handled in runs.
+		let (is_run, sep): (fn(&char) -> bool, &str) =
+			if c.is_whitespace() { (|c| c.is_whitespace(), " ") }
+			else if c.is_ascii_digit() { (char::is_ascii_digit, placeholder) }
+			else {
+				if let Some(out) = &mut out { out.push(c); }
+				continue;
+			};
+
+		let mut end = idx + c.len_utf8();
+		while let Some((idx2, c2)) = iter.next_if(|(_, c2)| is_run(c2)) {
+			end = idx2 + c2.len_utf8();
+		}
+
+		// Take ownership if the run isn't already just the replacement.
+		if src[idx..end] != *sep {
+			out.get_or_insert_with(|| String::from(&src[..idx]));
+		}
+		if let Some(out) = &mut out { out.push_str(sep); }
+	}
+
+	out.map_or(Cow::Borrowed(src), Cow::Owned)
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::TrimNormal;
+
+	#[test]
+	fn t_fingerprint_line() {
+		for (raw, expected) in [
+			("", ""),
+			(" \t ", ""),
+			("Hello World", "Hello World"),
+			("#", "#"),
+			("# # #", "# # #"),
+			("1", "#"),
+			("  Hello\u{3000}\u{3000}World 12  ", "Hello World #"),
+			("v1.2.3", "v#.#.#"),
+			("0xFF 0x1f", "#xFF #x#f"),
+			("took 12.5ms", "took #.#ms"),
+			("Björk 1965 ٣", "Björk # ٣"),
+		] {
+			let out = fingerprint_line(raw);
+			assert_eq!(out, expected, "{raw:?}");
+			assert_eq!(matches!(out, Cow::Borrowed(_)), raw.trim() == expected, "{raw:?}");
+		}
+
+		// Without digits, it should match normalization.
+		for raw in [" H\r\nE\u{2001}L  \u{3000}\u{205f}L\tO  ", "Hello World", " \n "] {
+			assert_eq!(fingerprint_line(raw), raw.trim_and_normalize(), "{raw:?}");
+		}
+
+		// Custom placeholders.
+		assert_eq!(fingerprint_line_with("1 2 3", "<N>"), "<N> <N> <N>");
+		assert_eq!(fingerprint_line_with("a1b22c", ""), "abc");
+		assert!(matches!(fingerprint_line_with("a 123 b", "123"), Cow::Borrowed(_)));
+	}
+}
@@ -0,0 +1,358 @@
+/*!
+# Trimothy: Fixed-Width Fields.
+*/
+
+use alloc::{
+	string::String,
+	vec::Vec,
+};
+use core::{
+	iter::FusedIterator,
+	slice::Iter,
+};
+
+
+
+/// # Fixed-Width Field Extraction.
+///
+/// This trait brings fixed-width record parsing — mainframe exports, FIX
+/// messages, legacy flat files, etc. — to string and byte slices, yielding
+/// each field pre-trimmed of its space and NUL padding.
+///
+/// | Method | Description |
+/// | ------ | ----------- |
+/// | `fixed_fields` | Iterate over trimmed fixed-width fields. |
+///
+/// Widths are measured in `char`s for string slices, and bytes for byte
+/// slices.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::TrimFixed;
+///
+/// let record = "00042Jane      Doe       \0\0\0";
+/// assert!(record.fixed_fields(&[5, 10, 10, 3]).eq(["00042", "Jane", "Doe", ""]));
+///
+/// let record = b"00042Jane      Doe       \0\0\0";
+/// assert!(record.fixed_fields(&[5, 10, 10, 3]).eq([
+///     &b"00042"[..],
+///     b"Jane",
+///     b"Doe",
+///     b"",
+/// ]));
+/// ```
+pub trait TrimFixed {
+	/// # Fixed Fields.
+	///
+	/// Return an iterator that slices the source into consecutive fields of
+	/// the given widths, trimming leading/trailing spaces and NULs from each.
+	///
+	/// Exactly one field is yielded per width; if the source is too short —
+	/// e.g. trailing padding was stripped — the missing fields come back
+	/// truncated or empty. Anything beyond the last width is ignored.
+	fn fixed_fields<'a>(&'a self, widths: &'a [usize]) -> FixedFields<'a, Self>;
+}
+
+impl TrimFixed for str {
+	#[inline]
+	/// # Fixed Fields.
+	///
+	/// Return an iterator that slices the source into consecutive fields of
+	/// the given (`char`) widths, trimming leading/trailing spaces and NULs
+	/// from each.
+	///
+	/// Exactly one field is yielded per width; if the source is too short,
+	/// the missing fields come back truncated or empty. Anything beyond the
+	/// last width is ignored.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::TrimFixed;
+	///
+	/// let record = "Björk     IS";
+	/// assert!(record.fixed_fields(&[10, 2, 5]).eq(["Björk", "IS", ""]));
+	/// ```
+	fn fixed_fields<'a>(&'a self, widths: &'a [usize]) -> FixedFields<'a, Self> {
+		FixedFields { src: self, widths: widths.iter() }
+	}
+}
+
+impl TrimFixed for [u8] {
+	#[inline]
+	/// # Fixed Fields.
+	///
+	/// Return an iterator that slices the source into consecutive fields of
+	/// the given (byte) widths, trimming leading/trailing spaces and NULs
+	/// from each.
+	///
+	/// Exactly one field is yielded per width; if the source is too short,
+	/// the missing fields come back truncated or empty. Anything beyond the
+	/// last width is ignored.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::TrimFixed;
+	///
+	/// let record: &[u8] = b"  12\0\0\0\0AB";
+	/// assert!(record.fixed_fields(&[4, 4, 4]).eq([&b"12"[..], b"", b"AB"]));
+	/// ```
+	fn fixed_fields<'a>(&'a self, widths: &'a [usize]) -> FixedFields<'a, Self> {
+		FixedFields { src: self, widths: widths.iter() }
+	}
+}
+
+
+
+#[derive(Debug, Clone)]
+/// # Fixed-Width Fields.
+///
+/// This iterator is returned by [`TrimFixed::fixed_fields`], yielding each
+/// fixed-width field with its space/NUL padding trimmed.
+pub struct FixedFields<'a, T: ?Sized> {
+	/// # Remaining Source.
+	src: &'a T,
+
+	/// # Remaining Widths.
+	widths: Iter<'a, usize>,
+}
+
+impl<'a, T: ?Sized> FixedFields<'a, T> {
+	#[must_use]
+	/// # Remainder.
+	///
+	/// Return the (untrimmed) portion of the source that has yet to be
+	/// consumed, including anything beyond the last width.
+	pub const fn remainder(&self) -> &'a T { self.src }
+}
+
+impl<'a> Iterator for FixedFields<'a, str> {
+	type Item = &'a str;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let width = *self.widths.next()?;
+		let end = self.src.char_indices().nth(width).map_or(self.src.len(), |(idx, _)| idx);
+		let (field, rest) = self.src.split_at(end);
+		self.src = rest;
+		Some(field.trim_matches(is_padding_char))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) { self.widths.size_hint() }
+}
+
+impl<'a> Iterator for FixedFields<'a, [u8]> {
+	type Item = &'a [u8];
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let width = *self.widths.next()?;
+		let (field, rest) = self.src.split_at(width.min(self.src.len()));
+		self.src = rest;
+		Some(trim_padding(field))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) { self.widths.size_hint() }
+}
+
+impl ExactSizeIterator for FixedFields<'_, str> {}
+impl ExactSizeIterator for FixedFields<'_, [u8]> {}
+impl FusedIterator for FixedFields<'_, str> {}
+impl FusedIterator for FixedFields<'_, [u8]> {}
+
+
+
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, PartialEq)]
+/// # Column Alignment.
+///
+/// This is used by [`align_columns_with`] to specify how each column's cells
+/// should be padded.
+pub enum ColumnAlign {
+	#[default]
+	/// # Left.
+	Left,
+
+	/// # Right.
+	Right,
+
+	/// # Center.
+	///
+	/// Odd leftovers go on the right.
+	Center,
+}
+
+
+
+#[must_use]
+/// # Align Columns.
+///
+/// Trim each cell of a table and pad it to the widest (trimmed) cell in its
+/// column, returning one aligned line per row, ready for quick CLI output.
+///
+/// Columns are left-aligned and separated by two spaces; see
+/// [`align_columns_with`] for more control.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::align_columns;
+///
+/// let rows = [
+///     ["Name ", " Qty", " Price"],
+///     ["Apple", "3", "1.25"],
+///     [" Watermelon", "12", " 4.00 "],
+/// ];
+/// assert_eq!(
+///     align_columns(&rows),
+///     [
+///         "Name        Qty  Price",
+///         "Apple       3    1.25",
+///         "Watermelon  12   4.00",
+///     ],
+/// );
+/// ```
+pub fn align_columns<R, S>(rows: &[R]) -> Vec<String>
+where R: AsRef<[S]>, S: AsRef<str> {
+	align_columns_with(rows, &[], "  ")
+}
+
+#[must_use]
+/// # Align Columns (With).
+///
+/// Same as [`align_columns`], but with explicit per-column alignments and a
+/// custom separator. Columns without an alignment default to
+/// [`ColumnAlign::Left`].
+///
+/// Widths are measured in `char`s. Rows may be ragged; missing cells are
+/// simply skipped. Trailing whitespace is trimmed from each line.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::{align_columns_with, ColumnAlign};
+///
+/// let rows = vec![
+///     vec![String::from("Item"), String::from("Price")],
+///     vec![String::from("Björk"), String::from("1.25")],
+///     vec![String::from("Tea"), String::from("12.50")],
+/// ];
+/// assert_eq!(
+///     align_columns_with(&rows, &[ColumnAlign::Center, ColumnAlign::Right], " | "),
+///     [
+///         "Item  | Price",
+///         "Björk |  1.25",
+///         " Tea  | 12.50",
+///     ],
+/// );
+/// ```
+pub fn align_columns_with<R, S>(rows: &[R], align: &[ColumnAlign], sep: &str)
+-> Vec<String>
+where R: AsRef<[S]>, S: AsRef<str> {
+	// Find the column widths.
+	let mut widths: Vec<usize> = Vec::new();
+	for row in rows {
+		for (idx, cell) in row.as_ref().iter().enumerate() {
+			let len = cell.as_ref().trim().chars().count();
+			if idx < widths.len() { widths[idx] = widths[idx].max(len); }
+			else { widths.push(len); }
+		}
+	}
+
+	rows.iter().map(|row| {
+		let mut line = String::new();
+		for (idx, cell) in row.as_ref().iter().enumerate() {
+			if idx != 0 { line.push_str(sep); }
+			let cell = cell.as_ref().trim();
+			let pad = widths[idx] - cell.chars().count();
+			let (before, after) = match align.get(idx).copied().unwrap_or_default() {
+				ColumnAlign::Left => (0, pad),
+				ColumnAlign::Right => (pad, 0),
+				ColumnAlign::Center => (pad / 2, pad - pad / 2),
+			};
+			line.extend(core::iter::repeat(' ').take(before));
+			line.push_str(cell);
+			line.extend(core::iter::repeat(' ').take(after));
+		}
+		line.truncate(line.trim_end().len());
+		line
+	}).collect()
+}
+
+
+
+#[inline]
+/// # Is Padding (Char)?
+const fn is_padding_char(c: char) -> bool { matches!(c, ' ' | '\0') }
+
+/// # Trim Padding (Bytes).
+const fn trim_padding(mut src: &[u8]) -> &[u8] {
+	while let [b' ' | b'\0', rest @ ..] = src { src = rest; }
+	while let [rest @ .., b' ' | b'\0'] = src { src = rest; }
+	src
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use alloc::vec::Vec;
+
+	#[test]
+	fn t_fixed_fields() {
+		for (raw, widths, expected) in [
+			("", &[][..], &[][..]),
+			("", &[3, 2], &["", ""]),
+			("abc", &[], &[]),
+			("  a\0b  c", &[3, 3, 3], &["a", "b", "c"]),
+			("a  b", &[0, 2, 0, 2], &["", "a", "", "b"]),
+			("ab  cd", &[3], &["ab"]),
+			("x y z   ", &[1, 6, 1], &["x", "y z", ""]),
+			("\0\0\0\0", &[2, 2], &["", ""]),
+		] {
+			let iter = raw.fixed_fields(widths);
+			assert_eq!(iter.len(), widths.len());
+			assert_eq!(iter.collect::<Vec<_>>(), expected, "{raw:?} {widths:?}");
+
+			// Bytes should be the same for ASCII.
+			let iter = raw.as_bytes().fixed_fields(widths);
+			assert_eq!(iter.len(), widths.len());
+			assert!(
+				iter.eq(expected.iter().map(|v| v.as_bytes())),
+				"{raw:?} {widths:?}",
+			);
+		}
+
+		// Multi-byte chars.
+		assert!("ÀÁÂ  ÃÄ".fixed_fields(&[2, 3, 2]).eq(["ÀÁ", "Â", "ÃÄ"]));
+		assert!("ÀÁÂ  ÃÄ".as_bytes().fixed_fields(&[4, 4]).eq([
+			"ÀÁ".as_bytes(),
+			"Â".as_bytes(),
+		]));
+
+		// Remainder.
+		let mut iter = "AB  CD EF".fixed_fields(&[4]);
+		assert_eq!(iter.next(), Some("AB"));
+		assert_eq!(iter.remainder(), "CD EF");
+		assert_eq!(iter.next(), None);
+	}
+
+	#[test]
+	fn t_align_columns() {
+		let empty: &[&[&str]] = &[];
+		assert!(align_columns(empty).is_empty());
+
+		// Ragged rows.
+		let rows: &[&[&str]] = &[&["a", "bb", "c"], &[" dddd "], &[], &["e", "f"]];
+		assert_eq!(align_columns(rows), ["a     bb  c", "dddd", "", "e     f"]);
+
+		// Alignments.
+		let rows = [["x", "y", "z"], ["xxxx", "yyyy", "zzzz"]];
+		assert_eq!(
+			align_columns_with(&rows, &[ColumnAlign::Right, ColumnAlign::Center], ""),
+			["   x y  z", "xxxxyyyyzzzz"],
+		);
+	}
+}
@@ -0,0 +1,1008 @@
+/*!
+# Trimothy: Formatting
+*/
+
+use alloc::{
+	borrow::Cow,
+	string::String,
+};
+use core::{
+	fmt,
+	iter::FusedIterator,
+	str::EscapeDebug,
+};
+
+
+
+/// # Write Normalized.
+///
+/// Write `src` to `f` with the leading/trailing whitespace trimmed, and
+/// spans of _inner_ whitespace compacted/normalized to a single horizontal
+/// space, the same as [`TrimNormal`](crate::TrimNormal), but without
+/// allocating any temporary strings along the way.
+///
+/// This is primarily intended for use within custom `Debug`/`Display`
+/// implementations wanting to emit single-line renditions of messy,
+/// multi-line inner text.
+///
+/// ## Examples
+///
+/// ```
+/// use std::fmt;
+/// use trimothy::write_normalized;
+///
+/// struct Query(&'static str);
+///
+/// impl fmt::Display for Query {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         write_normalized(f, self.0)
+///     }
+/// }
+///
+/// let query = Query("
+///     SELECT *
+///     FROM   users
+///     WHERE  id = 5
+/// ");
+/// assert_eq!(query.to_string(), "SELECT * FROM users WHERE id = 5");
+/// ```
+///
+/// ## Errors
+///
+/// This will bubble up any errors encountered by the writer.
+pub fn write_normalized<W: fmt::Write + ?Sized>(f: &mut W, src: &str)
+-> fmt::Result {
+	let mut iter = src.split_whitespace();
+	if let Some(next) = iter.next() {
+		f.write_str(next)?;
+		for next in iter {
+			f.write_char(' ')?;
+			f.write_str(next)?;
+		}
+	}
+
+	Ok(())
+}
+
+/// # Write Normalized (Arguments).
+///
+/// Same as [`write_normalized`], but for [`fmt::Arguments`], allowing
+/// formatted output — e.g. from `format_args!` — to be normalized on the fly,
+/// without first materializing the un-normalized string.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::write_normalized_args;
+///
+/// let name = "\n  Björk\n";
+/// let mut out = String::new();
+/// write_normalized_args(&mut out, format_args!("  Hello, {name}!  ")).unwrap();
+/// assert_eq!(out, "Hello, Björk !");
+/// ```
+///
+/// ## Errors
+///
+/// This will bubble up any errors encountered by the writer.
+pub fn write_normalized_args<W: fmt::Write + ?Sized>(f: &mut W, args: fmt::Arguments<'_>)
+-> fmt::Result {
+	if let Some(src) = args.as_str() { write_normalized(f, src) }
+	else {
+		fmt::write(
+			&mut NormalizeWriter { inner: f, started: false, pending: false },
+			args,
+		)
+	}
+}
+
+#[must_use]
+/// # Normalize Arguments.
+///
+/// Format the arguments into a new `String`, trimmed and normalized along
+/// the way. See [`write_normalized_args`] for details.
+///
+/// This is handy for logging macros and the like.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::normalize_args;
+///
+/// let query = "SELECT *\n\tFROM users\n";
+/// assert_eq!(
+///     normalize_args(format_args!("query={query} ")),
+///     "query=SELECT * FROM users",
+/// );
+/// ```
+pub fn normalize_args(args: fmt::Arguments<'_>) -> String {
+	let mut out = String::new();
+	// Writing to a String can't fail.
+	let _res = write_normalized_args(&mut out, args);
+	out
+}
+
+#[must_use]
+/// # Join Lines (Normalized).
+///
+/// Join an iterator of lines — e.g. from [`str::lines`] or (the `Ok`s of)
+/// `BufRead::lines` — into a single normalized paragraph, collapsing each
+/// line break along with any surrounding whitespace into a single space.
+///
+/// This is the classic "unwrap hard-wrapped email text" operation. Blank
+/// lines are collapsed like any other whitespace, so split on them first if
+/// there are multiple paragraphs to keep separate.
+///
+/// ## Examples
+///
+/// ```
+/// use std::io::BufRead;
+/// use trimothy::join_lines_normalized;
+///
+/// let email = "Dear  Björk,\n\n    Thanks for\n  the\tletter!  \n";
+/// assert_eq!(
+///     join_lines_normalized(email.lines()),
+///     "Dear Björk, Thanks for the letter!",
+/// );
+///
+/// // Owned lines work too.
+/// let reader = std::io::Cursor::new(email);
+/// assert_eq!(
+///     join_lines_normalized(reader.lines().map_while(Result::ok)),
+///     "Dear Björk, Thanks for the letter!",
+/// );
+/// ```
+pub fn join_lines_normalized<I, S>(lines: I) -> String
+where I: IntoIterator<Item=S>, S: AsRef<str> {
+	let mut out = NormalizedBuilder::new();
+	for line in lines {
+		out.push_str(line.as_ref());
+		out.push('\n');
+	}
+	out.finish()
+}
+
+/// # Write Wrapped.
+///
+/// Same as [`write_normalized`], but re-wraps the words to lines no wider
+/// than `width` chars, breaking only on (the original) whitespace. Lines
+/// are joined with `\n`.
+///
+/// There is no hyphenation: words longer than `width` are given lines of
+/// their own, but are otherwise left intact.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::write_wrapped;
+///
+/// let mut out = String::new();
+/// write_wrapped(&mut out, "  The quick brown\n\tfox jumps over the lazy dog.", 16).unwrap();
+/// assert_eq!(out, "The quick brown\nfox jumps over\nthe lazy dog.");
+/// ```
+///
+/// ## Errors
+///
+/// This will bubble up any errors encountered by the writer.
+pub fn write_wrapped<W: fmt::Write + ?Sized>(f: &mut W, src: &str, width: usize)
+-> fmt::Result {
+	let mut line = 0;
+	for next in src.split_whitespace() {
+		let len = next.chars().count();
+		if line == 0 { line = len; }
+		else if line + 1 + len <= width {
+			f.write_char(' ')?;
+			line += 1 + len;
+		}
+		else {
+			f.write_char('\n')?;
+			line = len;
+		}
+		f.write_str(next)?;
+	}
+
+	Ok(())
+}
+
+#[must_use]
+/// # Wrap Normalized.
+///
+/// Trim and normalize `src` — the same as
+/// [`TrimNormal`](crate::TrimNormal) — and re-wrap the result to lines no
+/// wider than `width` chars, as described by [`write_wrapped`].
+///
+/// If the source is already normalized and fits on a single line, it is
+/// borrowed; otherwise a new `String` is allocated.
+///
+/// ## Examples
+///
+/// ```
+/// use std::borrow::Cow;
+/// use trimothy::wrap_normalized;
+///
+/// assert_eq!(
+///     wrap_normalized("Usage:  app [OPTIONS]  <FILE>...", 16),
+///     "Usage: app\n[OPTIONS]\n<FILE>...",
+/// );
+///
+/// // Long words are left intact.
+/// assert_eq!(wrap_normalized("Supercalifragilistic!", 5), "Supercalifragilistic!");
+///
+/// // Nothing to do!
+/// assert!(matches!(wrap_normalized("Hello World", 80), Cow::Borrowed(_)));
+/// ```
+pub fn wrap_normalized(src: &str, width: usize) -> Cow<'_, str> {
+	let src = src.trim();
+	let mut ws = false;
+	let clean = src.chars().all(|c|
+		if c.is_whitespace() {
+			let ok = ! ws && c == ' ';
+			ws = true;
+			ok
+		}
+		else {
+			ws = false;
+			true
+		}
+	);
+
+	// Clean and short enough, or a single word.
+	if clean && (src.chars().count() <= width || ! src.contains(' ')) {
+		return Cow::Borrowed(src);
+	}
+
+	let mut out = String::with_capacity(src.len());
+	// Writing to a String can't fail.
+	let _res = write_wrapped(&mut out, src, width);
+	Cow::Owned(out)
+}
+
+
+
+/// # Normalizing Writer.
+///
+/// This wraps a writer, normalizing everything written to it on the fly.
+struct NormalizeWriter<'a, W: fmt::Write + ?Sized> {
+	/// # Writer.
+	inner: &'a mut W,
+
+	/// # Written Anything Yet?
+	started: bool,
+
+	/// # Whitespace Pending?
+	pending: bool,
+}
+
+impl<W: fmt::Write + ?Sized> fmt::Write for NormalizeWriter<'_, W> {
+	#[inline]
+	fn write_str(&mut self, s: &str) -> fmt::Result {
+		write_fragment(self.inner, s, &mut self.started, &mut self.pending)
+	}
+}
+
+
+
+#[derive(Debug, Clone, Default, Eq, Hash, PartialEq)]
+/// # Normalized String Builder.
+///
+/// This is a `String` builder for templating code and the like, collapsing
+/// whitespace — including any at the seams between fragments — on the fly,
+/// so the result is always trimmed and normalized, the same as
+/// [`TrimNormal`](crate::TrimNormal), without a final pass over a large
+/// buffer.
+///
+/// It implements [`fmt::Write`], so `write!` works too.
+///
+/// ## Examples
+///
+/// ```
+/// use std::fmt::Write;
+/// use trimothy::NormalizedBuilder;
+///
+/// let mut b = NormalizedBuilder::new();
+/// b.push_str("  <p>\n");
+/// b.push_str("\tHello ");
+/// b.push(' ');
+/// write!(b, " {}\n", "World!").unwrap();
+/// b.push_str("</p>\n\n");
+///
+/// // Trailing whitespace is held back until something follows it.
+/// assert_eq!(b.as_str(), "<p> Hello World! </p>");
+/// assert_eq!(b.finish(), "<p> Hello World! </p>");
+/// ```
+pub struct NormalizedBuilder {
+	/// # Buffer.
+	buf: String,
+
+	/// # Whitespace Pending?
+	pending: bool,
+}
+
+impl NormalizedBuilder {
+	#[must_use]
+	/// # New.
+	pub const fn new() -> Self {
+		Self { buf: String::new(), pending: false }
+	}
+
+	#[must_use]
+	/// # With Capacity.
+	///
+	/// Create a new builder with (at least) `capacity` bytes of pre-allocated
+	/// storage.
+	pub fn with_capacity(capacity: usize) -> Self {
+		Self { buf: String::with_capacity(capacity), pending: false }
+	}
+
+	/// # Push Fragment.
+	///
+	/// Append a string fragment, normalizing its whitespace along the way.
+	pub fn push_str(&mut self, s: &str) {
+		let mut started = ! self.buf.is_empty();
+		// Writing to a String can't fail.
+		let _res = write_fragment(&mut self.buf, s, &mut started, &mut self.pending);
+	}
+
+	/// # Push Char.
+	///
+	/// Append a single `char`, normalizing it if it is whitespace.
+	pub fn push(&mut self, c: char) {
+		if c.is_whitespace() { self.pending = true; }
+		else {
+			if self.pending && ! self.buf.is_empty() { self.buf.push(' '); }
+			self.buf.push(c);
+			self.pending = false;
+		}
+	}
+
+	#[must_use]
+	/// # As Str.
+	///
+	/// Return the (trimmed and normalized) value built so far.
+	pub fn as_str(&self) -> &str { &self.buf }
+
+	#[must_use]
+	/// # Length.
+	///
+	/// Return the byte length of the value built so far.
+	pub fn len(&self) -> usize { self.buf.len() }
+
+	#[must_use]
+	/// # Is Empty?
+	pub fn is_empty(&self) -> bool { self.buf.is_empty() }
+
+	#[must_use]
+	/// # Finish.
+	///
+	/// Consume the builder, returning the finished `String`.
+	pub fn finish(self) -> String { self.buf }
+}
+
+impl fmt::Write for NormalizedBuilder {
+	#[inline]
+	fn write_str(&mut self, s: &str) -> fmt::Result {
+		self.push_str(s);
+		Ok(())
+	}
+}
+
+impl From<NormalizedBuilder> for String {
+	#[inline]
+	fn from(src: NormalizedBuilder) -> Self { src.finish() }
+}
+
+
+
+/// # Write Fragment.
+///
+/// Write a normalized fragment to `f`, deferring whitespace until (and
+/// unless) something follows it.
+fn write_fragment<W: fmt::Write + ?Sized>(
+	f: &mut W,
+	src: &str,
+	started: &mut bool,
+	pending: &mut bool,
+) -> fmt::Result {
+	// Every chunk after the first was preceded by whitespace.
+	for (idx, next) in src.split(char::is_whitespace).enumerate() {
+		if idx != 0 { *pending = true; }
+		if ! next.is_empty() {
+			if *pending && *started { f.write_char(' ')?; }
+			f.write_str(next)?;
+			*pending = false;
+			*started = true;
+		}
+	}
+	Ok(())
+}
+
+
+
+#[derive(Clone, Copy)]
+/// # Normalized Display Wrapper.
+///
+/// This wrapper can be used to `Display` or `Debug` a string slice with its
+/// leading/trailing whitespace trimmed, and spans of _inner_ whitespace
+/// compacted/normalized to a single horizontal space, without allocating.
+///
+/// Its `Display` implementation writes the normalized text as-is, while its
+/// `Debug` implementation quotes and escapes it, mirroring `str`.
+///
+/// ## Examples
+///
+/// ```
+/// use std::fmt;
+/// use trimothy::NormalizedDisplay;
+///
+/// struct Record {
+///     id: u32,
+///     message: &'static str,
+/// }
+///
+/// impl fmt::Debug for Record {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         f.debug_struct("Record")
+///             .field("id", &self.id)
+///             .field("message", &NormalizedDisplay::new(self.message))
+///             .finish()
+///     }
+/// }
+///
+/// let record = Record { id: 1, message: "\n\tHello\n\tWorld!\n" };
+/// assert_eq!(
+///     format!("{record:?}"),
+///     r#"Record { id: 1, message: "Hello World!" }"#,
+/// );
+///
+/// // Display leaves out the quotes.
+/// assert_eq!(
+///     NormalizedDisplay::new("  Hello\n\tWorld!  ").to_string(),
+///     "Hello World!",
+/// );
+/// ```
+pub struct NormalizedDisplay<'a>(&'a str);
+
+impl fmt::Debug for NormalizedDisplay<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		use fmt::Write;
+
+		f.write_char('"')?;
+		let mut iter = self.0.split_whitespace();
+		if let Some(next) = iter.next() {
+			<EscapeDebug as fmt::Display>::fmt(&next.escape_debug(), f)?;
+			for next in iter {
+				f.write_char(' ')?;
+				<EscapeDebug as fmt::Display>::fmt(&next.escape_debug(), f)?;
+			}
+		}
+		f.write_char('"')
+	}
+}
+
+impl fmt::Display for NormalizedDisplay<'_> {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write_normalized(f, self.0)
+	}
+}
+
+impl<'a> From<&'a str> for NormalizedDisplay<'a> {
+	#[inline]
+	fn from(src: &'a str) -> Self { Self(src) }
+}
+
+impl<'a> NormalizedDisplay<'a> {
+	#[must_use]
+	/// # New.
+	///
+	/// Wrap a string slice for normalized display.
+	pub const fn new(src: &'a str) -> Self { Self(src) }
+
+	#[must_use]
+	/// # As Str.
+	///
+	/// Return the original, un-normalized string slice.
+	pub const fn as_str(&self) -> &'a str { self.0 }
+}
+
+#[must_use]
+/// # Normalized.
+///
+/// Wrap a string slice in a [`NormalizedDisplay`] for allocation-free,
+/// trimmed-and-normalized output, e.g. as an argument to `write!` or
+/// `format_args!`.
+///
+/// This is simply a terser alternative to [`NormalizedDisplay::new`].
+///
+/// ## Examples
+///
+/// ```
+/// use core::fmt::Write;
+/// use trimothy::normalized;
+///
+/// let mut log = String::new();
+/// let msg = "\n    Connection\n    reset  by peer\n";
+/// write!(&mut log, "[WARN] {}; retrying {:?}.", normalized(msg), normalized(" now ")).unwrap();
+/// assert_eq!(log, "[WARN] Connection reset by peer; retrying \"now\".");
+/// ```
+pub const fn normalized(src: &str) -> NormalizedDisplay<'_> { NormalizedDisplay(src) }
+
+
+
+#[derive(Debug, Clone, Copy)]
+/// # Whitespace Visualizer.
+///
+/// This wrapper — returned by [`show_whitespace`] — `Display`s a string
+/// slice with its whitespace made visible, which can be helpful when
+/// debugging (or asserting) trimming and normalization results.
+///
+/// | Whitespace | Symbol |
+/// | ---------- | ------ |
+/// | Space | `·` |
+/// | Tab | `→` |
+/// | Line Feed | `¶` |
+/// | Carriage Return | `␍` |
+///
+/// Any other whitespace is written as an escaped unicode sequence, e.g.
+/// `\u{3000}`. Non-whitespace is written as-is.
+pub struct ShowWhitespace<'a>(&'a str);
+
+impl fmt::Display for ShowWhitespace<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		use fmt::Write;
+
+		let mut rest = self.0;
+		while let Some(idx) = rest.find(char::is_whitespace) {
+			f.write_str(&rest[..idx])?;
+			let Some(c) = rest[idx..].chars().next() else { break; };
+			match c {
+				' ' => f.write_char('·')?,
+				'\t' => f.write_char('→')?,
+				'\n' => f.write_char('¶')?,
+				'\r' => f.write_char('␍')?,
+				_ => write!(f, "{}", c.escape_unicode())?,
+			}
+			rest = &rest[idx + c.len_utf8()..];
+		}
+		f.write_str(rest)
+	}
+}
+
+impl<'a> From<&'a str> for ShowWhitespace<'a> {
+	#[inline]
+	fn from(src: &'a str) -> Self { Self(src) }
+}
+
+impl<'a> ShowWhitespace<'a> {
+	#[must_use]
+	/// # As Str.
+	///
+	/// Return the original string slice.
+	pub const fn as_str(&self) -> &'a str { self.0 }
+}
+
+#[must_use]
+/// # Show Whitespace.
+///
+/// Wrap a string slice in a [`ShowWhitespace`] to `Display` it with
+/// visible whitespace symbols, e.g. for use in test assertion messages.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::show_whitespace;
+///
+/// assert_eq!(
+///     show_whitespace(" Hello\tWorld!\r\n").to_string(),
+///     "·Hello→World!␍¶",
+/// );
+///
+/// // Exotic whitespace is escaped.
+/// assert_eq!(
+///     show_whitespace("Hello\u{3000}World!").to_string(),
+///     "Hello\\u{3000}World!",
+/// );
+///
+/// // Handy for assertions!
+/// let a = "Hello World";
+/// let b = "Hello World";
+/// assert_eq!(a, b, "{} != {}", show_whitespace(a), show_whitespace(b));
+/// ```
+pub const fn show_whitespace(src: &str) -> ShowWhitespace<'_> { ShowWhitespace(src) }
+
+
+
+#[derive(Debug, Clone, Default)]
+/// # Trim Lines Writer.
+///
+/// This is a writer adapter that strips trailing spaces and tabs from every
+/// line written through it, for code generators and the like that need to
+/// produce lint-clean output without post-processing.
+///
+/// It implements [`fmt::Write`] and — with the `std` crate feature enabled —
+/// `std::io::Write`, wrapping writers of the same kind.
+///
+/// Runs of spaces, tabs, and carriage returns are held back until the writer
+/// knows what follows them: if it's more content, they're passed through
+/// as-is; if it's a newline, they're dropped, save for a `\r` immediately
+/// preceding the `\n`. Everything else is passed straight through.
+///
+/// Because trailing whitespace could always be followed by more content,
+/// flushing the writer does _not_ emit it. Any whitespace still held back
+/// when the writer is unwrapped via [`TrimLinesWriter::into_inner`] is
+/// treated as the end of the final line, and dropped.
+///
+/// ## Examples
+///
+/// ```
+/// use std::fmt::Write;
+/// use trimothy::TrimLinesWriter;
+///
+/// let mut w = TrimLinesWriter::new(String::new());
+/// writeln!(w, "fn main() {{  ").unwrap();
+/// write!(w, "\tprintln!(\"Hello\");\t").unwrap();
+/// write!(w, " \r\n}} ").unwrap();
+/// assert_eq!(w.into_inner(), "fn main() {\n\tprintln!(\"Hello\");\r\n}");
+/// ```
+pub struct TrimLinesWriter<W> {
+	/// # Writer.
+	inner: W,
+
+	/// # Pending Whitespace.
+	pending: String,
+}
+
+impl<W> TrimLinesWriter<W> {
+	#[must_use]
+	/// # New.
+	///
+	/// Wrap a writer.
+	pub const fn new(inner: W) -> Self {
+		Self { inner, pending: String::new() }
+	}
+
+	#[must_use]
+	/// # Get Reference.
+	///
+	/// Return a reference to the inner writer.
+	pub const fn get_ref(&self) -> &W { &self.inner }
+
+	#[must_use]
+	/// # Get Mutable Reference.
+	///
+	/// Return a mutable reference to the inner writer. Be careful writing to
+	/// it directly, as any pending whitespace will end up out of order.
+	pub fn get_mut(&mut self) -> &mut W { &mut self.inner }
+
+	#[must_use]
+	/// # Into Inner.
+	///
+	/// Unwrap and return the inner writer, dropping any trailing whitespace
+	/// still being held back.
+	pub fn into_inner(self) -> W { self.inner }
+
+	/// # Write With.
+	///
+	/// Process `src`, passing each finished segment to `emit` for writing.
+	/// Segments always begin and end on ASCII boundaries.
+	pub(crate) fn write_with<E, F>(&mut self, mut src: &[u8], mut emit: F)
+	-> Result<(), E>
+	where F: FnMut(&mut W, &[u8]) -> Result<(), E> {
+		while let Some(idx) = src.iter().position(|b| matches!(b, b' ' | b'\t' | b'\r' | b'\n')) {
+			// Content releases the pending whitespace.
+			if idx != 0 {
+				if ! self.pending.is_empty() {
+					emit(&mut self.inner, self.pending.as_bytes())?;
+					self.pending.truncate(0);
+				}
+				emit(&mut self.inner, &src[..idx])?;
+			}
+
+			// Newlines drop it.
+			if src[idx] == b'\n' {
+				let eol: &[u8] = if self.pending.ends_with('\r') { b"\r\n" } else { b"\n" };
+				self.pending.truncate(0);
+				emit(&mut self.inner, eol)?;
+			}
+			else { self.pending.push(char::from(src[idx])); }
+
+			src = &src[idx + 1..];
+		}
+
+		if ! src.is_empty() {
+			if ! self.pending.is_empty() {
+				emit(&mut self.inner, self.pending.as_bytes())?;
+				self.pending.truncate(0);
+			}
+			emit(&mut self.inner, src)?;
+		}
+
+		Ok(())
+	}
+}
+
+impl<W: fmt::Write> fmt::Write for TrimLinesWriter<W> {
+	fn write_str(&mut self, s: &str) -> fmt::Result {
+		self.write_with(s.as_bytes(), |w, seg|
+			// Segments are split on ASCII, so are always valid.
+			w.write_str(core::str::from_utf8(seg).map_err(|_| fmt::Error)?)
+		)
+	}
+}
+
+
+
+#[derive(Debug, Clone)]
+/// # Wrapped Lines.
+///
+/// This iterator — returned by [`wrapped_lines`] — yields the lines of a
+/// hard-wrapped string as borrowed slices of the original.
+pub struct WrappedLines<'a> {
+	/// # Remaining Source.
+	src: &'a str,
+
+	/// # Max Width (Chars).
+	width: usize,
+}
+
+impl<'a> Iterator for WrappedLines<'a> {
+	type Item = &'a str;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let src = self.src.trim_start();
+		if src.is_empty() {
+			self.src = src;
+			return None;
+		}
+
+		// Add words until we run out of room, always taking at least one.
+		let mut end = 0;
+		let mut line = 0;
+		let mut rest = src;
+		while ! rest.is_empty() {
+			let word = rest.find(char::is_whitespace).unwrap_or(rest.len());
+			let len = rest[..word].chars().count();
+			if end == 0 { line = len; }
+			else if line + 1 + len <= self.width { line += 1 + len; }
+			else { break; }
+
+			end = src.len() - rest.len() + word;
+			rest = rest[word..].trim_start();
+		}
+
+		self.src = &src[end..];
+		Some(&src[..end])
+	}
+}
+
+impl FusedIterator for WrappedLines<'_> {}
+
+#[must_use]
+/// # Wrapped Lines.
+///
+/// Hard-wrap an already-normalized string to lines no wider than `width`
+/// chars — following the same rules as [`write_wrapped`] — returning an
+/// iterator of borrowed line slices rather than allocating the whole
+/// wrapped result like [`wrap_normalized`] does.
+///
+/// Leading and trailing whitespace is skipped, but because each line is a
+/// slice of the original, any inner runs of whitespace are kept as-is, so
+/// normalize the source first if that matters.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::wrapped_lines;
+///
+/// let mut lines = wrapped_lines("The quick brown fox jumps over the lazy dog.", 16);
+/// assert_eq!(lines.next(), Some("The quick brown"));
+/// assert_eq!(lines.next(), Some("fox jumps over"));
+/// assert_eq!(lines.next(), Some("the lazy dog."));
+/// assert_eq!(lines.next(), None);
+///
+/// // Long words are left intact.
+/// assert_eq!(
+///     wrapped_lines("a Supercalifragilistic! b", 5).collect::<Vec<_>>(),
+///     ["a", "Supercalifragilistic!", "b"],
+/// );
+/// ```
+pub const fn wrapped_lines(src: &str, width: usize) -> WrappedLines<'_> {
+	WrappedLines { src, width }
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use alloc::{
+		format,
+		string::{
+			String,
+			ToString,
+		},
+		vec::Vec,
+	};
+	use crate::TrimNormal;
+	use fmt::Write;
+
+	#[test]
+	fn t_write_normalized() {
+		for (raw, expected) in [
+			("", ""),
+			(" \t\n", ""),
+			("hello", "hello"),
+			(" \u{2003}Hello\r\n\tWorld\u{3000}! ", "Hello World !"),
+		] {
+			let mut out = String::new();
+			write_normalized(&mut out, raw).unwrap();
+			assert_eq!(out, expected);
+
+			// The display wrapper should match.
+			out.truncate(0);
+			write!(&mut out, "{}", NormalizedDisplay::new(raw)).unwrap();
+			assert_eq!(out, expected);
+
+			// As should the shorthand.
+			out.truncate(0);
+			write!(&mut out, "{}", normalized(raw)).unwrap();
+			assert_eq!(out, expected);
+
+			// And debug should just add quotes.
+			out.truncate(0);
+			write!(&mut out, "{:?}", NormalizedDisplay::new(raw)).unwrap();
+			assert_eq!(out.len(), expected.len() + 2);
+			assert_eq!(out.trim_matches('"'), expected);
+		}
+
+		// Debug should escape the weird stuff.
+		let mut out = String::new();
+		write!(&mut out, "{:?}", NormalizedDisplay::new(" \"Quote\"\0 ")).unwrap();
+		assert_eq!(out, r#""\"Quote\"\0""#);
+	}
+
+	#[test]
+	fn t_show_whitespace() {
+		for (raw, expected) in [
+			("", ""),
+			("Hello", "Hello"),
+			(" ", "·"),
+			("\t\r\n", "→␍¶"),
+			(" Hello \t World \n", "·Hello·→·World·¶"),
+			("\u{2003}Björk\u{3000}", "\\u{2003}Björk\\u{3000}"),
+		] {
+			assert_eq!(show_whitespace(raw).to_string(), expected);
+		}
+	}
+
+	#[test]
+	fn t_trim_lines_writer() {
+		for (raw, expected) in [
+			("", ""),
+			("  \t", ""),
+			("Hello", "Hello"),
+			("Hello \nWorld\t\n", "Hello\nWorld\n"),
+			(" a \t b \r\n\r\n \n", " a \t b\r\n\r\n\n"),
+			("Björk\u{3000} \nGuðmundsdóttir ", "Björk\u{3000}\nGuðmundsdóttir"),
+			("a\r b\r", "a\r b"),
+		] {
+			// Char by char.
+			let mut w = TrimLinesWriter::new(String::new());
+			for c in raw.chars() { w.write_char(c).unwrap(); }
+			assert!(w.get_ref().len() <= expected.len(), "{raw:?}");
+			assert_eq!(w.into_inner(), expected, "{raw:?}");
+
+			// All at once.
+			let mut w = TrimLinesWriter::new(String::new());
+			w.write_str(raw).unwrap();
+			assert_eq!(w.into_inner(), expected, "{raw:?}");
+		}
+	}
+
+	#[test]
+	fn t_wrap_normalized() {
+		for (raw, width, expected) in [
+			("", 10, ""),
+			(" \t\n", 10, ""),
+			("Hello", 0, "Hello"),
+			("Hello World", 11, "Hello World"),
+			("Hello World", 10, "Hello\nWorld"),
+			(" Hello  World ", 80, "Hello World"),
+			("a b c d e f", 3, "a b\nc d\ne f"),
+			("Björk Guðmundsdóttir is great", 14, "Björk\nGuðmundsdóttir\nis great"),
+			("Björk Guð", 9, "Björk Guð"),
+			("one\ntwo\nthree four", 9, "one two\nthree\nfour"),
+		] {
+			let out = wrap_normalized(raw, width);
+			assert_eq!(out, expected, "{raw:?} {width}");
+			assert_eq!(matches!(out, Cow::Borrowed(_)), raw.trim() == expected, "{raw:?} {width}");
+
+			let mut out = String::new();
+			write_wrapped(&mut out, raw, width).unwrap();
+			assert_eq!(out, expected, "{raw:?} {width}");
+
+			let normal = raw.trim_and_normalize();
+			let mut lines = wrapped_lines(&normal, width);
+			assert_eq!(lines.by_ref().collect::<Vec<_>>().join("\n"), expected, "{raw:?} {width}");
+			assert_eq!(lines.next(), None);
+		}
+
+		// Inner whitespace is kept with un-normalized sources.
+		assert_eq!(
+			wrapped_lines("\n one\ttwo three  four \n", 9).collect::<Vec<_>>(),
+			["one\ttwo", "three", "four"],
+		);
+	}
+
+	#[test]
+	fn t_normalize_args() {
+		for (a, b) in [
+			("", ""),
+			(" ", "\t"),
+			("Hello", "World"),
+			(" Hello ", " World "),
+			("\u{3000}Björk\n", "\n\nGuðmundsdóttir\t"),
+			("a\n", "b"),
+			("a", "\nb"),
+		] {
+			let expected = format!("{a}{b}").trim_and_normalize();
+			assert_eq!(normalize_args(format_args!("{a}{b}")), expected, "{a:?} {b:?}");
+
+			let mut out = String::new();
+			write_normalized_args(&mut out, format_args!("{a}{b}")).unwrap();
+			assert_eq!(out, expected, "{a:?} {b:?}");
+		}
+
+		// Static strings too.
+		assert_eq!(normalize_args(format_args!(" Hello\n World ")), "Hello World");
+	}
+
+	#[test]
+	fn t_join_lines_normalized() {
+		for raw in [
+			"",
+			"\n\n",
+			"Hello",
+			"Hello\nWorld",
+			"  Hello  \r\n\t World\n",
+			"Hello\n\n\nWorld\n\n",
+			"\u{3000}Björk\n Guðmundsdóttir\u{a0}",
+		] {
+			let expected = raw.trim_and_normalize();
+			assert_eq!(join_lines_normalized(raw.lines()), expected, "{raw:?}");
+			assert_eq!(
+				join_lines_normalized(raw.lines().map(String::from)),
+				expected,
+				"{raw:?}",
+			);
+		}
+
+		// Lines should never run together.
+		assert_eq!(join_lines_normalized(["Hello", "World"]), "Hello World");
+		assert_eq!(join_lines_normalized(["Hello", "", " ", "World"]), "Hello World");
+	}
+
+	#[test]
+	fn t_normalized_builder() {
+		for parts in [
+			&[][..],
+			&[" ", "\t", ""],
+			&["Hello", "World"],
+			&["Hello ", " World"],
+			&[" Hello", "\n", "World "],
+			&["\u{3000}Bj", "örk\n", "\nGuð", "mundsdóttir\t"],
+		] {
+			let expected = parts.concat().trim_and_normalize();
+
+			let mut b = NormalizedBuilder::new();
+			for p in parts { b.push_str(p); }
+			assert_eq!(b.as_str(), expected, "{parts:?}");
+			assert_eq!(b.len(), expected.len(), "{parts:?}");
+
+			let mut b = NormalizedBuilder::with_capacity(10);
+			for p in parts {
+				for c in p.chars() { b.push(c); }
+			}
+			assert_eq!(String::from(b), expected, "{parts:?}");
+		}
+	}
+}
@@ -0,0 +1,144 @@
+/*!
+# Trimothy: Default Normalizer.
+*/
+
+use alloc::borrow::Cow;
+use crate::Normalizer;
+
+#[cfg(feature = "critical-section")]
+use core::cell::Cell;
+#[cfg(feature = "critical-section")]
+use critical_section::Mutex;
+#[cfg(not(feature = "critical-section"))]
+use std::sync::OnceLock;
+
+
+
+#[cfg(feature = "critical-section")]
+/// # Default Normalizer.
+static DEFAULT: Mutex<Cell<Option<Normalizer>>> = Mutex::new(Cell::new(None));
+
+#[cfg(not(feature = "critical-section"))]
+/// # Default Normalizer.
+static DEFAULT: OnceLock<Normalizer> = OnceLock::new();
+
+
+
+/// # Set Default Normalizer.
+///
+/// Configure the application-wide [`Normalizer`] used by
+/// [`normalize_default`], letting house normalization rules be set up once
+/// and used everywhere.
+///
+/// The default can only be set once; subsequent attempts are rejected,
+/// returning the unused normalizer as the error.
+///
+/// This function is only available when the `std` or `critical-section`
+/// crate feature is enabled. (The latter works in `no_std` environments,
+/// provided a `critical-section` implementation is linked in.)
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::{
+///     normalize_default,
+///     Normalizer,
+///     set_default_normalizer,
+///     WsKind,
+///     WsPolicy,
+/// };
+///
+/// // Until set, this works just like TrimNormal.
+/// assert_eq!(normalize_default(" Hello\nWorld "), "Hello World");
+///
+/// // Keep newlines from now on.
+/// let normalizer = Normalizer::new().with_policy(WsKind::Newline, WsPolicy::Keep);
+/// assert!(set_default_normalizer(normalizer).is_ok());
+/// assert_eq!(normalize_default(" Hello\nWorld "), "Hello\nWorld");
+///
+/// // Once set, it's set.
+/// assert!(set_default_normalizer(Normalizer::new()).is_err());
+/// ```
+///
+/// ## Errors
+///
+/// If a default has already been set, the normalizer is returned as an
+/// error.
+pub fn set_default_normalizer(normalizer: Normalizer) -> Result<(), Normalizer> {
+	#[cfg(feature = "critical-section")]
+	{
+		critical_section::with(|cs| {
+			let cell = DEFAULT.borrow(cs);
+			if cell.get().is_some() { Err(normalizer) }
+			else {
+				cell.set(Some(normalizer));
+				Ok(())
+			}
+		})
+	}
+
+	#[cfg(not(feature = "critical-section"))]
+	{ DEFAULT.set(normalizer) }
+}
+
+#[must_use]
+/// # Default Normalizer.
+///
+/// Return the application-wide [`Normalizer`] set via
+/// [`set_default_normalizer`], or [`Normalizer::new`] if none has been set.
+///
+/// This function is only available when the `std` or `critical-section`
+/// crate feature is enabled.
+pub fn default_normalizer() -> Normalizer {
+	#[cfg(feature = "critical-section")]
+	{ critical_section::with(|cs| DEFAULT.borrow(cs).get()).unwrap_or_default() }
+
+	#[cfg(not(feature = "critical-section"))]
+	{ DEFAULT.get().copied().unwrap_or_default() }
+}
+
+#[must_use]
+/// # Normalize (Default).
+///
+/// Normalize a string slice using the application-wide
+/// [`default_normalizer`].
+///
+/// This function is only available when the `std` or `critical-section`
+/// crate feature is enabled.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::normalize_default;
+///
+/// assert_eq!(normalize_default("\tHello\u{3000}World! "), "Hello World!");
+/// ```
+pub fn normalize_default(src: &str) -> Cow<'_, str> {
+	default_normalizer().normalize(src)
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::{
+		WsKind,
+		WsPolicy,
+	};
+
+	#[test]
+	fn t_default_normalizer() {
+		assert_eq!(default_normalizer(), Normalizer::new());
+		assert_eq!(normalize_default(" Hello\t\tWorld "), "Hello World");
+
+		let custom = Normalizer::new().with_policy(WsKind::Tab, WsPolicy::Replace('→'));
+		assert_eq!(set_default_normalizer(custom), Ok(()));
+		assert_eq!(default_normalizer(), custom);
+		assert_eq!(normalize_default(" Hello\t\tWorld "), "Hello→→World");
+
+		// No takebacks.
+		assert_eq!(set_default_normalizer(Normalizer::new()), Err(Normalizer::new()));
+		assert_eq!(default_normalizer(), custom);
+	}
+}
@@ -0,0 +1,205 @@
+/*!
+# Trimothy: Grapheme-Safe Trimming.
+*/
+
+use crate::pattern::MatchPattern;
+
+
+
+/// # Grapheme-Safe Trimming.
+///
+/// The [`TrimGraphemeMatches`] trait brings combining-mark-aware match-based
+/// trimming to string slices (and anything that dereferences to one).
+///
+/// The standard library's `trim_matches` and friends work one `char` at a
+/// time, so trimming letters can easily separate a base character from the
+/// combining mark(s) attached to it, leaving `"é"` (`e` + `U+0301`) half
+/// gone, or an orphaned accent floating at the edge of the result.
+///
+/// The methods here instead treat each base character _and_ its combining
+/// marks as a single unit, trimming the unit only if every `char` within it
+/// matches the pattern.
+///
+/// | Method | Description |
+/// | ------ | ----------- |
+/// | `trim_matches_grapheme` | Trim arbitrary leading and trailing chars, without splitting combining sequences. |
+/// | `trim_start_matches_grapheme` | Trim arbitrary leading chars, without splitting combining sequences. |
+/// | `trim_end_matches_grapheme` | Trim arbitrary trailing chars, without splitting combining sequences. |
+///
+/// Combining marks are recognized by block: Combining Diacritical Marks
+/// (and their Extended/Supplement/For Symbols siblings), Combining Half
+/// Marks, and Variation Selectors.
+///
+/// Each of the match methods accept either:
+/// * A single `char`;
+/// * An array or slice of `char`;
+/// * A `&BTreeSet<char>`;
+/// * A callback with the signature `Fn(char) -> bool`;
+pub trait TrimGraphemeMatches {
+	/// # Trim Matches (Grapheme-Safe).
+	///
+	/// Trim arbitrary leading and trailing chars as determined by the
+	/// provided pattern, without separating any base characters from their
+	/// combining marks.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::TrimGraphemeMatches;
+	///
+	/// let s = "e\u{301}cole cafe\u{301}";
+	///
+	/// // The naive approach leaves orphaned accents.
+	/// assert_eq!(s.trim_matches('e'), "\u{301}cole cafe\u{301}");
+	///
+	/// // This doesn't.
+	/// assert_eq!(s.trim_matches_grapheme('e'), s);
+	/// assert_eq!(
+	///     s.trim_matches_grapheme(|c: char| c != ' '),
+	///     " ",
+	/// );
+	/// ```
+	fn trim_matches_grapheme<P: MatchPattern<char>>(&self, pat: P) -> &str;
+
+	/// # Trim Start Matches (Grapheme-Safe).
+	///
+	/// Trim arbitrary leading chars as determined by the provided pattern,
+	/// without separating any base characters from their combining marks.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::TrimGraphemeMatches;
+	///
+	/// assert_eq!("xxe\u{301}".trim_start_matches_grapheme(['x', 'e']), "e\u{301}");
+	/// ```
+	fn trim_start_matches_grapheme<P: MatchPattern<char>>(&self, pat: P) -> &str;
+
+	/// # Trim End Matches (Grapheme-Safe).
+	///
+	/// Trim arbitrary trailing chars as determined by the provided pattern,
+	/// without separating any base characters from their combining marks.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::TrimGraphemeMatches;
+	///
+	/// // Strip trailing punctuation, including a stray mark.
+	/// let s = "cafe\u{301}!\u{301}";
+	/// assert_eq!(s.trim_end_matches(|c: char| ! c.is_alphabetic()), "cafe");
+	/// assert_eq!(
+	///     s.trim_end_matches_grapheme(|c: char| ! c.is_alphabetic()),
+	///     "cafe\u{301}",
+	/// );
+	/// ```
+	fn trim_end_matches_grapheme<P: MatchPattern<char>>(&self, pat: P) -> &str;
+}
+
+impl TrimGraphemeMatches for str {
+	#[inline]
+	fn trim_matches_grapheme<P: MatchPattern<char>>(&self, pat: P) -> &str {
+		self.trim_start_matches_grapheme(pat).trim_end_matches_grapheme(pat)
+	}
+
+	fn trim_start_matches_grapheme<P: MatchPattern<char>>(&self, pat: P) -> &str {
+		let mut src = self;
+		loop {
+			// The leading char has to match.
+			let mut chars = src.chars();
+			let Some(first) = chars.next() else { break; };
+			if ! pat.is_match(first) { break; }
+
+			// As do any marks attached to it.
+			let rest = chars.as_str();
+			let marks = rest.find(|c: char| ! is_combining(c)).unwrap_or(rest.len());
+			if ! rest[..marks].chars().all(|c| pat.is_match(c)) { break; }
+
+			src = &rest[marks..];
+		}
+
+		src
+	}
+
+	fn trim_end_matches_grapheme<P: MatchPattern<char>>(&self, pat: P) -> &str {
+		let mut src = self;
+		loop {
+			// Trailing marks have to match.
+			let base = src.trim_end_matches(is_combining);
+			if ! src[base.len()..].chars().all(|c| pat.is_match(c)) { break; }
+
+			// As does the char they're attached to, if any.
+			let mut chars = base.chars();
+			match chars.next_back() {
+				Some(c) if pat.is_match(c) => { src = chars.as_str(); },
+				Some(_) => break,
+				// Orphaned marks can go on their own.
+				None => {
+					src = base;
+					break;
+				},
+			}
+		}
+
+		src
+	}
+}
+
+
+
+#[inline]
+/// # Is Combining Mark?
+///
+/// Returns `true` if the char belongs to one of the combining mark (or
+/// variation selector) blocks.
+const fn is_combining(c: char) -> bool {
+	matches!(
+		c,
+		'\u{0300}'..='\u{036f}' |
+		'\u{1ab0}'..='\u{1aff}' |
+		'\u{1dc0}'..='\u{1dff}' |
+		'\u{20d0}'..='\u{20ff}' |
+		'\u{fe00}'..='\u{fe0f}' |
+		'\u{fe20}'..='\u{fe2f}'
+	)
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn t_trim_matches_grapheme() {
+		let not_alpha = |c: char| ! c.is_alphabetic();
+		for (raw, start, end) in [
+			("", "", ""),
+			("e\u{301}", "e\u{301}", "e\u{301}"),
+			("eee\u{301}", "e\u{301}", "eee\u{301}"),
+			("e\u{301}eee", "e\u{301}eee", "e\u{301}"),
+			("\u{301}e", "\u{301}e", "\u{301}"),
+			("e\u{301}\u{302}x", "e\u{301}\u{302}x", "e\u{301}\u{302}x"),
+			("eeee", "", ""),
+		] {
+			assert_eq!(raw.trim_start_matches_grapheme('e'), start, "{raw:?}");
+			assert_eq!(raw.trim_end_matches_grapheme('e'), end, "{raw:?}");
+			assert_eq!(
+				raw.trim_matches_grapheme('e'),
+				start.trim_end_matches_grapheme('e'),
+				"{raw:?}",
+			);
+		}
+
+		// Marks that match go with their base.
+		assert_eq!("..e\u{301}..".trim_matches_grapheme(['.', 'e', '\u{301}']), "");
+		assert_eq!("..e\u{301}..".trim_matches_grapheme(['.', 'e']), "e\u{301}");
+		assert_eq!("..e\u{301}..".trim_matches_grapheme(['.', '\u{301}']), "e\u{301}");
+
+		// Orphaned marks are their own units.
+		assert_eq!("\u{301}\u{302}abc!".trim_matches_grapheme(not_alpha), "abc");
+		assert_eq!("abc!\u{301}".trim_matches_grapheme(not_alpha), "abc");
+		assert_eq!("!\u{301}".trim_end_matches_grapheme(not_alpha), "");
+		assert_eq!("\u{301}".trim_end_matches_grapheme(not_alpha), "");
+	}
+}
@@ -0,0 +1,224 @@
+/*!
+# Trimothy: Interning.
+*/
+
+use alloc::{
+	collections::BTreeSet,
+	sync::Arc,
+};
+use crate::Normalizer;
+
+
+
+#[derive(Debug, Clone, Default)]
+/// # Normalized String Interner.
+///
+/// This is a simple string interner keyed by _normalized_ content: every
+/// string passed to [`NormalizedInterner::intern`] is trimmed and normalized
+/// first, and equivalent values — `" Hello\tWorld"`, `"Hello  World\n"`,
+/// etc. — all come back as clones of the same shared `Arc<str>` handle.
+///
+/// This makes light work of deduplicating messy user input at ingestion
+/// time.
+///
+/// By default normalization works just like
+/// [`TrimNormal`](crate::TrimNormal), but a custom [`Normalizer`] can be used
+/// instead via [`NormalizedInterner::with_normalizer`].
+///
+/// Interning requires `&mut self`; wrap the interner in a mutex or similar
+/// to share it between threads.
+///
+/// This struct is only available when the `intern` crate feature is
+/// enabled.
+///
+/// ## Examples
+///
+/// ```
+/// use std::sync::Arc;
+/// use trimothy::NormalizedInterner;
+///
+/// let mut interner = NormalizedInterner::new();
+/// let a = interner.intern(" Hello\tWorld");
+/// let b = interner.intern("Hello  World\n");
+/// assert_eq!(&*a, "Hello World");
+/// assert!(Arc::ptr_eq(&a, &b));
+/// assert_eq!(interner.len(), 1);
+///
+/// // Lookups are normalized too.
+/// assert!(interner.contains("\u{3000}Hello World"));
+/// assert!(interner.get("Goodbye World").is_none());
+/// ```
+pub struct NormalizedInterner {
+	/// # Interned Values.
+	set: BTreeSet<Arc<str>>,
+
+	/// # Normalizer.
+	normalizer: Normalizer,
+}
+
+/// ## Setup.
+impl NormalizedInterner {
+	#[must_use]
+	/// # New.
+	///
+	/// Return a new, empty interner using the default normalization.
+	pub const fn new() -> Self { Self::with_normalizer(Normalizer::new()) }
+
+	#[must_use]
+	/// # With Normalizer.
+	///
+	/// Return a new, empty interner using a custom [`Normalizer`].
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::{NormalizedInterner, Normalizer, WsKind, WsPolicy};
+	///
+	/// let mut interner = NormalizedInterner::with_normalizer(
+	///     Normalizer::new().with_policy(WsKind::Newline, WsPolicy::Keep),
+	/// );
+	/// assert_eq!(&*interner.intern(" Hello\nWorld "), "Hello\nWorld");
+	/// ```
+	pub const fn with_normalizer(normalizer: Normalizer) -> Self {
+		Self { set: BTreeSet::new(), normalizer }
+	}
+}
+
+/// ## Getters.
+impl NormalizedInterner {
+	#[must_use]
+	/// # Normalizer.
+	pub const fn normalizer(&self) -> Normalizer { self.normalizer }
+
+	#[must_use]
+	/// # Length.
+	///
+	/// Return the number of unique (normalized) values interned.
+	pub fn len(&self) -> usize { self.set.len() }
+
+	#[must_use]
+	/// # Is Empty?
+	pub fn is_empty(&self) -> bool { self.set.is_empty() }
+
+	#[must_use]
+	/// # Contains?
+	///
+	/// Returns `true` if the normalized form of `src` has been interned.
+	pub fn contains(&self, src: &str) -> bool {
+		self.set.contains(&*self.normalizer.normalize(src))
+	}
+
+	#[must_use]
+	/// # Get.
+	///
+	/// Return the shared handle for the normalized form of `src`, if it has
+	/// been interned.
+	pub fn get(&self, src: &str) -> Option<Arc<str>> {
+		self.set.get(&*self.normalizer.normalize(src)).cloned()
+	}
+
+	/// # Iterate.
+	///
+	/// Return an iterator over the interned values, in sorted order.
+	pub fn iter(&self) -> impl Iterator<Item=&Arc<str>> { self.set.iter() }
+}
+
+/// ## Interning.
+impl NormalizedInterner {
+	/// # Intern.
+	///
+	/// Normalize `src` and return the shared handle for the result, adding
+	/// it to the interner if it isn't already there.
+	///
+	/// Nothing is allocated for values that have already been interned,
+	/// unless normalization itself requires it.
+	pub fn intern(&mut self, src: &str) -> Arc<str> {
+		let normal = self.normalizer.normalize(src);
+		if let Some(out) = self.set.get(&*normal) { return Arc::clone(out); }
+
+		let out = Arc::<str>::from(normal);
+		self.set.insert(Arc::clone(&out));
+		out
+	}
+
+	/// # Purge.
+	///
+	/// Drop any values no longer referenced outside the interner, returning
+	/// the number removed.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::NormalizedInterner;
+	///
+	/// let mut interner = NormalizedInterner::new();
+	/// let keep = interner.intern("Hello World");
+	/// let _ = interner.intern("Goodbye World");
+	/// assert_eq!(interner.purge(), 1);
+	/// assert_eq!(interner.len(), 1);
+	/// assert!(interner.contains(&keep));
+	/// ```
+	pub fn purge(&mut self) -> usize {
+		let before = self.set.len();
+		self.set.retain(|v| Arc::strong_count(v) > 1);
+		before - self.set.len()
+	}
+
+	/// # Clear.
+	///
+	/// Remove all values from the interner. (Outstanding handles remain
+	/// valid.)
+	pub fn clear(&mut self) { self.set.clear(); }
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::{
+		WsKind,
+		WsPolicy,
+	};
+
+	#[test]
+	fn t_interner() {
+		let mut interner = NormalizedInterner::new();
+		assert!(interner.is_empty());
+		assert_eq!(interner.normalizer(), Normalizer::new());
+
+		let a = interner.intern("Hello World");
+		for raw in [" Hello World", "Hello\t\tWorld", "\r\nHello\u{3000}World\n"] {
+			let b = interner.intern(raw);
+			assert!(Arc::ptr_eq(&a, &b), "{raw:?}");
+			assert!(interner.contains(raw), "{raw:?}");
+			assert!(interner.get(raw).is_some_and(|c| Arc::ptr_eq(&a, &c)), "{raw:?}");
+		}
+		assert_eq!(interner.len(), 1);
+
+		// Something different.
+		let c = interner.intern(" Björk ");
+		assert_eq!(&*c, "Björk");
+		assert!(! Arc::ptr_eq(&a, &c));
+		assert_eq!(interner.len(), 2);
+		assert!(interner.iter().map(|v| &**v).eq(["Björk", "Hello World"]));
+
+		// Purging should leave referenced values alone.
+		drop(c);
+		assert_eq!(interner.purge(), 1);
+		assert_eq!(interner.len(), 1);
+		assert!(interner.contains("Hello World"));
+		assert!(! interner.contains("Björk"));
+
+		interner.clear();
+		assert!(interner.is_empty());
+		assert_eq!(&*a, "Hello World");
+
+		// Custom normalizers.
+		let mut interner = NormalizedInterner::with_normalizer(
+			Normalizer::new().with_policy(WsKind::Tab, WsPolicy::Replace('→')),
+		);
+		assert_eq!(&*interner.intern(" Hello\tWorld "), "Hello→World");
+		assert!(! interner.contains("Hello World"));
+	}
+}
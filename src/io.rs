@@ -0,0 +1,497 @@
+/*!
+# Trimothy: Streaming I/O.
+*/
+
+use crate::{
+	ByteSet,
+	MatchPattern,
+	TrimLinesWriter,
+	trim_normal::{
+		TrimNormalIter,
+		TrimNormalMaxIter,
+	},
+};
+use std::{
+	fs::File,
+	io::{
+		BufRead,
+		ErrorKind,
+		Read,
+		Result,
+		Write,
+	},
+	path::Path,
+};
+
+
+
+/// # Buffer Size.
+///
+/// The size of the (fixed) input and output buffers.
+const BUF_SIZE: usize = 8192;
+
+/// # ASCII Whitespace.
+const ASCII_WS: ByteSet = ByteSet::from_bytes(b"\t\n\x0C\r ");
+
+
+
+#[derive(Debug, Clone)]
+/// # Skip Leading Whitespace.
+///
+/// This is a tiny [`BufRead`] wrapper that skips over any leading ASCII
+/// whitespace — or bytes matching a custom [`MatchPattern`] — at the start of
+/// the stream, then passes everything else through unchanged.
+///
+/// It's handy for parsers that need to peek past padding without paying for
+/// a full [`normalize_reader`] pass. (Plain [`Read`] sources can be wrapped
+/// in a [`std::io::BufReader`] first.)
+///
+/// ## Examples
+///
+/// ```
+/// use std::io::{BufRead, Read};
+/// use trimothy::SkipLeadingWs;
+///
+/// let mut reader = SkipLeadingWs::new(&b"\n\n  {\"hello\": \"world\"} \n"[..]);
+/// assert_eq!(reader.fill_buf().unwrap().first(), Some(&b'{'));
+///
+/// let mut out = String::new();
+/// reader.read_to_string(&mut out).unwrap();
+/// assert_eq!(out, "{\"hello\": \"world\"} \n");
+///
+/// // Custom patterns work too.
+/// let mut reader = SkipLeadingWs::with_pattern(&b"0007"[..], b'0');
+/// let mut out = String::new();
+/// reader.read_to_string(&mut out).unwrap();
+/// assert_eq!(out, "7");
+/// ```
+pub struct SkipLeadingWs<R, P = ByteSet> {
+	/// # Reader.
+	inner: R,
+
+	/// # Pattern.
+	pat: P,
+
+	/// # Still Skipping?
+	skipping: bool,
+}
+
+impl<R: BufRead> SkipLeadingWs<R> {
+	#[must_use]
+	/// # New.
+	///
+	/// Wrap a reader, skipping its leading ASCII whitespace.
+	pub const fn new(inner: R) -> Self {
+		Self { inner, pat: ASCII_WS, skipping: true }
+	}
+}
+
+impl<R: BufRead, P: MatchPattern<u8>> SkipLeadingWs<R, P> {
+	#[must_use]
+	/// # With Pattern.
+	///
+	/// Wrap a reader, skipping its leading bytes matching `pat`.
+	pub const fn with_pattern(inner: R, pat: P) -> Self {
+		Self { inner, pat, skipping: true }
+	}
+
+	#[must_use]
+	/// # Get Reference.
+	///
+	/// Return a reference to the inner reader.
+	pub const fn get_ref(&self) -> &R { &self.inner }
+
+	#[must_use]
+	/// # Into Inner.
+	///
+	/// Unwrap and return the inner reader.
+	pub fn into_inner(self) -> R { self.inner }
+
+	/// # Skip.
+	///
+	/// Consume leading matches from the inner reader, if we haven't already.
+	fn skip(&mut self) -> Result<()> {
+		while self.skipping {
+			let buf = match self.inner.fill_buf() {
+				Ok(buf) => buf,
+				Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+				Err(e) => return Err(e),
+			};
+
+			// Skip what we can; if that's everything, we'll need more.
+			let len = buf.iter().position(|&b| ! self.pat.is_match(b));
+			if len.is_some() || buf.is_empty() { self.skipping = false; }
+			let len = len.unwrap_or(buf.len());
+			self.inner.consume(len);
+		}
+		Ok(())
+	}
+}
+
+impl<R: BufRead, P: MatchPattern<u8>> Read for SkipLeadingWs<R, P> {
+	fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+		self.skip()?;
+		self.inner.read(buf)
+	}
+}
+
+impl<R: BufRead, P: MatchPattern<u8>> BufRead for SkipLeadingWs<R, P> {
+	fn fill_buf(&mut self) -> Result<&[u8]> {
+		self.skip()?;
+		self.inner.fill_buf()
+	}
+
+	fn consume(&mut self, amt: usize) { self.inner.consume(amt); }
+}
+
+
+
+impl<W: Write> Write for TrimLinesWriter<W> {
+	fn write(&mut self, buf: &[u8]) -> Result<usize> {
+		self.write_with(buf, Write::write_all)?;
+		Ok(buf.len())
+	}
+
+	/// # Flush.
+	///
+	/// Flush the inner writer. Pending whitespace is not written.
+	fn flush(&mut self) -> Result<()> { self.get_mut().flush() }
+}
+
+
+
+#[derive(Debug, Clone)]
+/// # Byte Iterator Reader.
+///
+/// This adapter wraps any `Iterator<Item=u8>` — such as those returned by
+/// [`TrimNormalBytes::trim_and_normalize`](crate::TrimNormalBytes::trim_and_normalize)
+/// — exposing it as a [`Read`]er, so normalized bytes can be fed to hashers,
+/// compressors, uploaders, etc., without collecting them into a `Vec<u8>`
+/// first.
+///
+/// The normalizing iterators can be converted directly via their
+/// `into_reader` methods.
+///
+/// ## Examples
+///
+/// ```
+/// use std::io::Read;
+/// use trimothy::TrimNormalBytes;
+///
+/// let mut reader = b"  Hello\t\tWorld\n".iter()
+///     .trim_and_normalize()
+///     .into_reader();
+///
+/// let mut out = String::new();
+/// reader.read_to_string(&mut out).unwrap();
+/// assert_eq!(out, "Hello World");
+/// ```
+pub struct IterReader<I: Iterator<Item=u8>>(I);
+
+impl<I: Iterator<Item=u8>> IterReader<I> {
+	#[must_use]
+	/// # New.
+	pub const fn new(iter: I) -> Self { Self(iter) }
+
+	#[must_use]
+	/// # Into Inner.
+	///
+	/// Unwrap and return the inner iterator.
+	pub fn into_inner(self) -> I { self.0 }
+}
+
+impl<I: Iterator<Item=u8>> Read for IterReader<I> {
+	/// # Read.
+	///
+	/// Fill the buffer with bytes pulled from the iterator, returning the
+	/// number written. Zero means the iterator is exhausted.
+	fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+		let mut len = 0;
+		for (slot, b) in buf.iter_mut().zip(self.0.by_ref()) {
+			*slot = b;
+			len += 1;
+		}
+		Ok(len)
+	}
+}
+
+/// # Helper: Into Reader.
+macro_rules! into_reader {
+	($($ty:ident),+) => ($(
+		impl<I: Iterator<Item=u8>> $ty<u8, I> {
+			#[must_use]
+			/// # Into Reader.
+			///
+			/// Wrap the iterator in an [`IterReader`], making it usable
+			/// anywhere a [`Read`] is expected.
+			pub const fn into_reader(self) -> IterReader<Self> { IterReader::new(self) }
+		}
+	)+);
+}
+
+into_reader!(TrimNormalIter, TrimNormalMaxIter);
+
+
+
+/// # Normalize Reader.
+///
+/// Stream the contents of a reader through the equivalent of
+/// [`TrimNormal`](crate::TrimNormal) — trimming the leading/trailing ASCII
+/// whitespace and compacting spans of inner whitespace to a single
+/// horizontal space — and into a writer, using a small, fixed pair of
+/// working buffers regardless of the input size.
+///
+/// Whitespace spanning chunk boundaries is handled correctly, and because
+/// only ASCII whitespace is considered, UTF-8 content passes through
+/// unharmed.
+///
+/// Returns the total number of bytes written.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::normalize_reader;
+///
+/// let mut out = Vec::new();
+/// let len = normalize_reader(&b"  Hello\r\n\tWorld!\n"[..], &mut out).unwrap();
+/// assert_eq!(len, 12);
+/// assert_eq!(out, b"Hello World!");
+/// ```
+///
+/// ## Errors
+///
+/// Any read or write errors are passed through as-is, except for
+/// [`ErrorKind::Interrupted`], which is retried.
+pub fn normalize_reader<R: Read, W: Write>(mut reader: R, mut writer: W)
+-> Result<u64> {
+	let mut inbuf = [0_u8; BUF_SIZE];
+	let mut outbuf = [0_u8; BUF_SIZE];
+	let mut outlen = 0;
+	let mut total = 0;
+	let mut read = 0;
+	let mut skipped = 0;
+
+	#[cfg(feature = "tracing")]
+	let _span = tracing::debug_span!(target: "trimothy", "normalize_reader").entered();
+
+	// Whitespace is only ever written once something follows it, so we
+	// need to carry some state across chunks.
+	let mut started = false;
+	let mut pending = false;
+
+	loop {
+		let len = match reader.read(&mut inbuf) {
+			Ok(0) => break,
+			Ok(len) => len,
+			Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+			Err(e) => return Err(e),
+		};
+		read += len as u64;
+
+		for &b in &inbuf[..len] {
+			if b.is_ascii_whitespace() {
+				if started { pending = true; }
+				else { skipped += 1; }
+				continue;
+			}
+
+			// Make sure there's room for a space and the byte.
+			if BUF_SIZE - 2 < outlen {
+				writer.write_all(&outbuf[..outlen])?;
+				total += outlen as u64;
+				outlen = 0;
+			}
+
+			if pending {
+				outbuf[outlen] = b' ';
+				outlen += 1;
+				pending = false;
+			}
+			outbuf[outlen] = b;
+			outlen += 1;
+			if ! started {
+				started = true;
+				trace_debug!("normalize_reader: content started", skipped = skipped);
+			}
+		}
+	}
+
+	// Flush what's left.
+	if outlen != 0 {
+		writer.write_all(&outbuf[..outlen])?;
+		total += outlen as u64;
+	}
+	writer.flush()?;
+
+	trace_debug!(
+		"normalize_reader: finished",
+		read = read,
+		written = total,
+		removed = read - total,
+	);
+	Ok(total)
+}
+
+/// # Normalize File.
+///
+/// Open the file at `path` and stream it through [`normalize_reader`] into
+/// the writer, returning the total number of bytes written.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use trimothy::normalize_file;
+///
+/// let mut out = std::io::stdout().lock();
+/// normalize_file("/path/to/huge.txt", &mut out).unwrap();
+/// ```
+///
+/// ## Errors
+///
+/// This will return an error if the file cannot be opened or read, or if
+/// the writer cannot be written to.
+pub fn normalize_file<P: AsRef<Path>, W: Write>(path: P, writer: W)
+-> Result<u64> {
+	let file = File::open(path)?;
+	normalize_reader(file, writer)
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::TrimNormal;
+	use std::{
+		io::BufReader,
+		string::String,
+		vec::Vec,
+	};
+
+	/// # Trickle Reader.
+	///
+	/// Return at most `n` bytes at a time, with an interruption thrown in for
+	/// good measure.
+	struct Trickle<'a> {
+		/// # Source.
+		src: &'a [u8],
+
+		/// # Chunk Size.
+		n: usize,
+
+		/// # Interrupt?
+		interrupt: bool,
+	}
+
+	impl Read for Trickle<'_> {
+		fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+			self.interrupt = ! self.interrupt;
+			if self.interrupt { return Err(ErrorKind::Interrupted.into()); }
+
+			let len = self.n.min(buf.len()).min(self.src.len());
+			buf[..len].copy_from_slice(&self.src[..len]);
+			self.src = &self.src[len..];
+			Ok(len)
+		}
+	}
+
+	#[test]
+	fn t_normalize_reader() {
+		let mut big = Vec::new();
+		for i in 0..BUF_SIZE {
+			big.extend_from_slice(b"Hello");
+			big.resize(big.len() + i % 5, b' ');
+			big.extend_from_slice("Björk\r\n".as_bytes());
+		}
+
+		for raw in [
+			&b""[..], b"   ", b"Hello", b" \t Hello  World \n\n", b"a b  c\t\td",
+			big.as_slice(),
+		] {
+			let expected = raw.trim_and_normalize();
+			for n in [1, 2, 3, 7, BUF_SIZE, BUF_SIZE * 3] {
+				let mut out = Vec::new();
+				let len = normalize_reader(
+					Trickle { src: raw, n, interrupt: false },
+					&mut out,
+				).unwrap();
+				assert_eq!(len, out.len() as u64);
+				assert!(out == *expected, "Chunk size {n}.");
+			}
+		}
+	}
+
+	#[test]
+	fn t_iter_read() {
+		use crate::TrimNormalBytes;
+
+		let raw = b"  Hello\t\t\tWorld \r\n and   Moon\n";
+		let mut out = String::new();
+		raw.iter().trim_and_normalize().into_reader().read_to_string(&mut out).unwrap();
+		assert_eq!(out, "Hello World and Moon");
+
+		let mut out = String::new();
+		raw.iter().normalized_whitespace_max(2).into_reader().read_to_string(&mut out).unwrap();
+		assert_eq!(out, "Hello  World  and  Moon");
+
+		// Small buffers should work too.
+		let mut iter = raw.iter().trim_and_normalize().into_reader();
+		let mut buf = [0_u8; 3];
+		let mut out = Vec::new();
+		loop {
+			let len = iter.read(&mut buf).unwrap();
+			if len == 0 { break; }
+			out.extend_from_slice(&buf[..len]);
+		}
+		assert_eq!(out, b"Hello World and Moon");
+
+		// And so should copies.
+		let mut out = Vec::new();
+		let len = std::io::copy(&mut IterReader::new(raw.iter().copied()), &mut out).unwrap();
+		assert_eq!(len, raw.len() as u64);
+		assert_eq!(out, raw);
+
+		let mut out = Vec::new();
+		let len = std::io::copy(&mut raw.iter().trim_and_normalize().into_reader(), &mut out).unwrap();
+		assert_eq!(len, 20);
+		assert_eq!(out, b"Hello World and Moon");
+	}
+
+	#[test]
+	fn t_skip_leading_ws() {
+		for (raw, expected) in [
+			("", ""),
+			(" \t\n", ""),
+			("Hello", "Hello"),
+			("\n\n  Hello World \n", "Hello World \n"),
+			(" \u{a0}Björk", "\u{a0}Björk"),
+		] {
+			for n in [1, 2, 3, 7, BUF_SIZE] {
+				let trickle = Trickle { src: raw.as_bytes(), n, interrupt: false };
+				let mut out = String::new();
+				SkipLeadingWs::new(BufReader::with_capacity(n, trickle))
+					.read_to_string(&mut out)
+					.unwrap();
+				assert_eq!(out, expected, "Chunk size {n}.");
+			}
+		}
+
+		// Custom pattern, line reading.
+		let mut reader = SkipLeadingWs::with_pattern(&b"--\n-Hello\nWorld"[..], |b: u8| b == b'-' || b == b'\n');
+		let mut line = String::new();
+		reader.read_line(&mut line).unwrap();
+		assert_eq!(line, "Hello\n");
+		assert_eq!(reader.lines().next().unwrap().unwrap(), "World");
+	}
+
+	#[test]
+	fn t_trim_lines_writer() {
+		let raw = b"fn main() {  \r\n\tlet x = 1;\t\n\n \t\n}\t \t";
+		for n in 1..raw.len() {
+			let mut w = TrimLinesWriter::new(Vec::new());
+			for chunk in raw.chunks(n) { w.write_all(chunk).unwrap(); }
+			w.flush().unwrap();
+			assert_eq!(w.into_inner(), b"fn main() {\r\n\tlet x = 1;\n\n\n}", "Chunk size {n}.");
+		}
+	}
+}
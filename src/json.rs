@@ -0,0 +1,165 @@
+/*!
+# Trimothy: JSON.
+*/
+
+use alloc::{
+	string::String,
+	vec::Vec,
+};
+use crate::TrimNormal;
+use serde_json::{
+	Map,
+	Value,
+};
+
+
+
+/// # Normalize JSON Strings.
+///
+/// Walk a [`serde_json::Value`] tree, trimming and normalizing every string
+/// value — as with [`TrimNormal`] — in place, so payloads can be sanitized
+/// in a single call.
+///
+/// Object keys are left alone; see [`normalize_json_strings_and_keys`] if
+/// they need cleaning too.
+///
+/// This function is only available when the `serde_json` crate feature is
+/// enabled.
+///
+/// ## Examples
+///
+/// ```
+/// use serde_json::json;
+/// use trimothy::normalize_json_strings;
+///
+/// let mut value = json!({
+///     " name ": "  Björk\tGuðmundsdóttir ",
+///     "tags": [" singer", "songwriter\n", 42],
+/// });
+/// normalize_json_strings(&mut value);
+/// assert_eq!(value, json!({
+///     " name ": "Björk Guðmundsdóttir",
+///     "tags": ["singer", "songwriter", 42],
+/// }));
+/// ```
+pub fn normalize_json_strings(value: &mut Value) { walk(value, false); }
+
+/// # Normalize JSON Strings and Keys.
+///
+/// Same as [`normalize_json_strings`], but object keys are trimmed and
+/// normalized too.
+///
+/// If cleaning causes two keys to collide, keys that were already clean win;
+/// among the rest, the first in map order wins.
+///
+/// This function is only available when the `serde_json` crate feature is
+/// enabled.
+///
+/// ## Examples
+///
+/// ```
+/// use serde_json::json;
+/// use trimothy::normalize_json_strings_and_keys;
+///
+/// let mut value = json!({
+///     " first  name ": " Björk ",
+///     "nested": { "\tid\t": [" 1 "] },
+/// });
+/// normalize_json_strings_and_keys(&mut value);
+/// assert_eq!(value, json!({
+///     "first name": "Björk",
+///     "nested": { "id": ["1"] },
+/// }));
+/// ```
+pub fn normalize_json_strings_and_keys(value: &mut Value) { walk(value, true); }
+
+
+
+/// # Walk the Tree.
+///
+/// This uses an explicit stack rather than recursion so deeply-nested
+/// values can't blow the real one.
+fn walk(value: &mut Value, keys: bool) {
+	let mut stack = Vec::from([value]);
+	while let Some(value) = stack.pop() {
+		match value {
+			Value::String(s) => { s.trim_and_normalize(); },
+			Value::Array(arr) => { stack.extend(arr.iter_mut()); },
+			Value::Object(map) => {
+				if keys { normalize_keys(map); }
+				stack.extend(map.values_mut());
+			},
+			Value::Null | Value::Bool(_) | Value::Number(_) => {},
+		}
+	}
+}
+
+/// # Normalize Keys.
+fn normalize_keys(map: &mut Map<String, Value>) {
+	let dirty: Vec<String> = map.keys()
+		.filter(|k| *k.trim_and_normalize() != ***k)
+		.cloned()
+		.collect();
+
+	for old in dirty {
+		if let Some(v) = map.remove(&old) {
+			map.entry(old.trim_and_normalize()).or_insert(v);
+		}
+	}
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn t_normalize_json() {
+		let raw = json!([
+			null, true, 1.5, "", " ",
+			{
+				" a ": " Hello\r\nWorld ",
+				"a": "Clean",
+				" b\t": { "  c": [[[" deep "]]] },
+			},
+		]);
+
+		let mut value = raw.clone();
+		normalize_json_strings(&mut value);
+		assert_eq!(value, json!([
+			null, true, 1.5, "", "",
+			{
+				" a ": "Hello World",
+				"a": "Clean",
+				" b\t": { "  c": [[["deep"]]] },
+			},
+		]));
+
+		// The clean "a" key should win.
+		let mut value = raw;
+		normalize_json_strings_and_keys(&mut value);
+		assert_eq!(value, json!([
+			null, true, 1.5, "", "",
+			{
+				"a": "Clean",
+				"b": { "c": [[["deep"]]] },
+			},
+		]));
+
+		// Deep nesting shouldn't be a problem.
+		let mut value = json!(" deep ");
+		for _ in 0..10_000 { value = Value::Array(Vec::from([value])); }
+		normalize_json_strings(&mut value);
+		let mut inner = &value;
+		while let Value::Array(arr) = inner { inner = &arr[0]; }
+		assert_eq!(inner, "deep");
+
+		// Drop it iteratively too, to be safe.
+		let mut stack = Vec::from([value]);
+		while let Some(v) = stack.pop() {
+			if let Value::Array(arr) = v { stack.extend(arr); }
+		}
+	}
+}
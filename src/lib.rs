@@ -18,19 +18,22 @@ This crate is `#![no_std]`-compatible.
 
 ### [`TrimSliceMatches`]
 
-This trait adds the arbitrary, match-based trimming methods to `&[u8]`, `Vec<u8>`, and `Box<[u8]>`:
+This trait adds the arbitrary, match-based trimming methods to `&[T]`, `Vec<T>`, and `Box<[T]>` — bytes by default, but any `Copy + Eq + Ord` element type will do:
 
 | Method | Description |
 | ------ | ----------- |
-| `trim_matches` | Trim arbitrary leading and trailing bytes. |
-| `trim_start_matches` | Trim arbitrary leading bytes. |
-| `trim_end_matches` | Trim arbitrary trailing bytes. |
+| `trim_matches` | Trim arbitrary leading and trailing elements. |
+| `trim_start_matches` | Trim arbitrary leading elements. |
+| `trim_end_matches` | Trim arbitrary trailing elements. |
+| `trim_matches_counted` | Trim arbitrary leading and trailing elements, returning the counts too. |
+| `trim_seq` | Trim leading and trailing repetitions of a sequence. |
+| `trim_start_seq` | Trim leading repetitions of a sequence. |
+| `trim_end_seq` | Trim trailing repetitions of a sequence. |
 
-Each of these match methods accept either:
-* A single `u8`;
-* An array or slice of `u8`;
-* A `&BtreeSet<u8>`
-* A custom callback with signature `Fn(u8) -> bool`
+Each of these match methods accept anything implementing [`MatchPattern`]: a
+single value, an array or slice of values, a `&BTreeSet`, a [`ByteSet`],
+[`CharSet`], [`SortedSlice`], or [`ClassTable`], or a callback (wrapped in a
+[`Predicate`] for element types other than `u8` and `char`).
 
 
 ### [`TrimMut`]
@@ -46,22 +49,16 @@ This trait brings _mutable_ trimming support to `String`, `Vec<u8>`, and `Box<[u
 
 ### [`TrimMatchesMut`]
 
-This trait brings _mutable_ match-based trimming `String`, `Vec<u8>`, and `Box<[u8]>`.
+This trait brings _mutable_ match-based trimming `String`, `Vec<T>`, and `Box<[T]>`.
 
 | Method | Description |
 | ------ | ----------- |
 | `trim_matches_mut` | Trim arbitrary leading and trailing bytes (mutably). |
 | `trim_start_matches_mut` | Trim arbitrary leading bytes (mutably). |
+| `trim_start_split_mut` | Measure arbitrary leading bytes, deferring their removal. |
 | `trim_end_matches_mut` | Trim arbitrary trailing bytes (mutably). |
-
-Each of these match methods accept either:
-* A single T;
-* An array or slice of T;
-* A `&BtreeSet<T>`
-* A custom callback with signature `Fn(T) -> bool`
-
-Where T is `char` for string sources, and `u8` for byte sources.
-
+| `trim_layers_mut` | Trim alternating layers of patterns until nothing changes (mutably). |
+| `trim_repeat_mut` | Same as above, but with a cap on the number of passes. |
 
 
 ### [`TrimNormal`]
@@ -72,12 +69,29 @@ This trait adds a single `trim_and_normalize` method to owned and borrowed strin
 | ------ | ----------- |
 | `trim_and_normalize` | Trim, normalize, and return. |
 
-The [`TrimNormalBytes`] and [`TrimNormalChars`] traits can be used to extend
-this same functionality to arbitrary iterators of `u8` and `char`,
-respectively.
+The [`TrimNormalBytes`], [`TrimNormalChars`], and [`TrimNormalCharIndices`]
+traits can be used to extend this same functionality to arbitrary iterators
+of `u8`, `char`, and `(usize, char)` respectively.
+
+
+### Everything Else
+
+* **More trimming:** [`Trim`], [`TrimUtf8Matches`], [`TrimSliceMatchesUtf8`], [`TrimStrByteMatches`], [`TrimKeepNewlineMut`], [`TrimShrinkMut`], [`TrimStrMut`], [`TruncateUtf8Mut`], [`TrimMinLenMut`], [`TryTrimMatchesMut`], [`TrimmedWindow`], [`TrimAllMut`], [`TrimValuesMut`], [`TrimNumberMut`], [`TrimQuoted`], [`TrimGraphemeMatches`], [`TrimListMarkerMut`], [`Trimmer`].
+* **Lines and records:** [`TrimLines`], [`TrimLinesMut`], [`TrimBlankLines`], [`TrimRecords`], [`TrimFixed`], [`align_columns`].
+* **Normalization:** [`Normalizer`], [`Sanitizer`], [`StripNormal`], [`TrimNormalBoxed`], [`TrimNormalFit`], [`fingerprint_line`], [`collapse_sql_whitespace`], [`xml_attr_normalize`], [`strip_bidi_controls`], [`contains_confusable_space`].
+* **Inspection and comparison:** [`IsBlank`], [`NormalizedSearch`], [`NormalizedCompare`], [`NormalizedHasher`], [`verify_normalized`], [`ws_diff_spans`], [`compare_trimmed_numeric`].
+* **Cows and decoding:** [`cow_trimmed`], [`cow_normalized`], [`into_owned_if_changed`], [`normalize_within`], [`Decoder`], [`decode_trimmed`], [`trim_and_normalize_utf16`], [`parse_trimmed_bytes`].
+* **Formatting:** [`write_normalized`], [`NormalizedDisplay`], [`NormalizedBuilder`], [`wrap_normalized`], [`show_whitespace`], [`TrimLinesWriter`].
+* **Observing and testing:** [`TrimObserver`], [`TrimStats`], [`assert_trimmed_eq!`], [`assert_normalized_eq!`].
+
+Several more helpers are available via optional crate features — `std`
+streaming and environment helpers, `serde`/`serde_json`/`toml`/`yaml`
+integrations, `casefold` comparisons, `capi`/`pyo3`/`wasm` bindings, and so
+on. See the README for the full list.
 */
 
-#![forbid(unsafe_code)]
+#![cfg_attr(not(any(feature = "capi", feature = "pyo3", feature = "wasm")), forbid(unsafe_code))]
+#![cfg_attr(any(feature = "capi", feature = "pyo3", feature = "wasm"), deny(unsafe_code))]
 
 #![deny(
 	clippy::allow_attributes_without_reason,
@@ -131,19 +145,291 @@ respectively.
 #![no_std]
 
 extern crate alloc;
-
+#[cfg(feature = "std")] extern crate std;
+
+#[cfg(feature = "tracing")]
+/// # Helper: Debug Event.
+///
+/// Emit a debug-level `tracing` event for the crate, with any number of
+/// `key = value` fields.
+macro_rules! trace_debug {
+	($msg:literal $(, $key:ident = $val:expr)* $(,)?) => (
+		::tracing::debug!(target: "trimothy", $($key = $val,)* $msg)
+	);
+}
+
+#[cfg(not(feature = "tracing"))]
+/// # Helper: Debug Event (Disabled).
+///
+/// Without the `tracing` feature, events compile down to nothing. (The
+/// field values are still "used", keeping the compiler happy.)
+macro_rules! trace_debug {
+	($msg:literal $(, $key:ident = $val:expr)* $(,)?) => ({ $( let _ = $val; )* });
+}
+
+#[cfg(feature = "bench_support")] pub mod bench_support;
+mod blank;
+#[cfg(feature = "capi")] mod capi;
+mod collections;
+mod compare;
+#[cfg(any(feature = "toml", feature = "yaml"))] mod config;
+mod cow;
+mod decode;
+#[cfg(feature = "std")] mod env;
+mod fingerprint;
+mod fixed;
+mod fmt;
+mod grapheme;
+#[cfg(all(feature = "intern", target_has_atomic = "ptr"))] mod intern;
+#[cfg(any(feature = "std", feature = "critical-section"))] mod global;
+#[cfg(feature = "std")] mod io;
+#[cfg(feature = "serde_json")] mod json;
+mod lines;
+mod marker;
+mod normalizer;
+mod number;
+mod observer;
+mod parse;
 mod pattern;
+#[cfg(feature = "proptest")] mod props;
+#[cfg(feature = "pyo3")] mod python;
+mod quote;
+mod sanitize;
+mod search;
+mod sql;
+mod strip;
+mod testing;
+mod trim;
 mod trim_mut;
 mod trim_normal;
 mod trim_slice;
-
+mod trimmer;
+mod utf16;
+#[cfg(feature = "wasm")] mod wasm;
+mod window;
+mod xml;
+
+pub use blank::IsBlank;
+#[cfg(feature = "capi")]
+pub use capi::{
+	TrimothyRange,
+	trimothy_normalize,
+	trimothy_normalized_eq,
+	trimothy_trim,
+	trimothy_trim_end,
+	trimothy_trim_start,
+};
+pub use collections::{
+	TrimAllMut,
+	TrimValuesMut,
+};
+pub use compare::{
+	compare_trimmed_numeric,
+	NormalizedCompare,
+	NormalizedHasher,
+	NormalizeMismatch,
+	verify_normalized,
+	verify_normalized_bytes,
+	ws_diff_spans,
+	ws_only_diff,
+	WsDiffSpans,
+};
+#[cfg(feature = "casefold")]
+pub use compare::NormalizedCompareFold;
+#[cfg(feature = "toml")]
+pub use config::{
+	trim_toml_strings,
+	trim_toml_table,
+};
+#[cfg(feature = "yaml")]
+pub use config::trim_yaml_strings;
+pub use cow::{
+	cow_normalized,
+	cow_normalized_bytes,
+	cow_trimmed,
+	cow_trimmed_bytes,
+	into_owned_if_changed,
+	normalize_within,
+};
+pub use decode::{
+	Decoder,
+	decode_trimmed,
+};
+#[cfg(feature = "std")]
+pub use env::{
+	args_os_trimmed,
+	args_trimmed,
+	env_normalized,
+	env_trimmed,
+};
+pub use fingerprint::{
+	fingerprint_line,
+	fingerprint_line_with,
+};
+pub use fixed::{
+	align_columns,
+	align_columns_with,
+	ColumnAlign,
+	FixedFields,
+	TrimFixed,
+};
+pub use fmt::{
+	join_lines_normalized,
+	normalize_args,
+	normalized,
+	NormalizedBuilder,
+	NormalizedDisplay,
+	ShowWhitespace,
+	TrimLinesWriter,
+	show_whitespace,
+	wrap_normalized,
+	wrapped_lines,
+	WrappedLines,
+	write_normalized,
+	write_normalized_args,
+	write_wrapped,
+};
+#[cfg(any(feature = "std", feature = "critical-section"))]
+pub use global::{
+	default_normalizer,
+	normalize_default,
+	set_default_normalizer,
+};
+pub use grapheme::TrimGraphemeMatches;
+#[cfg(all(feature = "intern", target_has_atomic = "ptr"))]
+pub use intern::NormalizedInterner;
+#[cfg(feature = "std")]
+pub use io::{
+	IterReader,
+	normalize_file,
+	normalize_reader,
+	SkipLeadingWs,
+};
+#[cfg(feature = "serde_json")]
+pub use json::{
+	normalize_json_strings,
+	normalize_json_strings_and_keys,
+};
+pub use lines::{
+	Indent,
+	Records,
+	TrailingWhitespace,
+	TrimBlankLines,
+	TrimBlankLinesIter,
+	TrimLines,
+	TrimLinesMut,
+	TrimRecords,
+};
+pub use marker::{
+	ListMarker,
+	TrimListMarkerMut,
+};
+pub use normalizer::{
+	classify_ws,
+	classify_ws_byte,
+	contains_confusable_space,
+	EmbeddedNul,
+	Normalizer,
+	NulPolicy,
+	WsKind,
+	WsPolicy,
+};
+pub use number::TrimNumberMut;
+pub use observer::{
+	TrimObserver,
+	TrimStats,
+};
+pub use parse::{
+	FromTrimmedBytes,
+	parse_trimmed_bytes,
+	ParseTrimmedError,
+};
+pub use pattern::{
+	ByteSet,
+	CharSet,
+	ClassTable,
+	DynPattern,
+	MatchPattern,
+	Negated,
+	Predicate,
+	SortedSlice,
+};
+#[cfg(feature = "proptest")]
+pub use props::{
+	DirtyText,
+	dirty_bytes,
+	dirty_text,
+};
+#[cfg(feature = "pyo3")]
+pub use python::trimothy_py;
+pub use quote::{
+	Quotes,
+	TrimQuoted,
+};
+pub use sanitize::{
+	contains_bidi_controls,
+	SanitizeError,
+	Sanitizer,
+	strip_bidi_controls,
+};
+pub use search::{
+	MatchIndicesNormalized,
+	NormalizedSearch,
+};
+pub use sql::collapse_sql_whitespace;
+pub use strip::StripNormal;
+#[doc(hidden)]
+pub use testing::{
+	__assert_normalized_eq,
+	__assert_trimmed_eq,
+};
 pub use trim_mut::{
+	ShrinkPolicy,
+	TooMuchTrimmed,
+	TrimKeepNewlineMut,
+	TrimLimit,
 	TrimMut,
 	TrimMatchesMut,
+	TrimMinLenMut,
+	TrimShrinkMut,
+	TrimStrMut,
+	TruncateUtf8Mut,
+	TryTrimMatchesMut,
 };
+pub use trim::Trim;
 pub use trim_normal::{
 	TrimNormal,
+	TrimNormalBoxed,
 	TrimNormalBytes,
+	TrimNormalCharIndices,
 	TrimNormalChars,
+	TrimNormalFit,
+};
+pub use trim_slice::{
+	TrimSliceMatches,
+	TrimSliceMatchesUtf8,
+	TrimStrByteMatches,
+	TrimUtf8Matches,
+};
+pub use trimmer::{
+	Edges,
+	Trimmer,
 };
-pub use trim_slice::TrimSliceMatches;
+pub use utf16::{
+	trim_and_normalize_utf16,
+	trim_and_normalize_utf16_lossy,
+};
+#[cfg(feature = "wasm")]
+pub use wasm::{
+	normalized_eq_js,
+	sanitize_text_js,
+	trim_and_normalize_js,
+};
+pub use window::TrimmedWindow;
+pub use xml::{
+	xml_attr_normalize,
+	XmlAttrType,
+};
+
+#[cfg(test)] use serde_json as _;
+#[cfg(test)] use toml as _;
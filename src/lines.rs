@@ -0,0 +1,807 @@
+/*!
+# Trimothy: Line-Based Trimming.
+*/
+
+use alloc::{
+	collections::VecDeque,
+	string::String,
+	vec::Vec,
+};
+use core::{
+	iter::FusedIterator,
+	ops::Range,
+};
+use crate::IsBlank;
+
+
+
+/// # Line-Based Trimming.
+///
+/// This trait adds line-aware trimming methods to string slices, modeled
+/// after Kotlin's `trimIndent` and `trimMargin`. They're particularly handy
+/// for cleaning up (indented) multi-line string literals — test fixtures,
+/// embedded SQL/HTML, etc. — embedded in Rust sources.
+///
+/// Lines are split on `\n`, with any `\r` immediately preceding it removed
+/// too; the results are always joined back together with `\n`.
+///
+/// | Method | Description |
+/// | ------ | ----------- |
+/// | `trim_indent` | Remove the common minimal indentation from all lines. |
+/// | `trim_margin` | Remove leading whitespace and a margin prefix from all lines. |
+/// | `trim_blank_lines` | Remove all leading and trailing blank lines. |
+/// | `lines_with_trailing_ws` | Locate trailing whitespace, line by line. |
+/// | `detect_indent` | Guess the indentation style. |
+///
+/// See also [`TrimBlankLines`] for a line _iterator_ equivalent of
+/// `trim_blank_lines`, and [`TrimLinesMut`] for in-place indentation
+/// conversion.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::TrimLines;
+///
+/// let sql = "
+///     SELECT *
+///     FROM users
+///     WHERE
+///         id = 5
+/// ".trim_indent();
+///
+/// assert_eq!(sql, "SELECT *\nFROM users\nWHERE\n    id = 5");
+/// ```
+pub trait TrimLines {
+	/// # Trim Indent.
+	///
+	/// Remove the first and last lines if they're blank, then strip the
+	/// minimal common indentation from all remaining lines.
+	///
+	/// Blank lines are ignored when calculating the common indentation, but
+	/// will still be trimmed by up to that amount.
+	fn trim_indent(&self) -> String;
+
+	/// # Trim Margin.
+	///
+	/// Remove the first and last lines if they're blank, then strip the
+	/// leading whitespace _and_ `prefix` from each remaining line that has
+	/// one. Lines without the margin prefix are left as-is.
+	///
+	/// Note that `prefix` should not be whitespace, or nothing will match.
+	fn trim_margin(&self, prefix: char) -> String;
+
+	/// # Trim Blank Lines.
+	///
+	/// Remove _all_ leading and trailing lines that are empty or contain
+	/// only whitespace, leaving everything in between — including the
+	/// indentation of the first line, and any inner blank lines — as-is.
+	fn trim_blank_lines(&self) -> &str;
+
+	/// # Lines With Trailing Whitespace.
+	///
+	/// Return an iterator over the (1-based) line numbers and byte ranges of
+	/// any trailing whitespace, for linting purposes.
+	fn lines_with_trailing_ws(&self) -> TrailingWhitespace<'_>;
+
+	/// # Detect Indentation.
+	///
+	/// Guess whether the source is indented with tabs or spaces — and if the
+	/// latter, how many — returning `None` if nothing is indented.
+	fn detect_indent(&self) -> Option<Indent>;
+}
+
+impl TrimLines for str {
+	/// # Trim Indent.
+	///
+	/// Remove the first and last lines if they're blank, then strip the
+	/// minimal common indentation from all remaining lines.
+	///
+	/// Blank lines are ignored when calculating the common indentation, but
+	/// will still be trimmed by up to that amount.
+	///
+	/// Indentation is measured in `char`s, so tabs and spaces are not
+	/// interchangeable.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::TrimLines;
+	///
+	/// let html = "
+	///     <ul>
+	///         <li>One</li>
+	///
+	///         <li>Two</li>
+	///     </ul>
+	/// ".trim_indent();
+	///
+	/// assert_eq!(
+	///     html,
+	///     "<ul>\n    <li>One</li>\n\n    <li>Two</li>\n</ul>",
+	/// );
+	/// ```
+	fn trim_indent(&self) -> String {
+		let lines = edge_trimmed_lines(self);
+		let indent = lines.iter()
+			.filter_map(|line| {
+				let width = indent_width(line);
+				if width < line.len() { Some(line[..width].chars().count()) }
+				else { None }
+			})
+			.min()
+			.unwrap_or(0);
+
+		join_lines(lines.into_iter().map(|line| skip_chars(line, indent)), self.len())
+	}
+
+	/// # Trim Margin.
+	///
+	/// Remove the first and last lines if they're blank, then strip the
+	/// leading whitespace _and_ `prefix` from each remaining line that has
+	/// one. Lines without the margin prefix are left as-is.
+	///
+	/// Note that `prefix` should not be whitespace, or nothing will match.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::TrimLines;
+	///
+	/// let text = "
+	///     |Hello
+	///     |  World!
+	///     No margin here.
+	/// ".trim_margin('|');
+	///
+	/// assert_eq!(text, "Hello\n  World!\n    No margin here.");
+	/// ```
+	fn trim_margin(&self, prefix: char) -> String {
+		join_lines(
+			edge_trimmed_lines(self).into_iter().map(|line|
+				line.trim_start().strip_prefix(prefix).unwrap_or(line)
+			),
+			self.len(),
+		)
+	}
+
+	/// # Trim Blank Lines.
+	///
+	/// Remove _all_ leading and trailing lines that are empty or contain
+	/// only whitespace, leaving everything in between — including the
+	/// indentation of the first line, and any inner blank lines — as-is.
+	///
+	/// Unlike the other methods, this one returns a slice of the original.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::TrimLines;
+	///
+	/// let email = "\n  \n\tDear Björk,\n\n  Hello!\n\t\n\n";
+	/// assert_eq!(email.trim_blank_lines(), "\tDear Björk,\n\n  Hello!");
+	/// ```
+	fn trim_blank_lines(&self) -> &str {
+		// Find the first and last non-whitespace characters.
+		let Some(first) = self.find(|c: char| ! c.is_whitespace()) else { return ""; };
+		let last = self.trim_end().len();
+
+		// Expand to include the rest of their lines.
+		let start = self[..first].rfind('\n').map_or(0, |idx| idx + 1);
+		let end = self[last..].find('\n').map_or(self.len(), |idx| last + idx);
+		let out = &self[start..end];
+		out.strip_suffix('\r').unwrap_or(out)
+	}
+
+	#[inline]
+	/// # Lines With Trailing Whitespace.
+	///
+	/// Return an iterator over the (1-based) line numbers and byte ranges of
+	/// any trailing whitespace, for linting purposes.
+	///
+	/// The line endings themselves — `\n` or `\r\n` — are not considered
+	/// trailing whitespace. Ranges are relative to the full string, not the
+	/// individual lines.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::TrimLines;
+	///
+	/// let src = "fn main() {  \r\n\tok();\n}\t";
+	/// let found: Vec<_> = src.lines_with_trailing_ws().collect();
+	/// assert_eq!(found, [(1, 11..13), (3, 23..24)]);
+	///
+	/// // The ranges index the original source.
+	/// let ws: Vec<&str> = found.into_iter().map(|(_, r)| &src[r]).collect();
+	/// assert_eq!(ws, ["  ", "\t"]);
+	/// ```
+	fn lines_with_trailing_ws(&self) -> TrailingWhitespace<'_> {
+		TrailingWhitespace { src: self, pos: Some(0), line: 0 }
+	}
+
+	/// # Detect Indentation.
+	///
+	/// Guess whether the source is indented with tabs or spaces — and if the
+	/// latter, how many — returning `None` if nothing is indented.
+	///
+	/// The style is whichever begins the most (non-blank) indented lines,
+	/// with ties going to tabs. The space width is the most common increase
+	/// in indentation from one line to the next, between one and eight,
+	/// falling back to four if no such increases are found.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::{Indent, TrimLines};
+	///
+	/// assert_eq!("Hello\nWorld".detect_indent(), None);
+	/// assert_eq!("if x {\n\treturn;\n}".detect_indent(), Some(Indent::Tabs));
+	/// assert_eq!(
+	///     "a:\n  b:\n    c: 1\n  d: 2".detect_indent(),
+	///     Some(Indent::Spaces(2)),
+	/// );
+	/// ```
+	fn detect_indent(&self) -> Option<Indent> {
+		let mut tabs = 0_usize;
+		let mut spaces = 0_usize;
+		let mut deltas = [0_usize; 8];
+		let mut prev = 0;
+
+		for line in self.lines() {
+			if line.is_blank() { continue; }
+			if line.starts_with('\t') {
+				tabs += 1;
+				continue;
+			}
+
+			let width = line.len() - line.trim_start_matches(' ').len();
+			if width != 0 { spaces += 1; }
+			if let Some(count) = width.checked_sub(prev + 1).and_then(|idx| deltas.get_mut(idx)) {
+				*count += 1;
+			}
+			prev = width;
+		}
+
+		if tabs == 0 && spaces == 0 { None }
+		else if spaces <= tabs { Some(Indent::Tabs) }
+		else {
+			let mut width = 4;
+			let mut best = 0;
+			for (idx, count) in deltas.into_iter().enumerate() {
+				if best < count {
+					best = count;
+					width = idx + 1;
+				}
+			}
+			Some(Indent::Spaces(width))
+		}
+	}
+}
+
+
+
+#[derive(Debug, Clone)]
+/// # Trailing Whitespace Iterator.
+///
+/// This struct is yielded by [`TrimLines::lines_with_trailing_ws`]. Refer to
+/// its documentation for more details.
+pub struct TrailingWhitespace<'a> {
+	/// # Source.
+	src: &'a str,
+
+	/// # Start of Next Line (If Any).
+	pos: Option<usize>,
+
+	/// # Current Line Number.
+	line: usize,
+}
+
+impl Iterator for TrailingWhitespace<'_> {
+	type Item = (usize, Range<usize>);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		while let Some(pos) = self.pos {
+			// Find the end of the line, and the start of the next.
+			let rest = &self.src[pos..];
+			let line =
+				if let Some(idx) = rest.find('\n') {
+					self.pos = Some(pos + idx + 1);
+					&rest[..idx]
+				}
+				else {
+					self.pos = None;
+					rest
+				};
+			self.line += 1;
+
+			// Check the end.
+			let line = line.strip_suffix('\r').unwrap_or(line);
+			let trimmed = line.trim_end();
+			if trimmed.len() < line.len() {
+				return Some((self.line, pos + trimmed.len()..pos + line.len()));
+			}
+		}
+
+		None
+	}
+}
+
+impl FusedIterator for TrailingWhitespace<'_> {}
+
+
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+/// # Indentation Style.
+///
+/// This is returned by [`TrimLines::detect_indent`], and accepted by
+/// [`TrimLinesMut::convert_indent_mut`].
+pub enum Indent {
+	/// # Tabs.
+	Tabs,
+
+	/// # Spaces (Per Level).
+	Spaces(usize),
+}
+
+
+
+/// # Line-Based Mutation.
+///
+/// This trait brings in-place indentation rewriting to owned strings,
+/// handy for code formatters and the like.
+///
+/// | Method | Description |
+/// | ------ | ----------- |
+/// | `convert_indent_mut` | Rewrite leading indentation as tabs or spaces. |
+pub trait TrimLinesMut {
+	/// # Convert Indentation (Mutably).
+	///
+	/// Rewrite the leading tabs and spaces of each line using the given
+	/// style.
+	fn convert_indent_mut(&mut self, to: Indent);
+}
+
+impl TrimLinesMut for String {
+	/// # Convert Indentation (Mutably).
+	///
+	/// Rewrite the leading tabs and spaces of each line using the given
+	/// style, leaving everything else — line endings included — as-is.
+	///
+	/// The current style is determined by [`TrimLines::detect_indent`], and
+	/// used to work out the number of levels each line is indented by. Mixed
+	/// indentation is normalized along the way, with tabs advancing to the
+	/// next tab stop. Any leftover (alignment) spaces are preserved after
+	/// the converted levels.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::{Indent, TrimLinesMut};
+	///
+	/// let mut s = String::from("a:\n  b:\n    c: 1\n");
+	/// s.convert_indent_mut(Indent::Tabs);
+	/// assert_eq!(s, "a:\n\tb:\n\t\tc: 1\n");
+	///
+	/// s.convert_indent_mut(Indent::Spaces(4));
+	/// assert_eq!(s, "a:\n    b:\n        c: 1\n");
+	/// ```
+	fn convert_indent_mut(&mut self, to: Indent) {
+		// Figure out how many columns make up a level.
+		let unit = match (self.detect_indent(), to) {
+			(Some(Indent::Spaces(n)), _) | (_, Indent::Spaces(n)) => n.max(1),
+			_ => 4,
+		};
+
+		let mut out = Self::with_capacity(self.len());
+		for line in self.split_inclusive('\n') {
+			// Measure the existing indentation.
+			let rest = line.trim_start_matches([' ', '\t']);
+			let mut cols = 0;
+			for b in line[..line.len() - rest.len()].bytes() {
+				if b == b'\t' { cols = (cols / unit + 1) * unit; }
+				else { cols += 1; }
+			}
+
+			// Write it back out the new way.
+			let (levels, extra) = (cols / unit, cols % unit);
+			match to {
+				Indent::Tabs => for _ in 0..levels { out.push('\t'); },
+				Indent::Spaces(n) => for _ in 0..levels * n { out.push(' '); },
+			}
+			for _ in 0..extra { out.push(' '); }
+			out.push_str(rest);
+		}
+
+		if out != *self { *self = out; }
+	}
+}
+
+
+
+/// # Trim Blank Lines: Iterator Adapter.
+///
+/// This trait adds a `trim_blank_lines` method to any iterator of `&str`
+/// lines, skipping over leading and trailing lines that are empty or
+/// contain only whitespace, while passing everything in between — interior
+/// blank lines included — through as-is.
+///
+/// Leading blanks are dropped on the fly; interior blanks are buffered
+/// until it is known whether or not anything non-blank follows them.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::TrimBlankLines;
+///
+/// let text = "\n\nHello\n\nWorld\n  \n";
+/// let lines: Vec<&str> = text.lines().trim_blank_lines().collect();
+/// assert_eq!(lines, ["Hello", "", "World"]);
+/// ```
+pub trait TrimBlankLines<'a, I: Iterator<Item=&'a str>> {
+	/// # Trim Blank Lines.
+	///
+	/// Skip leading and trailing blank lines.
+	fn trim_blank_lines(self) -> TrimBlankLinesIter<'a, I>;
+}
+
+impl<'a, I: Iterator<Item=&'a str>> TrimBlankLines<'a, I> for I {
+	#[inline]
+	fn trim_blank_lines(self) -> TrimBlankLinesIter<'a, I> {
+		TrimBlankLinesIter {
+			iter: self,
+			started: false,
+			pending: VecDeque::new(),
+			held: None,
+		}
+	}
+}
+
+
+
+#[derive(Debug, Clone)]
+/// # Iterator for [`TrimBlankLines`].
+///
+/// This struct is yielded by [`TrimBlankLines::trim_blank_lines`]. Refer to
+/// its documentation for more details.
+pub struct TrimBlankLinesIter<'a, I: Iterator<Item=&'a str>> {
+	/// # The Iterator.
+	iter: I,
+
+	/// # Found a Non-Blank Line Yet?
+	started: bool,
+
+	/// # Pending (Interior?) Blank Lines.
+	pending: VecDeque<&'a str>,
+
+	/// # Non-Blank Line Waiting on the Pending Ones.
+	held: Option<&'a str>,
+}
+
+impl<'a, I: Iterator<Item=&'a str>> Iterator for TrimBlankLinesIter<'a, I> {
+	type Item = &'a str;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		// Flush out any confirmed interior blanks before the line that
+		// confirmed them.
+		if self.held.is_some() {
+			return self.pending.pop_front().or_else(|| self.held.take());
+		}
+
+		loop {
+			let line = self.iter.next()?;
+			if line.is_blank() {
+				if self.started { self.pending.push_back(line); }
+			}
+			else {
+				self.started = true;
+				if self.pending.is_empty() { return Some(line); }
+				self.held = Some(line);
+				return self.pending.pop_front();
+			}
+		}
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let buffered = self.pending.len() + usize::from(self.held.is_some());
+		let lower = if self.held.is_some() { buffered } else { 0 };
+		let (_, upper) = self.iter.size_hint();
+		(lower, upper.and_then(|n| n.checked_add(buffered)))
+	}
+}
+
+impl<'a, I: Iterator<Item=&'a str> + FusedIterator> FusedIterator for TrimBlankLinesIter<'a, I> {}
+
+
+
+/// # Trimmed Records.
+///
+/// This trait is the byte-side analogue of trimmed lines, for binary-ish
+/// log formats and other terminator-delimited byte buffers.
+///
+/// | Method | Description |
+/// | ------ | ----------- |
+/// | `records` | Iterate over trimmed, non-empty records. |
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::TrimRecords;
+///
+/// let log = b"  first\n\n\t second \r\n   \nthird";
+/// assert!(log.records(b'\n').eq([&b"first"[..], b"second", b"third"]));
+///
+/// let log = b"a=1 \x1E b=2\x1E\x1E";
+/// assert!(log.records(0x1E).eq([&b"a=1"[..], b"b=2"]));
+/// ```
+pub trait TrimRecords {
+	/// # Records.
+	///
+	/// Split the buffer on `terminator`, yielding each record with its
+	/// leading and trailing ASCII whitespace trimmed. Empty (or
+	/// whitespace-only) records are skipped, and a missing final terminator
+	/// is no problem.
+	fn records(&self, terminator: u8) -> Records<'_>;
+}
+
+impl TrimRecords for [u8] {
+	#[inline]
+	/// # Records.
+	///
+	/// Split the buffer on `terminator`, yielding each record with its
+	/// leading and trailing ASCII whitespace trimmed. Empty (or
+	/// whitespace-only) records are skipped, and a missing final terminator
+	/// is no problem.
+	fn records(&self, terminator: u8) -> Records<'_> {
+		Records { src: self, terminator }
+	}
+}
+
+
+
+#[derive(Debug, Clone)]
+/// # Trimmed Records Iterator.
+///
+/// This iterator is returned by [`TrimRecords::records`], yielding each
+/// trimmed, non-empty record.
+pub struct Records<'a> {
+	/// # Remaining Source.
+	src: &'a [u8],
+
+	/// # Terminator.
+	terminator: u8,
+}
+
+impl<'a> Iterator for Records<'a> {
+	type Item = &'a [u8];
+
+	fn next(&mut self) -> Option<Self::Item> {
+		while ! self.src.is_empty() {
+			let (record, rest) = match self.src.iter().position(|&b| b == self.terminator) {
+				Some(idx) => (&self.src[..idx], &self.src[idx + 1..]),
+				None => (self.src, &[][..]),
+			};
+			self.src = rest;
+
+			let record = record.trim_ascii();
+			if ! record.is_empty() { return Some(record); }
+		}
+
+		None
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) { (0, Some(self.src.len())) }
+}
+
+impl FusedIterator for Records<'_> {}
+
+
+
+/// # Edge-Trimmed Lines.
+///
+/// Split `src` into lines — on `\n`, removing any trailing `\r` — dropping
+/// the first and last lines if they're blank.
+fn edge_trimmed_lines(src: &str) -> Vec<&str> {
+	let mut lines: Vec<&str> = src.split('\n')
+		.map(|line| line.strip_suffix('\r').unwrap_or(line))
+		.collect();
+
+	if lines.last().is_some_and(|line| line.trim_start().is_empty()) {
+		lines.pop();
+	}
+	if lines.first().is_some_and(|line| line.trim_start().is_empty()) {
+		lines.remove(0);
+	}
+
+	lines
+}
+
+#[inline]
+/// # Indentation Width (Bytes).
+///
+/// Return the byte length of the leading whitespace.
+fn indent_width(line: &str) -> usize { line.len() - line.trim_start().len() }
+
+/// # Join Lines.
+///
+/// Join the lines together with `\n`.
+fn join_lines<'a, I: Iterator<Item=&'a str>>(mut lines: I, capacity: usize)
+-> String {
+	let mut out = String::with_capacity(capacity);
+	if let Some(line) = lines.next() {
+		out.push_str(line);
+		for line in lines {
+			out.push('\n');
+			out.push_str(line);
+		}
+	}
+	out
+}
+
+/// # Skip Chars.
+///
+/// Return the remainder of `line` after skipping up to `n` leading
+/// whitespace `char`s.
+fn skip_chars(line: &str, n: usize) -> &str {
+	let ws = &line[..indent_width(line)];
+	let idx = ws.char_indices().nth(n).map_or(ws.len(), |(idx, _)| idx);
+	&line[idx..]
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn t_records() {
+		for (raw, terminator, expected) in [
+			(&b""[..], b'\n', &[][..]),
+			(b"\n\n \n", b'\n', &[]),
+			(b"one", b'\n', &[&b"one"[..]]),
+			(b"one\n", b'\n', &[b"one"]),
+			(b"\none\r\n two \n\n\tthree", b'\n', &[b"one", b"two", b"three"]),
+			(b"a\0\0 b \0", b'\0', &[b"a", b"b"]),
+			(b" a b ;c", b';', &[b"a b", b"c"]),
+		] {
+			assert!(raw.records(terminator).eq(expected.iter().copied()), "{raw:?}");
+			assert!(raw.to_vec().records(terminator).eq(expected.iter().copied()), "{raw:?}");
+		}
+	}
+
+	#[test]
+	fn t_trim_indent() {
+		for (raw, expected) in [
+			("", ""),
+			("\n", ""),
+			("  \n  \n  ", "  "),
+			("Hello", "Hello"),
+			("  Hello", "Hello"),
+			("\n  Hello\n    World\n  ", "Hello\n  World"),
+			("\r\n  Hello\r\n    World\r\n", "Hello\n  World"),
+			// Only one blank line is removed from each end.
+			("\n\n  Hello\n\n", "\nHello\n"),
+			// Blank lines don't affect the indent, but are trimmed.
+			("\n    Hello\n\n  \n      \n    World\n", "Hello\n\n\n  \nWorld"),
+			// Tabs and spaces are counted the same.
+			("\t\tHello\n  World", "Hello\nWorld"),
+			("\u{3000}Hello\n World", "Hello\nWorld"),
+		] {
+			assert_eq!(raw.trim_indent(), expected, "Trim indent failed: {raw:?}");
+		}
+	}
+
+	#[test]
+	fn t_trim_margin() {
+		for (raw, expected) in [
+			("", ""),
+			("|Hello", "Hello"),
+			("   |Hello", "Hello"),
+			("\n  |Hello\n  | World\n", "Hello\n World"),
+			("\r\n  |Hello\r\n  | World\r\n", "Hello\n World"),
+			// Lines without margins are left alone.
+			("\n  |Hello\n  World\n\n  |!\n", "Hello\n  World\n\n!"),
+			// Only the first prefix is removed.
+			("\n  ||Hello\n", "|Hello"),
+		] {
+			assert_eq!(raw.trim_margin('|'), expected, "Trim margin failed: {raw:?}");
+		}
+
+		// Custom prefix.
+		assert_eq!("\n  >Hello\n  >World\n".trim_margin('>'), "Hello\nWorld");
+	}
+
+	#[test]
+	fn t_trim_blank_lines() {
+		for (raw, expected) in [
+			("", ""),
+			("\n", ""),
+			(" \n\t\n  ", ""),
+			("Hello", "Hello"),
+			("  Hello  ", "  Hello  "),
+			("\n\n  Hello\n\n", "  Hello"),
+			("\r\n  Hello\r\n\r\n", "  Hello"),
+			("\n \nHello\n\n\nWorld\n \n", "Hello\n\n\nWorld"),
+			("\n \nHello\r\n\r\nWorld\r\n", "Hello\r\n\r\nWorld"),
+			("\u{3000}\nHello \u{3000}\n\u{3000}", "Hello \u{3000}"),
+		] {
+			assert_eq!(raw.trim_blank_lines(), expected, "{raw:?}");
+
+			// The iterator version should match.
+			let lines: Vec<&str> = raw.lines().trim_blank_lines().collect();
+			let expected: Vec<&str> = expected.lines().collect();
+			assert_eq!(lines, expected, "{raw:?}");
+		}
+
+		// Make sure the buffering works as expected.
+		let mut iter = ["", "A", "", " ", "B", "", "C", "", ""].into_iter().trim_blank_lines();
+		assert_eq!(iter.next(), Some("A"));
+		assert_eq!(iter.next(), Some(""));
+		assert_eq!(iter.size_hint().0, 2);
+		assert_eq!(iter.next(), Some(" "));
+		assert_eq!(iter.next(), Some("B"));
+		assert_eq!(iter.next(), Some(""));
+		assert_eq!(iter.next(), Some("C"));
+		assert_eq!(iter.next(), None);
+		assert_eq!(iter.next(), None);
+	}
+
+	#[test]
+	fn t_lines_with_trailing_ws() {
+		for (raw, expected) in [
+			("", &[][..]),
+			("Hello", &[]),
+			("Hello\nWorld\n", &[]),
+			("Hello\r\nWorld\r\n", &[]),
+			(" ", &[(1, 0..1)]),
+			("\n\n \n", &[(3, 2..3)]),
+			("Hello \nWorld\t\r\n", &[(1, 5..6), (2, 12..13)]),
+			("a\u{3000}\nb\r\r\n", &[(1, 1..4), (2, 6..7)]),
+		] {
+			let found: Vec<_> = raw.lines_with_trailing_ws().collect();
+			assert_eq!(found, expected, "{raw:?}");
+		}
+	}
+
+	#[test]
+	fn t_detect_indent() {
+		for (raw, expected) in [
+			("", None),
+			("Hello\n\nWorld", None),
+			("Hello\n  \nWorld", None),
+			("\tHello", Some(Indent::Tabs)),
+			("  Hello", Some(Indent::Spaces(2))),
+			("a\n    b\n        c\n    d", Some(Indent::Spaces(4))),
+			("a\n  b\n    c\n      d\n   e", Some(Indent::Spaces(2))),
+			// Ties go to tabs.
+			("a\n\tb\n  c", Some(Indent::Tabs)),
+			("a\n\tb\n  c\n  d", Some(Indent::Spaces(2))),
+			// Ridiculous widths aren't counted.
+			("a\n            b", Some(Indent::Spaces(4))),
+		] {
+			assert_eq!(raw.detect_indent(), expected, "{raw:?}");
+		}
+	}
+
+	#[test]
+	fn t_convert_indent_mut() {
+		for (raw, to, expected) in [
+			("", Indent::Tabs, ""),
+			("Hello", Indent::Spaces(4), "Hello"),
+			("a\n  b\n    c\n", Indent::Tabs, "a\n\tb\n\t\tc\n"),
+			("a\r\n  b\r\n", Indent::Tabs, "a\r\n\tb\r\n"),
+			("a\n  b\n    c\n", Indent::Spaces(4), "a\n    b\n        c\n"),
+			("a\n\tb\n\t\tc", Indent::Spaces(2), "a\n  b\n    c"),
+			// Alignment spaces are kept.
+			("a\n\tb\n\t   c", Indent::Spaces(4), "a\n    b\n       c"),
+			("a\n    b\n        c\n      d", Indent::Tabs, "a\n\tb\n\t\tc\n\t  d"),
+			// Mixed indentation is fixed.
+			("a\n    b\n  \tc\n\t d", Indent::Spaces(4), "a\n    b\n    c\n     d"),
+		] {
+			let mut s = String::from(raw);
+			s.convert_indent_mut(to);
+			assert_eq!(s, expected, "{raw:?}");
+		}
+	}
+}
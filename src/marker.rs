@@ -0,0 +1,190 @@
+/*!
+# Trimothy: List Markers.
+*/
+
+use alloc::string::String;
+
+
+
+/// # Bullet Characters.
+const BULLETS: [char; 7] = ['-', '*', '+', '•', '◦', '‣', '⁃'];
+
+/// # Max Number Digits.
+///
+/// This matches the `CommonMark` limit, and keeps the value well within
+/// `u32` range.
+const MAX_DIGITS: usize = 9;
+
+
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+/// # List Marker.
+///
+/// This is returned by [`TrimListMarkerMut::trim_list_marker_mut`] to
+/// describe the marker that was removed.
+pub enum ListMarker {
+	/// # Bullet.
+	///
+	/// One of `-`, `*`, `+`, `•`, `◦`, `‣`, or `⁃`.
+	Bullet(char),
+
+	/// # Number.
+	///
+	/// One to nine ASCII digits followed by `.` or `)`, e.g. `1.` or `10)`.
+	Number(u32),
+
+	/// # Letter.
+	///
+	/// A single ASCII letter followed by `.` or `)`, e.g. `a)` or `B.`.
+	Letter(char),
+}
+
+
+
+/// # List Marker Trimming.
+///
+/// This trait brings list-marker removal to owned strings, making light work
+/// of ingesting Markdown and plain-text lists line by line.
+///
+/// | Method | Description |
+/// | ------ | ----------- |
+/// | `trim_list_marker_mut` | Remove a leading bullet or enumerator and the whitespace around it. |
+pub trait TrimListMarkerMut {
+	/// # Trim List Marker (Mutably).
+	///
+	/// If the line — ignoring leading whitespace — begins with a list
+	/// marker, remove it along with any whitespace before or after it,
+	/// returning the marker found. Otherwise the line is left unchanged and
+	/// `None` is returned.
+	///
+	/// See [`ListMarker`] for the supported formats. To avoid false
+	/// positives like `-5` or `1.5`, a marker only counts if it is followed
+	/// by whitespace or the end of the line.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::{ListMarker, TrimListMarkerMut};
+	///
+	/// let mut s = String::from("  - Hello World");
+	/// assert_eq!(s.trim_list_marker_mut(), Some(ListMarker::Bullet('-')));
+	/// assert_eq!(s, "Hello World");
+	///
+	/// let mut s = String::from("12.\tHello World");
+	/// assert_eq!(s.trim_list_marker_mut(), Some(ListMarker::Number(12)));
+	/// assert_eq!(s, "Hello World");
+	///
+	/// let mut s = String::from("b) Hello World");
+	/// assert_eq!(s.trim_list_marker_mut(), Some(ListMarker::Letter('b')));
+	/// assert_eq!(s, "Hello World");
+	///
+	/// // Not a marker.
+	/// let mut s = String::from("-5 degrees");
+	/// assert_eq!(s.trim_list_marker_mut(), None);
+	/// assert_eq!(s, "-5 degrees");
+	/// ```
+	fn trim_list_marker_mut(&mut self) -> Option<ListMarker>;
+}
+
+impl TrimListMarkerMut for String {
+	/// # Trim List Marker (Mutably).
+	///
+	/// If the line — ignoring leading whitespace — begins with a list
+	/// marker, remove it along with any whitespace before or after it,
+	/// returning the marker found.
+	fn trim_list_marker_mut(&mut self) -> Option<ListMarker> {
+		let src = self.trim_start();
+		let (marker, rest) = split_marker(src)?;
+
+		// The marker needs to stand on its own.
+		if rest.starts_with(|c: char| ! c.is_whitespace()) { return None; }
+
+		let end = self.len() - rest.trim_start().len();
+		self.replace_range(..end, "");
+		Some(marker)
+	}
+}
+
+
+
+/// # Split Marker.
+///
+/// Parse the marker at the start of `src`, if any, returning it along with
+/// whatever follows.
+fn split_marker(src: &str) -> Option<(ListMarker, &str)> {
+	let mut chars = src.chars();
+	let first = chars.next()?;
+
+	// Bullets.
+	if BULLETS.contains(&first) {
+		return Some((ListMarker::Bullet(first), chars.as_str()));
+	}
+
+	// Letters.
+	if first.is_ascii_alphabetic() {
+		let rest = chars.as_str().strip_prefix(['.', ')'])?;
+		return Some((ListMarker::Letter(first), rest));
+	}
+
+	// Numbers.
+	let digits = src.bytes().take_while(u8::is_ascii_digit).count();
+	if (1..=MAX_DIGITS).contains(&digits) {
+		let rest = src[digits..].strip_prefix(['.', ')'])?;
+		let num = src[..digits].parse::<u32>().ok()?;
+		return Some((ListMarker::Number(num), rest));
+	}
+
+	None
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn t_trim_list_marker_mut() {
+		for (raw, marker, expected) in [
+			// Bullets.
+			("- Hello", Some(ListMarker::Bullet('-')), "Hello"),
+			("* Hello", Some(ListMarker::Bullet('*')), "Hello"),
+			("+ Hello", Some(ListMarker::Bullet('+')), "Hello"),
+			("• Hello", Some(ListMarker::Bullet('•')), "Hello"),
+			("\t\t◦\u{3000}Hello ", Some(ListMarker::Bullet('◦')), "Hello "),
+			("-", Some(ListMarker::Bullet('-')), ""),
+			("  -  ", Some(ListMarker::Bullet('-')), ""),
+			("- - Hello", Some(ListMarker::Bullet('-')), "- Hello"),
+
+			// Numbers.
+			("1. Hello", Some(ListMarker::Number(1)), "Hello"),
+			("10) Hello", Some(ListMarker::Number(10)), "Hello"),
+			(" 007.\tHello", Some(ListMarker::Number(7)), "Hello"),
+			("999999999. Hello", Some(ListMarker::Number(999_999_999)), "Hello"),
+			("1.", Some(ListMarker::Number(1)), ""),
+
+			// Letters.
+			("a) Hello", Some(ListMarker::Letter('a')), "Hello"),
+			("B. Hello", Some(ListMarker::Letter('B')), "Hello"),
+
+			// Nope.
+			("", None, ""),
+			("   ", None, "   "),
+			("Hello", None, "Hello"),
+			(" Hello", None, " Hello"),
+			("-5 degrees", None, "-5 degrees"),
+			("*emphasis*", None, "*emphasis*"),
+			("1.5 million", None, "1.5 million"),
+			("1 Hello", None, "1 Hello"),
+			("1234567890. Hello", None, "1234567890. Hello"),
+			("ab) Hello", None, "ab) Hello"),
+			("é) Hello", None, "é) Hello"),
+			("a)Hello", None, "a)Hello"),
+			("—Hello", None, "—Hello"),
+		] {
+			let mut s = String::from(raw);
+			assert_eq!(s.trim_list_marker_mut(), marker, "{raw:?}");
+			assert_eq!(s, expected, "{raw:?}");
+		}
+	}
+}
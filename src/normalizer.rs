@@ -0,0 +1,1087 @@
+/*!
+# Trimothy: Whitespace Classification and Normalization.
+*/
+
+use alloc::{
+	borrow::Cow,
+	string::String,
+	vec::Vec,
+};
+use core::{
+	error::Error,
+	fmt,
+};
+use crate::{
+	MatchPattern,
+	TrimObserver,
+	TrimSliceMatches,
+};
+
+
+
+#[derive(Debug, Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+/// # Whitespace Kind.
+///
+/// This enum breaks whitespace down into a handful of broad categories,
+/// allowing [`Normalizer`] policies to be set on a per-kind basis.
+///
+/// Use [`classify_ws`] or [`classify_ws_byte`] to figure out which — if any
+/// — kind a given character belongs to.
+pub enum WsKind {
+	/// # Horizontal Space (`U+0020`).
+	Space,
+
+	/// # Horizontal Tab (`\t`).
+	Tab,
+
+	/// # Line Break.
+	///
+	/// `\n`, `\r`, and the Unicode line/paragraph separators `U+0085`,
+	/// `U+2028`, and `U+2029`.
+	Newline,
+
+	/// # Vertical Tab and Form Feed (`\x0B`, `\x0C`).
+	///
+	/// Conventions vary wildly regarding these — [`u8::is_ascii_whitespace`]
+	/// includes form feeds but not vertical tabs, for example — so they get
+	/// their own kind. Set it to [`WsPolicy::Keep`] to have a [`Normalizer`]
+	/// treat them as regular, non-whitespace characters.
+	Vertical,
+
+	/// # Other Unicode Whitespace.
+	///
+	/// Everything else [`char::is_whitespace`] considers whitespace, like
+	/// `U+00A0` (no-break space) and `U+3000` (ideographic space).
+	Unicode,
+
+	/// # Zero-Width Space.
+	///
+	/// `U+200B` (zero-width space), `U+2060` (word joiner), and `U+FEFF`
+	/// (byte order mark). These are _not_ whitespace according to
+	/// [`char::is_whitespace`], but frequently find their way into text
+	/// anyway.
+	ZeroWidth,
+}
+
+impl WsKind {
+	/// # All Kinds.
+	pub const ALL: [Self; 6] = [
+		Self::Space, Self::Tab, Self::Newline,
+		Self::Vertical, Self::Unicode, Self::ZeroWidth,
+	];
+
+	#[must_use]
+	/// # From Char.
+	///
+	/// Same as [`classify_ws`].
+	pub fn from_char(c: char) -> Option<Self> {
+		match c {
+			' ' => Some(Self::Space),
+			'\t' => Some(Self::Tab),
+			'\n' | '\r' | '\u{85}' | '\u{2028}' | '\u{2029}' => Some(Self::Newline),
+			'\x0B' | '\x0C' => Some(Self::Vertical),
+			'\u{200B}' | '\u{2060}' | '\u{FEFF}' => Some(Self::ZeroWidth),
+			_ => {
+				if c.is_whitespace() { Some(Self::Unicode) }
+				else { None }
+			},
+		}
+	}
+
+	#[must_use]
+	/// # From Byte.
+	///
+	/// Same as [`classify_ws_byte`].
+	pub const fn from_byte(b: u8) -> Option<Self> {
+		match b {
+			b' ' => Some(Self::Space),
+			b'\t' => Some(Self::Tab),
+			b'\n' | b'\r' => Some(Self::Newline),
+			b'\x0B' | b'\x0C' => Some(Self::Vertical),
+			_ => None,
+		}
+	}
+
+	/// # Index.
+	///
+	/// Return the policy array index for the kind.
+	const fn idx(self) -> usize { self as usize }
+}
+
+#[inline]
+#[must_use]
+/// # Classify Whitespace (Char).
+///
+/// Return the [`WsKind`] of a `char`, or `None` if it isn't whitespace.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::{classify_ws, WsKind};
+///
+/// assert_eq!(classify_ws(' '), Some(WsKind::Space));
+/// assert_eq!(classify_ws('\r'), Some(WsKind::Newline));
+/// assert_eq!(classify_ws('\u{3000}'), Some(WsKind::Unicode));
+/// assert_eq!(classify_ws('\u{FEFF}'), Some(WsKind::ZeroWidth));
+/// assert_eq!(classify_ws('A'), None);
+/// ```
+pub fn classify_ws(c: char) -> Option<WsKind> { WsKind::from_char(c) }
+
+#[inline]
+#[must_use]
+/// # Classify Whitespace (Byte).
+///
+/// Return the [`WsKind`] of an ASCII byte, or `None` if it isn't
+/// whitespace.
+///
+/// Unlike [`u8::is_ascii_whitespace`], vertical tabs (`\x0B`) are included
+/// here (along with form feeds) as [`WsKind::Vertical`].
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::{classify_ws_byte, WsKind};
+///
+/// assert_eq!(classify_ws_byte(b'\t'), Some(WsKind::Tab));
+/// assert_eq!(classify_ws_byte(b'\x0B'), Some(WsKind::Vertical));
+/// assert_eq!(classify_ws_byte(b'A'), None);
+/// ```
+pub const fn classify_ws_byte(b: u8) -> Option<WsKind> { WsKind::from_byte(b) }
+
+#[must_use]
+/// # Contains Confusable Space?
+///
+/// Returns `true` if the string contains any characters that look like —
+/// or are frequently abused as — spaces, but which most software doesn't
+/// treat as such:
+///
+/// | Char | Name |
+/// | ---- | ---- |
+/// | `U+115F` | Hangul Choseong Filler |
+/// | `U+1160` | Hangul Jungseong Filler |
+/// | `U+1680` | Ogham Space Mark |
+/// | `U+2800` | Braille Pattern Blank |
+/// | `U+3164` | Hangul Filler |
+/// | `U+FFA0` | Halfwidth Hangul Filler |
+///
+/// These are popular for sneaking "blank" usernames and the like past
+/// validation. (The Ogham space mark _is_ technically whitespace, but
+/// usually renders as a dash, making it confusing in a different way.)
+///
+/// To map them to regular spaces during normalization instead, see
+/// [`Normalizer::with_confusable_spaces`].
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::contains_confusable_space;
+///
+/// assert!(contains_confusable_space("Hello\u{2800}World"));
+/// assert!(contains_confusable_space("\u{3164}"));
+/// assert!(! contains_confusable_space("Hello\u{3000}World"));
+/// ```
+pub fn contains_confusable_space(src: &str) -> bool {
+	src.contains(is_confusable_space)
+}
+
+
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+/// # Whitespace Policy.
+///
+/// This enum describes how a [`Normalizer`] should handle a given
+/// [`WsKind`].
+pub enum WsPolicy {
+	/// # Keep As-Is.
+	///
+	/// Leave the character alone, even at the edges. (Kept characters are
+	/// effectively treated as non-whitespace.)
+	Keep,
+
+	/// # Collapse.
+	///
+	/// Trim at the edges and collapse inner runs — along with adjacent
+	/// characters of other collapsing kinds — into a single horizontal space.
+	Collapse,
+
+	/// # Replace.
+	///
+	/// Trim at the edges and replace each inner occurrence with the given
+	/// character, without collapsing.
+	Replace(char),
+
+	/// # Remove.
+	///
+	/// Remove the character entirely.
+	Remove,
+}
+
+
+
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+/// # NUL Policy.
+///
+/// This enum describes how a [`Normalizer`] should handle embedded NUL
+/// (`\0`) characters, which are not whitespace, but tend to break C APIs
+/// and other downstream consumers in surprising ways.
+pub enum NulPolicy {
+	#[default]
+	/// # Keep As-Is.
+	///
+	/// Treat NULs like any other non-whitespace character.
+	Keep,
+
+	/// # Remove.
+	///
+	/// Remove NULs entirely, the same as [`WsPolicy::Remove`].
+	Remove,
+
+	/// # Reject.
+	///
+	/// Have [`Normalizer::try_normalize`] and
+	/// [`Normalizer::try_normalize_bytes`] return an [`EmbeddedNul`] error
+	/// if the source contains any NULs.
+	///
+	/// The infallible methods can't reject anything, so fall back to
+	/// [`NulPolicy::Remove`].
+	Reject,
+}
+
+
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+/// # Embedded NUL.
+///
+/// This is the error returned by [`Normalizer::try_normalize`] and
+/// [`Normalizer::try_normalize_bytes`] when the source contains a NUL and
+/// the policy is [`NulPolicy::Reject`].
+pub struct EmbeddedNul {
+	/// # Byte Position.
+	pos: usize,
+}
+
+impl fmt::Display for EmbeddedNul {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "embedded NUL at byte {}", self.pos)
+	}
+}
+
+impl Error for EmbeddedNul {}
+
+impl EmbeddedNul {
+	#[must_use]
+	/// # Position.
+	///
+	/// Return the byte position of the (first) NUL within the source.
+	pub const fn position(self) -> usize { self.pos }
+}
+
+
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+/// # Whitespace Normalizer.
+///
+/// This struct generalizes [`TrimNormal`](crate::TrimNormal), allowing the
+/// handling of each [`WsKind`] to be set independently via [`WsPolicy`].
+///
+/// The default configuration — [`Normalizer::new`] — collapses all
+/// [`char::is_whitespace`] whitespace and keeps zero-width spaces, exactly
+/// like [`TrimNormal`](crate::TrimNormal) does for strings.
+///
+/// Byte sources differ in one respect: [`WsKind::Vertical`] covers vertical
+/// tabs (`\x0B`) as well as form feeds, so the default normalizer collapses
+/// them, whereas [`TrimNormal`](crate::TrimNormal) — following
+/// [`u8::is_ascii_whitespace`] — leaves them be. Set that kind to
+/// [`WsPolicy::Keep`] to keep both.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::{Normalizer, WsKind, WsPolicy};
+///
+/// // Keep newlines, collapse everything else.
+/// let normalizer = Normalizer::new()
+///     .with_policy(WsKind::Newline, WsPolicy::Keep);
+///
+/// assert_eq!(
+///     normalizer.normalize("  Hello \t World\nGoodbye  Moon\n"),
+///     "Hello World\nGoodbye Moon\n",
+/// );
+///
+/// // Zap zero-width spaces, turn tabs into arrows.
+/// let normalizer = Normalizer::new()
+///     .with_policy(WsKind::ZeroWidth, WsPolicy::Remove)
+///     .with_policy(WsKind::Tab, WsPolicy::Replace('→'));
+///
+/// assert_eq!(
+///     normalizer.normalize("\u{FEFF}Hello\u{200B}\t\tWorld"),
+///     "Hello→→World",
+/// );
+/// ```
+pub struct Normalizer {
+	/// # Policies (by Kind).
+	policies: [WsPolicy; 6],
+
+	/// # Trim Edges?
+	trim: bool,
+
+	/// # NUL Policy.
+	nul: NulPolicy,
+
+	/// # Map Confusable Spaces?
+	confusable: bool,
+}
+
+impl Default for Normalizer {
+	#[inline]
+	fn default() -> Self { Self::new() }
+}
+
+impl MatchPattern<char> for &Normalizer {
+	#[inline]
+	/// # Match Normalizer.
+	fn is_match(self, thing: char) -> bool { Normalizer::is_ws(self, thing) }
+}
+
+impl MatchPattern<u8> for &Normalizer {
+	#[inline]
+	/// # Match Normalizer.
+	fn is_match(self, thing: u8) -> bool { Normalizer::is_ws_byte(self, thing) }
+}
+
+/// ## Setup.
+impl Normalizer {
+	#[must_use]
+	/// # New.
+	///
+	/// Return a new normalizer that collapses all whitespace — except
+	/// zero-width spaces, which are kept — and trims the edges.
+	pub const fn new() -> Self {
+		Self {
+			policies: [
+				WsPolicy::Collapse, WsPolicy::Collapse, WsPolicy::Collapse,
+				WsPolicy::Collapse, WsPolicy::Collapse, WsPolicy::Keep,
+			],
+			trim: true,
+			nul: NulPolicy::Keep,
+			confusable: false,
+		}
+	}
+
+	#[must_use]
+	/// # With Policy.
+	///
+	/// Set the policy for a given kind of whitespace.
+	pub const fn with_policy(mut self, kind: WsKind, policy: WsPolicy) -> Self {
+		self.policies[kind.idx()] = policy;
+		self
+	}
+
+	#[must_use]
+	/// # With Trim.
+	///
+	/// Enable or disable trimming of leading/trailing whitespace. (Kinds set
+	/// to [`WsPolicy::Keep`] are never trimmed.)
+	pub const fn with_trim(mut self, trim: bool) -> Self {
+		self.trim = trim;
+		self
+	}
+
+	#[must_use]
+	/// # With NUL Policy.
+	///
+	/// Set the policy for embedded NUL characters. By default, they are
+	/// kept.
+	pub const fn with_nul_policy(mut self, nul: NulPolicy) -> Self {
+		self.nul = nul;
+		self
+	}
+
+	#[must_use]
+	/// # With Confusable Spaces.
+	///
+	/// Enable or disable the mapping of space-like characters — see
+	/// [`contains_confusable_space`] for the full list — to regular spaces,
+	/// after which they're subject to the [`WsKind::Space`] policy like any
+	/// other. By default, they are left alone.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::{Normalizer, WsKind, WsPolicy};
+	///
+	/// let normalizer = Normalizer::new().with_confusable_spaces(true);
+	/// assert_eq!(
+	///     normalizer.normalize("\u{3164}Hello\u{2800}\u{2800} World\u{2800}"),
+	///     "Hello World",
+	/// );
+	///
+	/// // They follow the space policy, even when it's keep.
+	/// let normalizer = normalizer.with_policy(WsKind::Space, WsPolicy::Keep);
+	/// assert_eq!(normalizer.normalize("Hello\u{2800}World"), "Hello World");
+	/// ```
+	pub const fn with_confusable_spaces(mut self, confusable: bool) -> Self {
+		self.confusable = confusable;
+		self
+	}
+}
+
+/// ## Getters.
+impl Normalizer {
+	#[must_use]
+	/// # Policy.
+	///
+	/// Return the policy for a given kind of whitespace.
+	pub const fn policy(&self, kind: WsKind) -> WsPolicy { self.policies[kind.idx()] }
+
+	#[must_use]
+	/// # Trim Edges?
+	pub const fn trim(&self) -> bool { self.trim }
+
+	#[must_use]
+	/// # NUL Policy.
+	pub const fn nul_policy(&self) -> NulPolicy { self.nul }
+
+	#[must_use]
+	/// # Map Confusable Spaces?
+	pub const fn confusable_spaces(&self) -> bool { self.confusable }
+
+	#[must_use]
+	/// # Is Whitespace?
+	///
+	/// Returns `true` if the character is whitespace with a policy other
+	/// than [`WsPolicy::Keep`] — or a NUL that isn't being kept — i.e.
+	/// something that would be trimmed.
+	///
+	/// References to normalizers can also be used directly as trim patterns
+	/// for string and byte sources, making the same policies — particularly
+	/// those for [`WsKind::Vertical`] — apply to plain trimming too.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::{Normalizer, TrimSliceMatches, WsKind, WsPolicy};
+	///
+	/// // Vertical tabs and form feeds are whitespace by default.
+	/// let normalizer = Normalizer::new();
+	/// assert!(normalizer.is_ws('\x0B'));
+	/// assert_eq!(b"\x0B\x0CHello\x0C".trim_matches(&normalizer), b"Hello");
+	///
+	/// // But needn't be!
+	/// let normalizer = normalizer.with_policy(WsKind::Vertical, WsPolicy::Keep);
+	/// assert!(! normalizer.is_ws('\x0B'));
+	/// assert_eq!(
+	///     b"\x0B\x0CHello\x0C\n".trim_matches(&normalizer),
+	///     b"\x0B\x0CHello\x0C",
+	/// );
+	/// ```
+	pub fn is_ws(&self, c: char) -> bool {
+		! matches!(self.char_policy(c), WsPolicy::Keep)
+	}
+
+	#[must_use]
+	/// # Is Whitespace (Byte)?
+	///
+	/// Same as [`Normalizer::is_ws`], but for (ASCII) bytes.
+	pub const fn is_ws_byte(&self, b: u8) -> bool {
+		! matches!(self.byte_policy(b), WsPolicy::Keep)
+	}
+
+	/// # Policy for Char.
+	///
+	/// Return the policy for an arbitrary character, `Keep`ing anything that
+	/// isn't whitespace.
+	fn char_policy(&self, c: char) -> WsPolicy {
+		if c == '\0' { self.nul_as_policy() }
+		else if self.confusable && is_confusable_space(c) { self.policy(WsKind::Space) }
+		else {
+			WsKind::from_char(c).map_or(WsPolicy::Keep, |kind| self.policy(kind))
+		}
+	}
+
+	/// # Policy for Byte.
+	///
+	/// Return the policy for an arbitrary byte, `Keep`ing anything that
+	/// isn't whitespace.
+	const fn byte_policy(&self, b: u8) -> WsPolicy {
+		if b == 0 { return self.nul_as_policy(); }
+		match WsKind::from_byte(b) {
+			Some(kind) => self.policy(kind),
+			None => WsPolicy::Keep,
+		}
+	}
+
+	/// # NUL Policy (as Whitespace Policy).
+	///
+	/// Return the whitespace policy equivalent of the NUL policy.
+	const fn nul_as_policy(&self) -> WsPolicy {
+		match self.nul {
+			NulPolicy::Keep => WsPolicy::Keep,
+			NulPolicy::Remove | NulPolicy::Reject => WsPolicy::Remove,
+		}
+	}
+}
+
+/// ## Normalization.
+impl Normalizer {
+	#[must_use]
+	/// # Normalize (String).
+	///
+	/// Apply the policies to a string slice, returning it borrowed if
+	/// nothing needed changing, or owned if it did.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// # extern crate alloc;
+	/// # use alloc::borrow::Cow;
+	/// use trimothy::{Normalizer, WsKind, WsPolicy};
+	///
+	/// let normalizer = Normalizer::new()
+	///     .with_policy(WsKind::Newline, WsPolicy::Remove);
+	///
+	/// let out = normalizer.normalize(" Hello\r\n World ");
+	/// assert_eq!(out, "Hello World");
+	/// assert!(matches!(out, Cow::Owned(_)));
+	///
+	/// let out = normalizer.normalize(" Hello World ");
+	/// assert_eq!(out, "Hello World");
+	/// assert!(matches!(out, Cow::Borrowed(_)));
+	/// ```
+	pub fn normalize<'a>(&self, src: &'a str) -> Cow<'a, str> {
+		self.normalize_observed(src, &mut ())
+	}
+
+	#[must_use]
+	/// # Normalize (String, Observed).
+	///
+	/// Same as [`Normalizer::normalize`], but reports any trimming,
+	/// collapsing, and removals to the [`TrimObserver`] along the way.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::{Normalizer, TrimStats};
+	///
+	/// let mut stats = TrimStats::new();
+	/// let normalizer = Normalizer::new();
+	/// assert_eq!(normalizer.normalize_observed(" Hello\tWorld", &mut stats), "Hello World");
+	/// assert_eq!(normalizer.normalize_observed("Hello World ", &mut stats), "Hello World");
+	///
+	/// assert_eq!(stats.trimmed, 2);
+	/// assert_eq!(stats.runs, 1);
+	/// ```
+	pub fn normalize_observed<'a, O>(&self, src: &'a str, obs: &mut O) -> Cow<'a, str>
+	where O: TrimObserver + ?Sized {
+		let len = src.len();
+		let src =
+			if self.trim {
+				src.trim_matches(|c| self.is_ws(c))
+			}
+			else { src };
+		if src.len() != len { obs.trimmed(len - src.len()); }
+
+		let mut out = CowStr::new(src);
+		let mut pending = None;
+		for (idx, c) in src.char_indices() {
+			match self.char_policy(c) {
+				WsPolicy::Keep => {
+					if let Some((p, n)) = pending.take() {
+						observe_run(src.as_bytes(), p, n, obs);
+						out.push(p, ' ');
+					}
+					if self.confusable && is_confusable_space(c) { out.push(idx, ' '); }
+					else { out.push(idx, c); }
+				},
+				WsPolicy::Collapse => { pending.get_or_insert((idx, 0)).1 += c.len_utf8(); },
+				WsPolicy::Replace(r) => {
+					if let Some((p, n)) = pending.take() {
+						observe_run(src.as_bytes(), p, n, obs);
+						out.push(p, ' ');
+					}
+					out.push(idx, r);
+				},
+				WsPolicy::Remove => { obs.removed(c.len_utf8()); },
+			}
+		}
+		if let Some((p, n)) = pending {
+			observe_run(src.as_bytes(), p, n, obs);
+			out.push(p, ' ');
+		}
+
+		let out = out.finish();
+		if let Cow::Owned(o) = &out {
+			trace_debug!(
+				"Normalizer: rebuilt string",
+				before = len,
+				after = o.len(),
+				removed = len.saturating_sub(o.len()),
+			);
+		}
+		out
+	}
+
+	/// # Try Normalize (String).
+	///
+	/// Same as [`Normalizer::normalize`], but if the NUL policy is
+	/// [`NulPolicy::Reject`], sources containing NULs are refused.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::{Normalizer, NulPolicy};
+	///
+	/// let normalizer = Normalizer::new().with_nul_policy(NulPolicy::Reject);
+	/// assert_eq!(normalizer.try_normalize(" Hello\tWorld ").unwrap(), "Hello World");
+	///
+	/// let err = normalizer.try_normalize("Hello\0World").unwrap_err();
+	/// assert_eq!(err.position(), 5);
+	///
+	/// // Or they can simply be removed.
+	/// let normalizer = normalizer.with_nul_policy(NulPolicy::Remove);
+	/// assert_eq!(normalizer.try_normalize("\0Hello\0 World").unwrap(), "Hello World");
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// Returns an [`EmbeddedNul`] error if the policy is
+	/// [`NulPolicy::Reject`] and the source contains a NUL.
+	pub fn try_normalize<'a>(&self, src: &'a str) -> Result<Cow<'a, str>, EmbeddedNul> {
+		self.check_nul(src.as_bytes())?;
+		Ok(self.normalize(src))
+	}
+
+	#[must_use]
+	/// # Normalize (Bytes).
+	///
+	/// Apply the policies to a byte slice, returning it borrowed if nothing
+	/// needed changing, or owned if it did.
+	///
+	/// Only ASCII whitespace — see [`classify_ws_byte`] — is considered.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// # extern crate alloc;
+	/// # use alloc::borrow::Cow;
+	/// use trimothy::{Normalizer, WsKind, WsPolicy};
+	///
+	/// let normalizer = Normalizer::new()
+	///     .with_policy(WsKind::Newline, WsPolicy::Keep);
+	///
+	/// let out = normalizer.normalize_bytes(b" Hello\t\t World\n");
+	/// assert_eq!(out.as_ref(), b"Hello World\n");
+	/// assert!(matches!(out, Cow::Owned(_)));
+	/// ```
+	pub fn normalize_bytes<'a>(&self, src: &'a [u8]) -> Cow<'a, [u8]> {
+		self.normalize_bytes_observed(src, &mut ())
+	}
+
+	#[must_use]
+	/// # Normalize (Bytes, Observed).
+	///
+	/// Same as [`Normalizer::normalize_bytes`], but reports any trimming,
+	/// collapsing, and removals to the [`TrimObserver`] along the way.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::{Normalizer, TrimStats};
+	///
+	/// let mut stats = TrimStats::new();
+	/// let out = Normalizer::new().normalize_bytes_observed(b"Hello \r\n World", &mut stats);
+	/// assert_eq!(out.as_ref(), b"Hello World");
+	/// assert_eq!(stats.collapsed, 3);
+	/// ```
+	pub fn normalize_bytes_observed<'a, O>(&self, src: &'a [u8], obs: &mut O) -> Cow<'a, [u8]>
+	where O: TrimObserver + ?Sized {
+		let len = src.len();
+		let src =
+			if self.trim { src.trim_matches(self) }
+			else { src };
+		if src.len() != len { obs.trimmed(len - src.len()); }
+
+		let mut out = CowBytes::new(src);
+		let mut pending = None;
+		for (idx, b) in src.iter().copied().enumerate() {
+			match self.byte_policy(b) {
+				WsPolicy::Keep => {
+					if let Some((p, n)) = pending.take() {
+						observe_run(src, p, n, obs);
+						out.push(p, b' ');
+					}
+					out.push(idx, b);
+				},
+				WsPolicy::Collapse => { pending.get_or_insert((idx, 0)).1 += 1; },
+				WsPolicy::Replace(r) => {
+					if let Some((p, n)) = pending.take() {
+						observe_run(src, p, n, obs);
+						out.push(p, b' ');
+					}
+					let mut buf = [0_u8; 4];
+					for (i, r) in r.encode_utf8(&mut buf).bytes().enumerate() {
+						out.push(idx + i, r);
+					}
+				},
+				WsPolicy::Remove => { obs.removed(1); },
+			}
+		}
+		if let Some((p, n)) = pending {
+			observe_run(src, p, n, obs);
+			out.push(p, b' ');
+		}
+
+		let out = out.finish();
+		if let Cow::Owned(o) = &out {
+			trace_debug!(
+				"Normalizer: rebuilt bytes",
+				before = len,
+				after = o.len(),
+				removed = len.saturating_sub(o.len()),
+			);
+		}
+		out
+	}
+
+	/// # Try Normalize (Bytes).
+	///
+	/// Same as [`Normalizer::normalize_bytes`], but if the NUL policy is
+	/// [`NulPolicy::Reject`], sources containing NULs are refused.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::{Normalizer, NulPolicy};
+	///
+	/// let normalizer = Normalizer::new().with_nul_policy(NulPolicy::Reject);
+	/// assert!(normalizer.try_normalize_bytes(b"Hello\0").is_err());
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// Returns an [`EmbeddedNul`] error if the policy is
+	/// [`NulPolicy::Reject`] and the source contains a NUL.
+	pub fn try_normalize_bytes<'a>(&self, src: &'a [u8])
+	-> Result<Cow<'a, [u8]>, EmbeddedNul> {
+		self.check_nul(src)?;
+		Ok(self.normalize_bytes(src))
+	}
+
+	/// # Check NUL.
+	///
+	/// Return an error if NULs are rejected and the source has any.
+	fn check_nul(&self, src: &[u8]) -> Result<(), EmbeddedNul> {
+		if matches!(self.nul, NulPolicy::Reject) {
+			if let Some(pos) = src.iter().position(|&b| b == 0) {
+				return Err(EmbeddedNul { pos });
+			}
+		}
+		Ok(())
+	}
+}
+
+
+
+/// # Confusable Spaces.
+///
+/// This must remain sorted.
+const CONFUSABLE_SPACES: [char; 6] = [
+	'\u{115F}', '\u{1160}', '\u{1680}', '\u{2800}', '\u{3164}', '\u{FFA0}',
+];
+
+/// # Is Confusable Space?
+fn is_confusable_space(c: char) -> bool { CONFUSABLE_SPACES.binary_search(&c).is_ok() }
+
+/// # Observe Run.
+///
+/// Report a collapsed run of `len` (collapsible) bytes starting at `idx`,
+/// unless it was already a lone horizontal space.
+fn observe_run<O: TrimObserver + ?Sized>(src: &[u8], idx: usize, len: usize, obs: &mut O) {
+	if len != 1 || src[idx] != b' ' { obs.collapsed(len, 1); }
+}
+
+/// # Lazy Cow Builder (String).
+///
+/// This accumulates output, staying borrowed for as long as the output
+/// matches a contiguous stretch of the source, and allocating only once it
+/// doesn't.
+struct CowStr<'a> {
+	/// # Source.
+	src: &'a str,
+
+	/// # Borrowed Range.
+	start: usize,
+
+	/// # Borrowed Range End.
+	end: usize,
+
+	/// # Owned Output.
+	out: Option<String>,
+}
+
+impl<'a> CowStr<'a> {
+	/// # New.
+	const fn new(src: &'a str) -> Self {
+		Self { src, start: 0, end: 0, out: None }
+	}
+
+	/// # Push.
+	///
+	/// Push `c`, which corresponds to the source at `idx`.
+	fn push(&mut self, idx: usize, c: char) {
+		if let Some(out) = &mut self.out { out.push(c); }
+		else {
+			if self.start == self.end { self.start = idx; self.end = idx; }
+			if idx == self.end && self.src[idx..].starts_with(c) {
+				self.end += c.len_utf8();
+			}
+			else {
+				let mut out = String::with_capacity(self.src.len());
+				out.push_str(&self.src[self.start..self.end]);
+				out.push(c);
+				self.out = Some(out);
+			}
+		}
+	}
+
+	/// # Finish.
+	fn finish(self) -> Cow<'a, str> {
+		self.out.map_or_else(|| Cow::Borrowed(&self.src[self.start..self.end]), Cow::Owned)
+	}
+}
+
+/// # Lazy Cow Builder (Bytes).
+///
+/// Same as [`CowStr`], but for byte slices.
+struct CowBytes<'a> {
+	/// # Source.
+	src: &'a [u8],
+
+	/// # Borrowed Range.
+	start: usize,
+
+	/// # Borrowed Range End.
+	end: usize,
+
+	/// # Owned Output.
+	out: Option<Vec<u8>>,
+}
+
+impl<'a> CowBytes<'a> {
+	/// # New.
+	const fn new(src: &'a [u8]) -> Self {
+		Self { src, start: 0, end: 0, out: None }
+	}
+
+	/// # Push.
+	///
+	/// Push `b`, which corresponds to the source at `idx`.
+	fn push(&mut self, idx: usize, b: u8) {
+		if let Some(out) = &mut self.out { out.push(b); }
+		else {
+			if self.start == self.end { self.start = idx; self.end = idx; }
+			if idx == self.end && self.src.get(idx) == Some(&b) { self.end += 1; }
+			else {
+				let mut out = Vec::with_capacity(self.src.len());
+				out.extend_from_slice(&self.src[self.start..self.end]);
+				out.push(b);
+				self.out = Some(out);
+			}
+		}
+	}
+
+	/// # Finish.
+	fn finish(self) -> Cow<'a, [u8]> {
+		self.out.map_or_else(|| Cow::Borrowed(&self.src[self.start..self.end]), Cow::Owned)
+	}
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::TrimNormal;
+
+	#[test]
+	fn t_classify_ws() {
+		// Everything char::is_whitespace should have a kind.
+		for c in ('\0'..=char::MAX).filter(|c| c.is_whitespace()) {
+			assert!(classify_ws(c).is_some(), "{c:?}");
+			assert_ne!(classify_ws(c), Some(WsKind::ZeroWidth));
+		}
+
+		// Bytes and chars should agree.
+		for b in 0..=u8::MAX {
+			let kind = classify_ws_byte(b);
+			if b.is_ascii() { assert_eq!(kind, classify_ws(char::from(b))); }
+			else { assert!(kind.is_none()); }
+		}
+	}
+
+	#[test]
+	fn t_normalizer_default() {
+		let normalizer = Normalizer::default();
+		for raw in [
+			"", " ", "Hello", " Hello ", "Hello  World", "\r\nHello\tWorld\x0B",
+			"H\u{3000}E\u{2003}\u{2003}L L O", "\u{200B}Hello\u{200B}",
+		] {
+			let a = normalizer.normalize(raw);
+			let b = raw.trim_and_normalize();
+			assert_eq!(a, b);
+			assert_eq!(matches!(a, Cow::Borrowed(_)), matches!(b, Cow::Borrowed(_)), "{raw:?}");
+		}
+
+		// Bytes agree too, vertical tabs aside.
+		for raw in [
+			&b""[..], b" ", b"Hello", b" Hello ", b"Hello  World", b"\r\nHello\tWorld\x0C",
+			b"\x0CA\x0C\x0CB\x0C",
+		] {
+			let a = normalizer.normalize_bytes(raw);
+			let b = raw.trim_and_normalize();
+			assert_eq!(a, b, "{raw:?}");
+			assert_eq!(matches!(a, Cow::Borrowed(_)), matches!(b, Cow::Borrowed(_)), "{raw:?}");
+		}
+		assert_eq!(normalizer.normalize_bytes(b"a\x0Bb").as_ref(), b"a b");
+		assert_eq!(b"a\x0Bb".trim_and_normalize().as_ref(), b"a\x0Bb");
+	}
+
+	#[test]
+	fn t_normalizer() {
+		let normalizer = Normalizer::new()
+			.with_policy(WsKind::Newline, WsPolicy::Keep)
+			.with_policy(WsKind::Tab, WsPolicy::Replace('_'))
+			.with_policy(WsKind::ZeroWidth, WsPolicy::Remove);
+
+		assert_eq!(normalizer.policy(WsKind::Tab), WsPolicy::Replace('_'));
+		assert_eq!(normalizer.policy(WsKind::Space), WsPolicy::Collapse);
+
+		for (raw, expected, borrowed) in [
+			("", "", true),
+			(" \t \u{200B} ", "", true),
+			("\nHello\n", "\nHello\n", true),
+			(" \nHello \n", "\nHello \n", true),
+			("Hello\tWorld", "Hello_World", false),
+			("Hello \t World", "Hello _ World", false),
+			("Hello  \t World", "Hello _ World", false),
+			("Hello\u{200B}World", "HelloWorld", false),
+			("Hello \u{200B} World", "Hello World", false),
+			("\u{200B}Hello World\u{200B}", "Hello World", true),
+		] {
+			let out = normalizer.normalize(raw);
+			assert_eq!(out, expected, "{raw:?}");
+			assert_eq!(matches!(out, Cow::Borrowed(_)), borrowed, "{raw:?}");
+
+			if raw.is_ascii() {
+				let out = normalizer.normalize_bytes(raw.as_bytes());
+				assert_eq!(out.as_ref(), expected.as_bytes(), "{raw:?}");
+				assert_eq!(matches!(out, Cow::Borrowed(_)), borrowed, "{raw:?}");
+			}
+		}
+
+		// No trimming.
+		let normalizer = normalizer.with_trim(false);
+		assert!(! normalizer.trim());
+		assert_eq!(normalizer.normalize("  Hello\t"), " Hello_");
+		assert_eq!(normalizer.normalize_bytes(b"  Hello\t").as_ref(), b" Hello_");
+
+		// Vertical policy.
+		let normalizer = Normalizer::new();
+		assert_eq!(normalizer.normalize_bytes(b"\x0BA\x0B\x0CB\x0C").as_ref(), b"A B");
+		assert_eq!(b"\x0BA\x0C".trim_matches(&normalizer), b"A");
+		assert_eq!("\x0BA\x0C".trim_matches(|c| normalizer.is_ws(c)), "A");
+		let normalizer = normalizer.with_policy(WsKind::Vertical, WsPolicy::Keep);
+		assert_eq!(normalizer.normalize_bytes(b" \x0BA\x0B\x0CB\x0C ").as_ref(), b"\x0BA\x0B\x0CB\x0C");
+		assert_eq!(b" \x0BA\x0C ".trim_matches(&normalizer), b"\x0BA\x0C");
+
+		// Multi-byte replacements.
+		let normalizer = Normalizer::new().with_policy(WsKind::Tab, WsPolicy::Replace('→'));
+		assert_eq!(normalizer.normalize_bytes(b"A\tB").as_ref(), "A→B".as_bytes());
+	}
+
+	#[test]
+	fn t_normalizer_nul() {
+		// Kept by default.
+		let normalizer = Normalizer::new();
+		assert_eq!(normalizer.nul_policy(), NulPolicy::Keep);
+		assert_eq!(normalizer.normalize(" \0 Hello\0 "), "\0 Hello\0");
+		assert_eq!(normalizer.try_normalize(" \0 Hello\0 ").unwrap(), "\0 Hello\0");
+
+		for policy in [NulPolicy::Remove, NulPolicy::Reject] {
+			let normalizer = Normalizer::new().with_nul_policy(policy);
+			assert_eq!(normalizer.nul_policy(), policy);
+			assert!(normalizer.is_ws('\0'));
+			assert!(normalizer.is_ws_byte(0));
+
+			for (raw, expected) in [
+				("", ""),
+				("\0", ""),
+				(" \0 Hello\0 ", "Hello"),
+				("Hel\0lo \0 World", "Hello World"),
+				("Hello\0\tWorld", "Hello World"),
+			] {
+				assert_eq!(normalizer.normalize(raw), expected, "{raw:?}");
+				assert_eq!(normalizer.normalize_bytes(raw.as_bytes()).as_ref(), expected.as_bytes(), "{raw:?}");
+
+				let res = normalizer.try_normalize(raw);
+				let res_bytes = normalizer.try_normalize_bytes(raw.as_bytes());
+				if matches!(policy, NulPolicy::Reject) && raw.contains('\0') {
+					let pos = raw.find('\0');
+					assert_eq!(res.map_err(EmbeddedNul::position).err(), pos, "{raw:?}");
+					assert_eq!(res_bytes.map_err(EmbeddedNul::position).err(), pos, "{raw:?}");
+				}
+				else {
+					assert_eq!(res.unwrap(), expected, "{raw:?}");
+					assert_eq!(res_bytes.unwrap().as_ref(), expected.as_bytes(), "{raw:?}");
+				}
+			}
+		}
+
+		// Errors should be readable.
+		let err = Normalizer::new().with_nul_policy(NulPolicy::Reject)
+			.try_normalize("Hi\0").unwrap_err();
+		assert_eq!(alloc::format!("{err}"), "embedded NUL at byte 2");
+	}
+
+	#[test]
+	fn t_normalizer_confusable() {
+		// The table should be sorted, and none but Ogham should be whitespace.
+		assert!(CONFUSABLE_SPACES.windows(2).all(|w| w[0] < w[1]));
+		for c in CONFUSABLE_SPACES {
+			assert!(contains_confusable_space(&alloc::format!("a{c}b")), "{c:?}");
+			assert_eq!(c.is_whitespace(), c == '\u{1680}', "{c:?}");
+		}
+		assert!(! contains_confusable_space(""));
+		assert!(! contains_confusable_space(" \t\u{A0}\u{3000}Hello\u{200B}"));
+
+		// Left alone by default.
+		let normalizer = Normalizer::new();
+		assert!(! normalizer.confusable_spaces());
+		assert!(! normalizer.is_ws('\u{2800}'));
+		assert_eq!(normalizer.normalize("\u{2800}Hello\u{2800}"), "\u{2800}Hello\u{2800}");
+
+		let normalizer = normalizer.with_confusable_spaces(true);
+		assert!(normalizer.confusable_spaces());
+		assert!(normalizer.is_ws('\u{2800}'));
+		for (raw, expected) in [
+			("", ""),
+			("\u{3164}", ""),
+			("Hello\u{2800}World", "Hello World"),
+			("Hello \u{115F}\u{1160} World", "Hello World"),
+			("\u{FFA0}Hello\u{1680}World\u{FFA0}", "Hello World"),
+			("Hello World", "Hello World"),
+		] {
+			assert_eq!(normalizer.normalize(raw), expected, "{raw:?}");
+		}
+
+		// They follow the space policy.
+		let normalizer = normalizer.with_policy(WsKind::Space, WsPolicy::Remove);
+		assert_eq!(normalizer.normalize("Hello\u{2800} World"), "HelloWorld");
+		let normalizer = normalizer.with_policy(WsKind::Space, WsPolicy::Keep);
+		assert!(! normalizer.is_ws('\u{2800}'));
+		assert_eq!(normalizer.normalize("\u{2800}Hello\u{2800}"), " Hello ");
+	}
+}
@@ -0,0 +1,340 @@
+/*!
+# Trimothy: Numeric Trimming.
+*/
+
+use alloc::{
+	string::String,
+	vec::Vec,
+};
+use core::ops::Range;
+
+
+
+/// # Mutable Numeric Trim.
+///
+/// The [`TrimNumberMut`] trait exposes mutable trimming methods for numeric
+/// `String`s and `Vec<u8>`s — decimals like `-12.50` or `1.250e10` — that
+/// strip the insignificant bits without changing the value.
+///
+/// The trait methods included are:
+///
+/// | Method | Description |
+/// | ------ | ----------- |
+/// | `trim_decimal_zeros_mut` | Trim trailing fractional zeros (mutably). |
+/// | `trim_leading_zeros_mut` | Trim leading integer zeros (mutably). |
+///
+/// Sources that aren't plain decimal numbers — an optional sign, digits, an
+/// optional fraction, and an optional exponent, _without_ any surrounding
+/// whitespace — are left as-is.
+///
+/// Refer to the individual implementations for examples.
+pub trait TrimNumberMut {
+	/// # Trim Decimal Zeros Mut.
+	///
+	/// Remove trailing zeros from the fractional part of a decimal number,
+	/// along with the decimal point itself if nothing else remains. Refer to
+	/// the individual implementations for examples.
+	fn trim_decimal_zeros_mut(&mut self);
+
+	/// # Trim Leading Zeros Mut.
+	///
+	/// Remove leading zeros from the integer part of a decimal number, after
+	/// any sign, keeping a single `0` if nothing else would remain. Refer to
+	/// the individual implementations for examples.
+	fn trim_leading_zeros_mut(&mut self);
+}
+
+impl TrimNumberMut for String {
+	/// # Trim Decimal Zeros Mut.
+	///
+	/// Remove trailing zeros from the fractional part of a decimal number,
+	/// along with the decimal point itself if nothing else remains.
+	///
+	/// Exponents and signs are preserved, as is a lone zero in cases like
+	/// `".000"`. Non-numeric strings are left unchanged.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::TrimNumberMut;
+	///
+	/// let mut s = String::from("1.2300");
+	/// s.trim_decimal_zeros_mut();
+	/// assert_eq!(s, "1.23");
+	///
+	/// let mut s = String::from("-5.000e10");
+	/// s.trim_decimal_zeros_mut();
+	/// assert_eq!(s, "-5e10");
+	///
+	/// // Integers have nothing to trim.
+	/// let mut s = String::from("100");
+	/// s.trim_decimal_zeros_mut();
+	/// assert_eq!(s, "100");
+	/// ```
+	fn trim_decimal_zeros_mut(&mut self) {
+		let src = self.as_bytes();
+		if let Some((range, zero)) = Decimal::parse(src).and_then(|d| d.trailing_zeros(src)) {
+			self.replace_range(range, if zero { "0" } else { "" });
+		}
+	}
+
+	/// # Trim Leading Zeros Mut.
+	///
+	/// Remove leading zeros from the integer part of a decimal number, after
+	/// any sign, keeping a single `0` if nothing else would remain.
+	///
+	/// Non-numeric strings are left unchanged.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::TrimNumberMut;
+	///
+	/// let mut s = String::from("-000120");
+	/// s.trim_leading_zeros_mut();
+	/// assert_eq!(s, "-120");
+	///
+	/// let mut s = String::from("+000.50");
+	/// s.trim_leading_zeros_mut();
+	/// assert_eq!(s, "+0.50");
+	///
+	/// let mut s = String::from("0000");
+	/// s.trim_leading_zeros_mut();
+	/// assert_eq!(s, "0");
+	/// ```
+	fn trim_leading_zeros_mut(&mut self) {
+		let src = self.as_bytes();
+		if let Some(range) = Decimal::parse(src).and_then(|d| d.leading_zeros(src)) {
+			self.replace_range(range, "");
+		}
+	}
+}
+
+impl TrimNumberMut for Vec<u8> {
+	/// # Trim Decimal Zeros Mut.
+	///
+	/// Remove trailing zeros from the fractional part of a decimal number,
+	/// along with the decimal point itself if nothing else remains.
+	///
+	/// Exponents and signs are preserved, as is a lone zero in cases like
+	/// `".000"`. Non-numeric values are left unchanged.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::TrimNumberMut;
+	///
+	/// let mut v = b"1.2300".to_vec();
+	/// v.trim_decimal_zeros_mut();
+	/// assert_eq!(v, b"1.23");
+	///
+	/// let mut v = b"+.000".to_vec();
+	/// v.trim_decimal_zeros_mut();
+	/// assert_eq!(v, b"+0");
+	/// ```
+	fn trim_decimal_zeros_mut(&mut self) {
+		if let Some((range, zero)) = Decimal::parse(self).and_then(|d| d.trailing_zeros(self)) {
+			let zero: &[u8] = if zero { b"0" } else { b"" };
+			self.splice(range, zero.iter().copied());
+		}
+	}
+
+	/// # Trim Leading Zeros Mut.
+	///
+	/// Remove leading zeros from the integer part of a decimal number, after
+	/// any sign, keeping a single `0` if nothing else would remain.
+	///
+	/// Non-numeric values — including fields with leftover space padding —
+	/// are left unchanged.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::TrimNumberMut;
+	///
+	/// let mut v = b"0000012345".to_vec();
+	/// v.trim_leading_zeros_mut();
+	/// assert_eq!(v, b"12345");
+	///
+	/// let mut v = b"-0000".to_vec();
+	/// v.trim_leading_zeros_mut();
+	/// assert_eq!(v, b"-0");
+	/// ```
+	fn trim_leading_zeros_mut(&mut self) {
+		if let Some(range) = Decimal::parse(self).and_then(|d| d.leading_zeros(self)) {
+			self.drain(range);
+		}
+	}
+}
+
+
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+/// # Decimal Layout.
+///
+/// This holds the byte positions of the various parts of a (validated)
+/// decimal number, i.e. `[sign][int][.frac][exp]`.
+struct Decimal {
+	/// # Start of Integer Digits (i.e. Sign Length).
+	int: usize,
+
+	/// # Decimal Point.
+	dot: Option<usize>,
+
+	/// # Start of Exponent (or Length).
+	exp: usize,
+}
+
+impl Decimal {
+	/// # Parse.
+	///
+	/// Validate and map out the parts of a decimal number, returning `None`
+	/// if the source isn't one.
+	fn parse(src: &[u8]) -> Option<Self> {
+		let int = usize::from(matches!(src.first(), Some(b'+' | b'-')));
+		let exp = src.iter().position(|b| matches!(b, b'e' | b'E')).unwrap_or(src.len());
+
+		// The exponent, if any, needs an optionally-signed integer.
+		if exp < src.len() {
+			let mut digits = &src[exp + 1..];
+			if let [b'+' | b'-', rest @ ..] = digits { digits = rest; }
+			if digits.is_empty() || ! digits.iter().all(u8::is_ascii_digit) { return None; }
+		}
+
+		// The mantissa needs digits and at most one dot.
+		let mantissa = src.get(int..exp)?;
+		let dot = mantissa.iter().position(|&b| b == b'.').map(|d| d + int);
+		let mut digits = 0;
+		for (idx, b) in mantissa.iter().enumerate() {
+			if b.is_ascii_digit() { digits += 1; }
+			else if Some(idx + int) != dot { return None; }
+		}
+
+		if digits == 0 { None }
+		else { Some(Self { int, dot, exp }) }
+	}
+
+	/// # Leading Zeros.
+	///
+	/// Return the range of the insignificant leading integer zeros, if any,
+	/// keeping one back if the integer part would otherwise wind up empty.
+	fn leading_zeros(self, src: &[u8]) -> Option<Range<usize>> {
+		let end = self.dot.unwrap_or(self.exp);
+		let int = &src[self.int..end];
+		let mut zeros = int.iter().take_while(|&&b| b == b'0').count();
+		if zeros == int.len() { zeros = zeros.saturating_sub(1); }
+
+		if zeros == 0 { None }
+		else { Some(self.int..self.int + zeros) }
+	}
+
+	/// # Trailing Zeros.
+	///
+	/// Return the range of the insignificant trailing fractional zeros —
+	/// including the decimal point, if the fraction would wind up empty —
+	/// and whether or not a `0` needs to be put in their place to keep the
+	/// value valid.
+	fn trailing_zeros(self, src: &[u8]) -> Option<(Range<usize>, bool)> {
+		let dot = self.dot?;
+		let frac = &src[dot + 1..self.exp];
+		let kept = frac.iter().rposition(|&b| b != b'0').map_or(0, |p| p + 1);
+
+		// Something to keep.
+		if kept != 0 {
+			let start = dot + 1 + kept;
+			if start == self.exp { None }
+			else { Some((start..self.exp, false)) }
+		}
+		// Nothing to keep.
+		else { Some((dot..self.exp, dot == self.int)) }
+	}
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn t_trim_decimal_zeros() {
+		for (raw, expected) in [
+			("", ""),
+			("0", "0"),
+			("100", "100"),
+			("1.23", "1.23"),
+			("1.2300", "1.23"),
+			("1.0203000", "1.0203"),
+			("5.000", "5"),
+			("5.", "5"),
+			("-5.0", "-5"),
+			("+0.000", "+0"),
+			(".000", "0"),
+			("-.0", "-0"),
+			(".050", ".05"),
+			("1.500e10", "1.5e10"),
+			("1.000E-5", "1E-5"),
+			("1.0e+5", "1e+5"),
+			("1e5", "1e5"),
+			("10.0e0", "10e0"),
+			// Not numbers.
+			(".", "."),
+			("-", "-"),
+			("1.0.0", "1.0.0"),
+			(" 1.0", " 1.0"),
+			("1.0e", "1.0e"),
+			("1.0e+", "1.0e+"),
+			("e10", "e10"),
+			("1.0f", "1.0f"),
+			("1.0e1.0", "1.0e1.0"),
+			("--1.0", "--1.0"),
+		] {
+			let mut s = String::from(raw);
+			s.trim_decimal_zeros_mut();
+			assert_eq!(s, expected, "{raw:?}");
+
+			let mut v = raw.as_bytes().to_vec();
+			v.trim_decimal_zeros_mut();
+			assert_eq!(v, expected.as_bytes(), "{raw:?}");
+		}
+	}
+
+	#[test]
+	fn t_trim_leading_zeros() {
+		for (raw, expected) in [
+			("", ""),
+			("0", "0"),
+			("00", "0"),
+			("100", "100"),
+			("007", "7"),
+			("+007", "+7"),
+			("-007", "-7"),
+			("-000", "-0"),
+			("000.5", "0.5"),
+			(".5", ".5"),
+			("0.000", "0.000"),
+			("-00.", "-0."),
+			("00100e05", "100e05"),
+			("000E1", "0E1"),
+			// Not numbers.
+			(" 007", " 007"),
+			("007 ", "007 "),
+			("00A", "00A"),
+			("+-007", "+-007"),
+		] {
+			let mut s = String::from(raw);
+			s.trim_leading_zeros_mut();
+			assert_eq!(s, expected, "{raw:?}");
+
+			let mut v = raw.as_bytes().to_vec();
+			v.trim_leading_zeros_mut();
+			assert_eq!(v, expected.as_bytes(), "{raw:?}");
+		}
+
+		// Both ends.
+		let mut s = String::from("-0012.3400e+07");
+		s.trim_leading_zeros_mut();
+		s.trim_decimal_zeros_mut();
+		assert_eq!(s, "-12.34e+07");
+	}
+}
@@ -0,0 +1,225 @@
+/*!
+# Trimothy: Observers.
+*/
+
+
+
+/// # Trim Observer.
+///
+/// This trait allows [`Trimmer`](crate::Trimmer) and
+/// [`Normalizer`](crate::Normalizer) operations to report what they changed —
+/// via their `*_observed` methods — so applications can export sanitization
+/// metrics without having to diff the before and after themselves.
+///
+/// Every method has a no-op default, so implementations need only override
+/// the events they care about. Events are only reported when something
+/// actually changes.
+///
+/// | Method | Description |
+/// | ------ | ----------- |
+/// | `trimmed` | Leading and/or trailing bytes were trimmed. |
+/// | `collapsed` | An inner run was collapsed to a single replacement. |
+/// | `removed` | An inner character was removed outright. |
+///
+/// The unit type `()` is a no-op observer, while [`TrimStats`] keeps a
+/// running tally.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::{TrimObserver, Trimmer};
+///
+/// #[derive(Default)]
+/// struct Counter(usize);
+///
+/// impl TrimObserver for Counter {
+///     fn collapsed(&mut self, _from: usize, _to: usize) { self.0 += 1; }
+/// }
+///
+/// let trimmer = Trimmer::new().with_collapse(Some(' '));
+/// let mut counter = Counter::default();
+/// assert_eq!(
+///     trimmer.trim_observed(" Hello\t\tWorld  and Moon ", &mut counter),
+///     "Hello World and Moon",
+/// );
+/// assert_eq!(counter.0, 2);
+/// ```
+pub trait TrimObserver {
+	#[inline]
+	/// # Trimmed.
+	///
+	/// Called with the total number of bytes trimmed from the edge(s).
+	fn trimmed(&mut self, _bytes: usize) {}
+
+	#[inline]
+	/// # Collapsed.
+	///
+	/// Called for each inner run of `from` bytes replaced by a single
+	/// character `to` bytes long. (Runs that were already just the
+	/// replacement are not reported.)
+	fn collapsed(&mut self, _from: usize, _to: usize) {}
+
+	#[inline]
+	/// # Removed.
+	///
+	/// Called with the length of each inner character removed outright.
+	fn removed(&mut self, _bytes: usize) {}
+}
+
+impl TrimObserver for () {}
+
+
+
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, PartialEq)]
+/// # Trim Stats.
+///
+/// A simple [`TrimObserver`] that tallies everything reported to it, across
+/// any number of operations.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::{Normalizer, TrimStats, WsKind, WsPolicy};
+///
+/// let normalizer = Normalizer::new()
+///     .with_policy(WsKind::ZeroWidth, WsPolicy::Remove);
+///
+/// let mut stats = TrimStats::new();
+/// assert_eq!(
+///     normalizer.normalize_observed("  Hello\n\n\u{200B}World ", &mut stats),
+///     "Hello World",
+/// );
+/// assert_eq!(stats.trimmed, 3);   // Two leading spaces, one trailing.
+/// assert_eq!(stats.runs, 1);      // The newlines.
+/// assert_eq!(stats.collapsed, 1); // Two newlines became one space.
+/// assert_eq!(stats.removed, 3);   // The zero-width space.
+/// assert_eq!(stats.total(), 7);
+/// ```
+pub struct TrimStats {
+	/// # Bytes Trimmed (Edges).
+	pub trimmed: usize,
+
+	/// # Runs Collapsed.
+	pub runs: usize,
+
+	/// # Bytes Saved by Collapsing.
+	pub collapsed: usize,
+
+	/// # Bytes Removed (Inner).
+	pub removed: usize,
+}
+
+impl TrimObserver for TrimStats {
+	#[inline]
+	/// # Trimmed.
+	fn trimmed(&mut self, bytes: usize) { self.trimmed += bytes; }
+
+	#[inline]
+	/// # Collapsed.
+	fn collapsed(&mut self, from: usize, to: usize) {
+		self.runs += 1;
+		self.collapsed += from.saturating_sub(to);
+	}
+
+	#[inline]
+	/// # Removed.
+	fn removed(&mut self, bytes: usize) { self.removed += bytes; }
+}
+
+impl TrimStats {
+	#[must_use]
+	/// # New.
+	///
+	/// Return a new, zeroed instance.
+	pub const fn new() -> Self {
+		Self { trimmed: 0, runs: 0, collapsed: 0, removed: 0 }
+	}
+
+	#[must_use]
+	/// # Total Bytes Saved.
+	///
+	/// Return the combined number of bytes trimmed, collapsed, and removed.
+	pub const fn total(&self) -> usize {
+		self.trimmed + self.collapsed + self.removed
+	}
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::{
+		Edges,
+		Normalizer,
+		Trimmer,
+		WsKind,
+		WsPolicy,
+	};
+	use alloc::string::String;
+
+	#[test]
+	fn t_trim_stats() {
+		// Clean sources report nothing.
+		let mut stats = TrimStats::new();
+		assert_eq!(Normalizer::new().normalize_observed("Hello World", &mut stats), "Hello World");
+		assert_eq!(Normalizer::new().normalize_bytes_observed(b"Hello World", &mut stats).as_ref(), b"Hello World");
+		assert_eq!(Trimmer::new().with_collapse(Some(' ')).trim_observed("Hello World", &mut stats), "Hello World");
+		assert_eq!(stats, TrimStats::default());
+
+		// Normalizer.
+		let normalizer = Normalizer::new()
+			.with_policy(WsKind::Tab, WsPolicy::Replace('_'))
+			.with_policy(WsKind::ZeroWidth, WsPolicy::Remove);
+		for (raw, expected, trimmed, runs, collapsed, removed) in [
+			(" Hello World ", "Hello World", 2, 0, 0, 0),
+			("Hello\nWorld", "Hello World", 0, 1, 0, 0),
+			("Hello \r\n World", "Hello World", 0, 1, 3, 0),
+			("Hello\u{3000}World", "Hello World", 0, 1, 2, 0),
+			("Hello \u{200B} World", "Hello World", 0, 1, 1, 3),
+			("Hello\tWorld", "Hello_World", 0, 0, 0, 0),
+			("\u{200B}\t Hello", "Hello", 5, 0, 0, 0),
+		] {
+			let mut stats = TrimStats::new();
+			assert_eq!(normalizer.normalize_observed(raw, &mut stats), expected, "{raw:?}");
+			assert_eq!(
+				stats,
+				TrimStats { trimmed, runs, collapsed, removed },
+				"{raw:?}",
+			);
+
+			if raw.is_ascii() {
+				let mut stats2 = TrimStats::new();
+				let out = normalizer.normalize_bytes_observed(raw.as_bytes(), &mut stats2);
+				assert_eq!(out.as_ref(), expected.as_bytes(), "{raw:?}");
+				assert_eq!(stats, stats2, "{raw:?}");
+			}
+		}
+
+		// Trimmer.
+		let trimmer = Trimmer::new()
+			.with_chars(['-'])
+			.with_edges(Edges::End)
+			.with_collapse(Some('-'));
+		for (raw, expected, trimmed, runs, collapsed) in [
+			("Hello-World", "Hello-World", 0, 0, 0),
+			("Hello World", "Hello-World", 0, 1, 0),
+			("Hello--World", "Hello-World", 0, 1, 1),
+			(" -Hello - World- ", "-Hello-World", 2, 2, 3),
+		] {
+			let mut stats = TrimStats::new();
+			assert_eq!(trimmer.trim_observed(raw, &mut stats), expected, "{raw:?}");
+			assert_eq!(stats, TrimStats { trimmed, runs, collapsed, removed: 0 }, "{raw:?}");
+
+			let mut stats2 = TrimStats::new();
+			let mut s = String::from(raw);
+			trimmer.trim_mut_observed(&mut s, &mut stats2);
+			assert_eq!(s, expected, "{raw:?}");
+			assert_eq!(stats, stats2, "{raw:?}");
+		}
+
+		// Totals.
+		let stats = TrimStats { trimmed: 1, runs: 5, collapsed: 2, removed: 3 };
+		assert_eq!(stats.total(), 6);
+	}
+}
@@ -0,0 +1,260 @@
+/*!
+# Trimothy: Trimmed Parsing.
+*/
+
+use core::{
+	error::Error,
+	fmt,
+};
+
+
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+/// # Trimmed Parsing Error.
+///
+/// This is the error returned by [`parse_trimmed_bytes`] and
+/// [`FromTrimmedBytes`] when a value cannot be parsed.
+pub enum ParseTrimmedError {
+	/// # Empty (or Whitespace-Only) Source.
+	Empty,
+
+	/// # Invalid Number.
+	Invalid,
+
+	/// # Out of Range.
+	Overflow,
+}
+
+impl fmt::Display for ParseTrimmedError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			Self::Empty => "cannot parse number from empty string",
+			Self::Invalid => "invalid number",
+			Self::Overflow => "number out of range",
+		})
+	}
+}
+
+impl Error for ParseTrimmedError {}
+
+
+
+/// # Parse From (Trimmed) Bytes.
+///
+/// This trait is implemented for all of the primitive integer and float
+/// types, parsing them directly from ASCII bytes — no UTF-8 round trip
+/// required.
+///
+/// The method itself does _not_ trim anything; use [`parse_trimmed_bytes`]
+/// for that.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::FromTrimmedBytes;
+///
+/// assert_eq!(u8::from_trimmed_bytes(b"255"), Ok(255));
+/// assert_eq!(i8::from_trimmed_bytes(b"-128"), Ok(-128));
+/// assert!(u8::from_trimmed_bytes(b" 255").is_err());
+/// ```
+pub trait FromTrimmedBytes: Sized {
+	/// # From Trimmed Bytes.
+	///
+	/// Parse `Self` from the exact byte slice provided.
+	///
+	/// ## Errors
+	///
+	/// An error is returned if the slice is empty, not a valid number, or
+	/// out of range for the type.
+	fn from_trimmed_bytes(src: &[u8]) -> Result<Self, ParseTrimmedError>;
+}
+
+/// # Helper: Unsigned Implementations.
+macro_rules! unsigned {
+	($($ty:ty),+ $(,)?) => ($(
+		impl FromTrimmedBytes for $ty {
+			fn from_trimmed_bytes(src: &[u8]) -> Result<Self, ParseTrimmedError> {
+				let (false, digits) = split_sign(src)? else {
+					return Err(ParseTrimmedError::Invalid);
+				};
+
+				let mut out: Self = 0;
+				for &b in digits {
+					let d = digit(b)?;
+					out = out.checked_mul(10)
+						.and_then(|n| n.checked_add(Self::from(d)))
+						.ok_or(ParseTrimmedError::Overflow)?;
+				}
+				Ok(out)
+			}
+		}
+	)+);
+}
+
+unsigned!(u8, u16, u32, u64, u128, usize);
+
+/// # Helper: Signed Implementations.
+macro_rules! signed {
+	($($ty:ty),+ $(,)?) => ($(
+		impl FromTrimmedBytes for $ty {
+			fn from_trimmed_bytes(src: &[u8]) -> Result<Self, ParseTrimmedError> {
+				let (neg, digits) = split_sign(src)?;
+
+				// Negative values are accumulated negatively so MIN fits.
+				let mut out: Self = 0;
+				for &b in digits {
+					let d = Self::from(signed_digit(b)?);
+					out = out.checked_mul(10)
+						.and_then(|n| if neg { n.checked_sub(d) } else { n.checked_add(d) })
+						.ok_or(ParseTrimmedError::Overflow)?;
+				}
+				Ok(out)
+			}
+		}
+	)+);
+}
+
+signed!(i8, i16, i32, i64, i128, isize);
+
+/// # Helper: Float Implementations.
+macro_rules! float {
+	($($ty:ty),+ $(,)?) => ($(
+		impl FromTrimmedBytes for $ty {
+			fn from_trimmed_bytes(src: &[u8]) -> Result<Self, ParseTrimmedError> {
+				if src.is_empty() { return Err(ParseTrimmedError::Empty); }
+
+				// Valid floats are always ASCII, so the (borrowed) conversion
+				// can't fail for anything worth parsing.
+				if ! src.is_ascii() { return Err(ParseTrimmedError::Invalid); }
+				core::str::from_utf8(src).ok()
+					.and_then(|s| s.parse::<Self>().ok())
+					.ok_or(ParseTrimmedError::Invalid)
+			}
+		}
+	)+);
+}
+
+float!(f32, f64);
+
+
+
+/// # Parse Trimmed Bytes.
+///
+/// Trim the leading/trailing ASCII whitespace — and a single pair of
+/// matching surrounding `"` or `'` quotes, if any — from a byte slice and
+/// parse what remains as an integer or float, directly, without any UTF-8
+/// round trip.
+///
+/// This is handy for crunching padded numeric fields in logs, CSVs, and the
+/// like.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::parse_trimmed_bytes;
+///
+/// assert_eq!(parse_trimmed_bytes::<u32>(b"  123\t"), Ok(123));
+/// assert_eq!(parse_trimmed_bytes::<i64>(b" \"-42\" "), Ok(-42));
+/// assert_eq!(parse_trimmed_bytes::<f64>(b"' 1.5 '\r\n"), Ok(1.5));
+///
+/// // Mismatched quotes are left alone.
+/// assert!(parse_trimmed_bytes::<u32>(b"\"123'").is_err());
+/// ```
+///
+/// ## Errors
+///
+/// An error is returned if the trimmed slice is empty, not a valid number,
+/// or out of range for the type.
+pub fn parse_trimmed_bytes<T: FromTrimmedBytes>(src: &[u8])
+-> Result<T, ParseTrimmedError> {
+	let src = src.trim_ascii();
+	let src = match src {
+		[q @ (b'"' | b'\''), rest @ .., q2] if q == q2 => rest.trim_ascii(),
+		_ => src,
+	};
+	T::from_trimmed_bytes(src)
+}
+
+
+
+/// # Digit.
+///
+/// Convert an ASCII digit to its numeric value.
+const fn digit(b: u8) -> Result<u8, ParseTrimmedError> {
+	if b.is_ascii_digit() { Ok(b - b'0') }
+	else { Err(ParseTrimmedError::Invalid) }
+}
+
+#[expect(clippy::cast_possible_wrap, reason = "Digits are always 0-9.")]
+/// # Signed Digit.
+///
+/// Same as [`digit`], but signed.
+const fn signed_digit(b: u8) -> Result<i8, ParseTrimmedError> {
+	match digit(b) {
+		Ok(d) => Ok(d as i8),
+		Err(e) => Err(e),
+	}
+}
+
+/// # Split Sign.
+///
+/// Split off the leading `+` or `-`, if any, returning `true` for negative
+/// along with the remaining digits, which must not be empty.
+const fn split_sign(src: &[u8]) -> Result<(bool, &[u8]), ParseTrimmedError> {
+	let (neg, digits) = match src {
+		[] => return Err(ParseTrimmedError::Empty),
+		[b'-', rest @ ..] => (true, rest),
+		[b'+', rest @ ..] => (false, rest),
+		_ => (false, src),
+	};
+	if digits.is_empty() { Err(ParseTrimmedError::Invalid) }
+	else { Ok((neg, digits)) }
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn t_parse_trimmed_bytes() {
+		for (raw, expected) in [
+			(&b""[..], Err(ParseTrimmedError::Empty)),
+			(b" \t\n", Err(ParseTrimmedError::Empty)),
+			(b"\"\"", Err(ParseTrimmedError::Empty)),
+			(b"+", Err(ParseTrimmedError::Invalid)),
+			(b"-", Err(ParseTrimmedError::Invalid)),
+			(b"1 2", Err(ParseTrimmedError::Invalid)),
+			(b"0x10", Err(ParseTrimmedError::Invalid)),
+			(b"0", Ok(0)),
+			(b"  +7 ", Ok(7)),
+			(b"'-7'", Ok(-7)),
+			(b" \" 127 \" ", Ok(127)),
+			(b"-128", Ok(-128)),
+			(b"128", Err(ParseTrimmedError::Overflow)),
+			(b"-129", Err(ParseTrimmedError::Overflow)),
+		] {
+			assert_eq!(parse_trimmed_bytes::<i8>(raw), expected, "{raw:?}");
+		}
+
+		// Unsigned.
+		assert_eq!(parse_trimmed_bytes::<u8>(b" 255 "), Ok(255));
+		assert_eq!(parse_trimmed_bytes::<u8>(b"256"), Err(ParseTrimmedError::Overflow));
+		assert_eq!(parse_trimmed_bytes::<u8>(b"-0"), Err(ParseTrimmedError::Invalid));
+		assert_eq!(parse_trimmed_bytes::<u64>(b"\t18446744073709551615\n"), Ok(u64::MAX));
+		assert_eq!(parse_trimmed_bytes::<i128>(b"-170141183460469231731687303715884105728"), Ok(i128::MIN));
+
+		// Floats.
+		assert_eq!(parse_trimmed_bytes::<f64>(b" -1.25e2 "), Ok(-125.0));
+		assert_eq!(parse_trimmed_bytes::<f32>(b"'3'"), Ok(3.0));
+		assert_eq!(parse_trimmed_bytes::<f64>(b"1.5\xff"), Err(ParseTrimmedError::Invalid));
+		assert_eq!(parse_trimmed_bytes::<f64>(b"  "), Err(ParseTrimmedError::Empty));
+
+		// Integers should agree with the standard library.
+		for n in [0_u32, 1, 9, 10, 99, 12_345, u32::MAX] {
+			let s = alloc::format!(" {n} ");
+			assert_eq!(parse_trimmed_bytes::<u32>(s.as_bytes()), Ok(n));
+		}
+	}
+}
@@ -15,6 +15,9 @@ use alloc::collections::BTreeSet;
 /// * A single T;
 /// * An array or slice of T;
 /// * A `&BTreeSet<T>`;
+/// * A [`SortedSlice`] of T;
+/// * A [`ClassTable`] (`u8` only);
+/// * A `&dyn DynPattern<T>` (see [`DynPattern`]);
 /// * A custom callback with signature `Fn(T) -> bool`;
 pub trait MatchPattern<T: Copy + Eq + Ord + Sized>: Copy + Sized {
 	/// # Is Match?
@@ -69,6 +72,523 @@ impl<T: Copy + Eq + Ord + Sized> MatchPattern<T> for &[T; 2] {
 
 
 
+/// # Dynamic Pattern Trait.
+///
+/// [`MatchPattern`] is `Copy + Sized`, which keeps things fast and simple,
+/// but makes it impossible to box up, store, or otherwise choose a pattern
+/// at runtime without generics.
+///
+/// This is an object-safe counterpart, automatically implemented for every
+/// [`MatchPattern`]. References to `dyn DynPattern<T>` (including the `Send`
+/// and `Send + Sync` variants) are themselves [`MatchPattern`]s, so can be
+/// passed to any of the match-trimming methods.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::{DynPattern, TrimSliceMatches};
+///
+/// struct Config {
+///     pattern: Box<dyn DynPattern<u8>>,
+/// }
+///
+/// let configs = [
+///     Config { pattern: Box::new(b'.') },
+///     Config { pattern: Box::new(|b: u8| b.is_ascii_digit()) },
+/// ];
+///
+/// assert_eq!(
+///     b"..Hello World!..".trim_matches(&*configs[0].pattern),
+///     b"Hello World!",
+/// );
+/// assert_eq!(
+///     b"12Hello World!34".trim_matches(configs[1].pattern.as_ref()),
+///     b"Hello World!",
+/// );
+/// ```
+pub trait DynPattern<T: Copy + Eq + Ord + Sized> {
+	/// # Is Match?
+	///
+	/// Returns `true` if `thing` should be trimmed.
+	fn dyn_is_match(&self, thing: T) -> bool;
+}
+
+impl<T: Copy + Eq + Ord + Sized, P: MatchPattern<T>> DynPattern<T> for P {
+	#[inline]
+	/// # Is Match?
+	fn dyn_is_match(&self, thing: T) -> bool { self.is_match(thing) }
+}
+
+/// # Helper: Dynamic Pattern Implementations.
+macro_rules! dyn_pattern {
+	($($ty:ty),+ $(,)?) => ($(
+		impl<T: Copy + Eq + Ord + Sized> MatchPattern<T> for &$ty {
+			#[inline]
+			/// # Dynamic Match.
+			fn is_match(self, thing: T) -> bool { self.dyn_is_match(thing) }
+		}
+	)+);
+}
+
+dyn_pattern!(
+	dyn DynPattern<T>,
+	dyn DynPattern<T> + Send,
+	dyn DynPattern<T> + Send + Sync,
+);
+
+
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+/// # Byte Set.
+///
+/// This is a compact, `Copy`able, 256-bit bitmap of bytes, offering `O(1)`
+/// lookups without any indirection.
+///
+/// It is the recommended alternative to `&BTreeSet<u8>` patterns — which
+/// pay for a tree probe on every byte checked — particularly when the same
+/// set is used for many trimming operations. Conversion is as easy as
+/// [`ByteSet::from`].
+///
+/// ## Examples
+///
+/// ```
+/// use std::collections::BTreeSet;
+/// use trimothy::{ByteSet, TrimSliceMatches};
+///
+/// let tree = BTreeSet::from([b' ', b'-', b'_']);
+/// let set = ByteSet::from(&tree);
+/// assert_eq!(
+///     b"_- Hello World! -_".trim_matches(set),
+///     b"Hello World!",
+/// );
+///
+/// // Sets can be built at compile time too.
+/// const SET: ByteSet = ByteSet::from_bytes(b" -_");
+/// assert_eq!(SET, set);
+/// ```
+pub struct ByteSet([u64; 4]);
+
+impl From<&BTreeSet<u8>> for ByteSet {
+	#[inline]
+	fn from(src: &BTreeSet<u8>) -> Self { src.iter().copied().collect() }
+}
+
+impl From<&[u8]> for ByteSet {
+	#[inline]
+	fn from(src: &[u8]) -> Self { Self::from_bytes(src) }
+}
+
+impl<const N: usize> From<[u8; N]> for ByteSet {
+	#[inline]
+	fn from(src: [u8; N]) -> Self { Self::from_bytes(src.as_slice()) }
+}
+
+impl<const N: usize> From<&[u8; N]> for ByteSet {
+	#[inline]
+	fn from(src: &[u8; N]) -> Self { Self::from_bytes(src.as_slice()) }
+}
+
+impl From<ClassTable<'_>> for ByteSet {
+	fn from(src: ClassTable<'_>) -> Self {
+		(0..=u8::MAX).filter(|&b| src.contains(b)).collect()
+	}
+}
+
+impl From<CharSet> for ByteSet {
+	fn from(src: CharSet) -> Self {
+		(0..=u8::MAX).filter(|&b| src.matches_byte(b)).collect()
+	}
+}
+
+impl Extend<u8> for ByteSet {
+	#[inline]
+	fn extend<I: IntoIterator<Item=u8>>(&mut self, iter: I) {
+		for b in iter { self.insert(b); }
+	}
+}
+
+impl FromIterator<u8> for ByteSet {
+	#[inline]
+	fn from_iter<I: IntoIterator<Item=u8>>(iter: I) -> Self {
+		let mut out = Self::new();
+		out.extend(iter);
+		out
+	}
+}
+
+impl MatchPattern<u8> for ByteSet {
+	#[inline]
+	/// # Match Set.
+	fn is_match(self, thing: u8) -> bool { self.contains(thing) }
+}
+
+impl ByteSet {
+	#[must_use]
+	/// # New (Empty).
+	pub const fn new() -> Self { Self([0; 4]) }
+
+	#[must_use]
+	/// # From Bytes.
+	///
+	/// Build a set from a slice of bytes at compile time (or runtime).
+	pub const fn from_bytes(src: &[u8]) -> Self {
+		let mut out = [0_u64; 4];
+		let mut idx = 0;
+		while idx < src.len() {
+			let b = src[idx];
+			out[(b >> 6) as usize] |= 1 << (b & 63);
+			idx += 1;
+		}
+		Self(out)
+	}
+
+	#[inline]
+	#[must_use]
+	/// # Contains?
+	///
+	/// Returns `true` if the byte is in the set.
+	pub const fn contains(self, b: u8) -> bool {
+		self.0[(b >> 6) as usize] & (1 << (b & 63)) != 0
+	}
+
+	#[must_use]
+	/// # Is Empty?
+	pub const fn is_empty(self) -> bool {
+		self.0[0] == 0 && self.0[1] == 0 && self.0[2] == 0 && self.0[3] == 0
+	}
+
+	#[must_use]
+	/// # Length.
+	///
+	/// Return the number of bytes in the set.
+	pub const fn len(self) -> usize {
+		(
+			self.0[0].count_ones() + self.0[1].count_ones() +
+			self.0[2].count_ones() + self.0[3].count_ones()
+		) as usize
+	}
+
+	#[inline]
+	/// # Insert.
+	///
+	/// Add a byte to the set.
+	pub fn insert(&mut self, b: u8) {
+		self.0[(b >> 6) as usize] |= 1 << (b & 63);
+	}
+
+	#[inline]
+	/// # Remove.
+	///
+	/// Remove a byte from the set.
+	pub fn remove(&mut self, b: u8) {
+		self.0[(b >> 6) as usize] &= ! (1 << (b & 63));
+	}
+
+	/// # Iterate.
+	///
+	/// Return an iterator over the bytes in the set, in ascending order.
+	pub fn iter(self) -> impl Iterator<Item=u8> {
+		(0..=u8::MAX).filter(move |&b| self.contains(b))
+	}
+}
+
+
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, PartialEq)]
+/// # Character Class Set.
+///
+/// This is a compact, `Copy`able set of character _classes_ — whitespace,
+/// control characters, etc. — that can be used as a pattern for any of the
+/// match-trimming methods, or combined with explicit characters in a
+/// [`Trimmer`](crate::Trimmer).
+///
+/// Sets can be combined with `|` or [`CharSet::union`], and inverted with
+/// `!` — or one of the prebuilt `NOT_*` constants — to match everything
+/// _outside_ the set. (See [`Negated`].)
+///
+/// | Class | `char` Test | `u8` Test |
+/// | ----- | ----------- | --------- |
+/// | `WHITESPACE` | [`char::is_whitespace`] | [`u8::is_ascii_whitespace`] |
+/// | `CONTROL` | [`char::is_control`] | [`u8::is_ascii_control`] |
+/// | `ALPHABETIC` | [`char::is_alphabetic`] | [`u8::is_ascii_alphabetic`] |
+/// | `NUMERIC` | [`char::is_numeric`] | [`u8::is_ascii_digit`] |
+/// | `ASCII_PUNCTUATION` | [`char::is_ascii_punctuation`] | [`u8::is_ascii_punctuation`] |
+/// | `ASCII_GRAPHIC` | [`char::is_ascii_graphic`] | [`u8::is_ascii_graphic`] |
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::{CharSet, TrimSliceMatches};
+///
+/// let set = CharSet::WHITESPACE | CharSet::ASCII_PUNCTUATION;
+/// assert_eq!(
+///     "  ...Hello World!?  ".trim_matches(|c| set.matches_char(c)),
+///     "Hello World",
+/// );
+/// assert_eq!(
+///     b"  ...Hello World!?  ".trim_matches(set),
+///     b"Hello World",
+/// );
+/// ```
+pub struct CharSet(u8);
+
+impl core::ops::BitOr for CharSet {
+	type Output = Self;
+
+	#[inline]
+	fn bitor(self, other: Self) -> Self { self.union(other) }
+}
+
+impl core::ops::BitOrAssign for CharSet {
+	#[inline]
+	fn bitor_assign(&mut self, other: Self) { *self = self.union(other); }
+}
+
+impl core::ops::Not for CharSet {
+	type Output = Negated<Self>;
+
+	#[inline]
+	fn not(self) -> Self::Output { Negated(self) }
+}
+
+impl MatchPattern<char> for CharSet {
+	#[inline]
+	/// # Match Class.
+	fn is_match(self, thing: char) -> bool { self.matches_char(thing) }
+}
+
+impl MatchPattern<u8> for CharSet {
+	#[inline]
+	/// # Match Class.
+	fn is_match(self, thing: u8) -> bool { self.matches_byte(thing) }
+}
+
+impl CharSet {
+	/// # No Classes.
+	pub const NONE: Self = Self(0);
+
+	/// # Whitespace.
+	pub const WHITESPACE: Self = Self(0b0000_0001);
+
+	/// # Control Characters.
+	pub const CONTROL: Self = Self(0b0000_0010);
+
+	/// # Alphabetic.
+	pub const ALPHABETIC: Self = Self(0b0000_0100);
+
+	/// # Numeric.
+	pub const NUMERIC: Self = Self(0b0000_1000);
+
+	/// # ASCII Punctuation.
+	pub const ASCII_PUNCTUATION: Self = Self(0b0001_0000);
+
+	/// # ASCII Graphic.
+	pub const ASCII_GRAPHIC: Self = Self(0b0010_0000);
+
+	/// # Alphanumeric.
+	///
+	/// This is equivalent to `ALPHABETIC | NUMERIC`.
+	pub const ALPHANUMERIC: Self = Self(Self::ALPHABETIC.0 | Self::NUMERIC.0);
+
+	/// # Not Alphabetic.
+	pub const NOT_ALPHABETIC: Negated<Self> = Negated(Self::ALPHABETIC);
+
+	/// # Not Numeric.
+	pub const NOT_NUMERIC: Negated<Self> = Negated(Self::NUMERIC);
+
+	/// # Not Alphanumeric.
+	pub const NOT_ALPHANUMERIC: Negated<Self> = Negated(Self::ALPHANUMERIC);
+
+	/// # Not ASCII Graphic.
+	pub const NOT_ASCII_GRAPHIC: Negated<Self> = Negated(Self::ASCII_GRAPHIC);
+
+	/// # All Classes (and Names).
+	pub(crate) const CLASSES: [(Self, &'static str); 6] = [
+		(Self::WHITESPACE, "whitespace"),
+		(Self::CONTROL, "control"),
+		(Self::ALPHABETIC, "alphabetic"),
+		(Self::NUMERIC, "numeric"),
+		(Self::ASCII_PUNCTUATION, "ascii_punctuation"),
+		(Self::ASCII_GRAPHIC, "ascii_graphic"),
+	];
+
+	#[must_use]
+	/// # Union.
+	///
+	/// Return a set containing the classes from both `self` and `other`.
+	pub const fn union(self, other: Self) -> Self { Self(self.0 | other.0) }
+
+	#[must_use]
+	/// # Contains Classes?
+	///
+	/// Returns `true` if `self` includes every class in `other`.
+	pub const fn contains(self, other: Self) -> bool { self.0 & other.0 == other.0 }
+
+	#[must_use]
+	/// # Is Empty?
+	///
+	/// Returns `true` if the set contains no classes.
+	pub const fn is_empty(self) -> bool { self.0 == 0 }
+
+	#[must_use]
+	/// # Matches Char?
+	///
+	/// Returns `true` if the `char` belongs to any of the classes in the set.
+	pub fn matches_char(self, c: char) -> bool {
+		(self.contains(Self::WHITESPACE) && c.is_whitespace()) ||
+		(self.contains(Self::CONTROL) && c.is_control()) ||
+		(self.contains(Self::ALPHABETIC) && c.is_alphabetic()) ||
+		(self.contains(Self::NUMERIC) && c.is_numeric()) ||
+		(self.contains(Self::ASCII_PUNCTUATION) && c.is_ascii_punctuation()) ||
+		(self.contains(Self::ASCII_GRAPHIC) && c.is_ascii_graphic())
+	}
+
+	#[must_use]
+	/// # Matches Byte?
+	///
+	/// Returns `true` if the byte belongs to any of the classes in the set.
+	///
+	/// Bytes are always evaluated as ASCII, so will never match anything
+	/// above `0x7F`.
+	pub const fn matches_byte(self, b: u8) -> bool {
+		(self.contains(Self::WHITESPACE) && b.is_ascii_whitespace()) ||
+		(self.contains(Self::CONTROL) && b.is_ascii_control()) ||
+		(self.contains(Self::ALPHABETIC) && b.is_ascii_alphabetic()) ||
+		(self.contains(Self::NUMERIC) && b.is_ascii_digit()) ||
+		(self.contains(Self::ASCII_PUNCTUATION) && b.is_ascii_punctuation()) ||
+		(self.contains(Self::ASCII_GRAPHIC) && b.is_ascii_graphic())
+	}
+
+	/// # Class Names.
+	///
+	/// Return the (lowercase) names of each of the individual classes in
+	/// the set.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::CharSet;
+	///
+	/// let set = CharSet::WHITESPACE | CharSet::NUMERIC;
+	/// assert_eq!(
+	///     set.names().collect::<Vec<_>>(),
+	///     ["whitespace", "numeric"],
+	/// );
+	/// ```
+	pub fn names(self) -> impl Iterator<Item=&'static str> {
+		Self::CLASSES.into_iter().filter_map(move |(class, name)|
+			if self.contains(class) { Some(name) }
+			else { None }
+		)
+	}
+
+	#[must_use]
+	/// # From Name.
+	///
+	/// Return the class corresponding to the (case-insensitive) name, if
+	/// any. `"alphanumeric"` is accepted as a shorthand too.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::CharSet;
+	///
+	/// assert_eq!(CharSet::from_name("Whitespace"), Some(CharSet::WHITESPACE));
+	/// assert_eq!(CharSet::from_name("alphanumeric"), Some(CharSet::ALPHANUMERIC));
+	/// assert_eq!(CharSet::from_name("nope"), None);
+	/// ```
+	pub fn from_name(name: &str) -> Option<Self> {
+		let name = name.trim();
+		if name.eq_ignore_ascii_case("alphanumeric") { return Some(Self::ALPHANUMERIC); }
+		Self::CLASSES.into_iter().find_map(|(class, n)|
+			if name.eq_ignore_ascii_case(n) { Some(class) }
+			else { None }
+		)
+	}
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for CharSet {
+	/// # Arbitrary.
+	///
+	/// Only the defined class bits are kept.
+	fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+		let all = Self::CLASSES.iter().fold(0, |acc, (class, _)| acc | class.0);
+		Ok(Self(u8::arbitrary(u)? & all))
+	}
+
+	#[inline]
+	fn size_hint(depth: usize) -> (usize, Option<usize>) { u8::size_hint(depth) }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for CharSet {
+	/// # Serialize.
+	///
+	/// Sets are serialized as a sequence of class names.
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.collect_seq(self.names())
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CharSet {
+	/// # Deserialize.
+	///
+	/// Sets are deserialized from a sequence of (case-insensitive) class
+	/// names, e.g. `["whitespace", "control"]`.
+	fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		/// # Class Name.
+		struct Class(CharSet);
+
+		impl<'de> serde::Deserialize<'de> for Class {
+			fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+				/// # Visitor.
+				struct Visitor;
+
+				impl serde::de::Visitor<'_> for Visitor {
+					type Value = Class;
+
+					fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+						f.write_str("a character class name")
+					}
+
+					fn visit_str<E: serde::de::Error>(self, src: &str) -> Result<Class, E> {
+						CharSet::from_name(src).map(Class).ok_or_else(||
+							E::unknown_variant(src, &[
+								"whitespace", "control", "alphabetic", "numeric",
+								"alphanumeric", "ascii_punctuation", "ascii_graphic",
+							])
+						)
+					}
+				}
+
+				deserializer.deserialize_str(Visitor)
+			}
+		}
+
+		/// # Visitor.
+		struct Visitor;
+
+		impl<'de> serde::de::Visitor<'de> for Visitor {
+			type Value = CharSet;
+
+			fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+				f.write_str("a list of character class names")
+			}
+
+			fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A)
+			-> Result<CharSet, A::Error> {
+				let mut out = CharSet::NONE;
+				while let Some(Class(class)) = seq.next_element()? { out |= class; }
+				Ok(out)
+			}
+		}
+
+		deserializer.deserialize_seq(Visitor)
+	}
+}
+
+
 // Note: for some reason Rust things FN(T) conflicts with T, so we have to be
 // specific. Haha.
 
@@ -109,6 +629,204 @@ arr!(
 
 
 
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, PartialEq)]
+/// # Negated Pattern.
+///
+/// This wraps any other pattern, matching whatever it _doesn't_, making
+/// "trim everything that isn't a letter or digit" a one-liner.
+///
+/// [`CharSet`] provides prebuilt `NOT_*` constants for the most common
+/// cases, and supports `!` for the rest.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::{CharSet, Negated, TrimMatchesMut, TrimSliceMatches};
+///
+/// let mut username = String::from("  @björk_99!! ");
+/// username.trim_matches_mut(CharSet::NOT_ALPHANUMERIC);
+/// assert_eq!(username, "björk_99");
+///
+/// assert_eq!(
+///     b"\x00\x7FToken\n".trim_matches(CharSet::NOT_ASCII_GRAPHIC),
+///     b"Token",
+/// );
+/// assert_eq!(
+///     b"--Hello--World--".trim_matches(!(CharSet::ALPHABETIC | CharSet::WHITESPACE)),
+///     b"Hello--World",
+/// );
+///
+/// // Any other pattern can be negated too.
+/// assert_eq!(b"(555) 867-5309.".trim_matches(Negated(b"0123456789")), b"555) 867-5309");
+/// assert_eq!(b"..abc..".trim_start_matches(Negated(b'a')), b"abc..");
+/// ```
+pub struct Negated<P>(pub P);
+
+impl<T: Copy + Eq + Ord + Sized, P: MatchPattern<T>> MatchPattern<T> for Negated<P> {
+	#[inline]
+	/// # Match Negation.
+	fn is_match(self, thing: T) -> bool { ! self.0.is_match(thing) }
+}
+
+
+
+#[derive(Debug, Clone, Copy, Default)]
+/// # Predicate Pattern.
+///
+/// Bare callbacks can only be used as patterns for `char` and `u8`, as
+/// Rust can't otherwise tell them apart from single-value patterns. This
+/// wrapper fills the gap, turning any `Fn(T) -> bool` into a pattern for
+/// arbitrary `T`.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::{Negated, Predicate, TrimSliceMatches};
+///
+/// let s: &[u16] = &[0, 1, 500, 600, 2, 0];
+/// assert_eq!(s.trim_matches(Predicate(|n: u16| n < 10)), &[500, 600]);
+/// assert_eq!(s.trim_start_matches(Negated(Predicate(|n: u16| n > 10))), &[500, 600, 2, 0]);
+/// ```
+pub struct Predicate<F>(pub F);
+
+impl<T: Copy + Eq + Ord + Sized, F: Fn(T) -> bool + Copy> MatchPattern<T> for Predicate<F> {
+	#[inline]
+	/// # Custom Match.
+	fn is_match(self, thing: T) -> bool { (self.0)(thing) }
+}
+
+
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+/// # Sorted Slice Pattern.
+///
+/// This wraps a slice known to be sorted, matching membership via binary
+/// search rather than the linear scan used for plain slice patterns.
+///
+/// For small patterns the difference is negligible, but large exclusion
+/// lists — dozens of punctuation marks, say — are checked in `O(log n)`
+/// instead of `O(n)`.
+///
+/// Sortedness is verified once, at construction; unsorted slices are
+/// rejected.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::{SortedSlice, TrimSliceMatches};
+///
+/// let pat = SortedSlice::new(b"!\"#$%&'()*+,-./".as_slice()).unwrap();
+/// assert_eq!(
+///     b"((Hello World!))".trim_matches(pat),
+///     b"Hello World",
+/// );
+///
+/// // Unsorted slices are rejected.
+/// assert!(SortedSlice::new(b"ba".as_slice()).is_none());
+/// ```
+pub struct SortedSlice<'a, T>(&'a [T]);
+
+impl<T: Copy + Eq + Ord + Sized> MatchPattern<T> for SortedSlice<'_, T> {
+	#[inline]
+	/// # Match Sorted Slice.
+	fn is_match(self, thing: T) -> bool { self.0.binary_search(&thing).is_ok() }
+}
+
+impl<'a, T: Ord> SortedSlice<'a, T> {
+	#[must_use]
+	/// # New.
+	///
+	/// Wrap a slice, returning `None` if it is not sorted in ascending
+	/// order.
+	pub fn new(src: &'a [T]) -> Option<Self> {
+		if src.windows(2).all(|w| w[0] <= w[1]) { Some(Self(src)) }
+		else { None }
+	}
+
+	#[inline]
+	#[must_use]
+	/// # As Slice.
+	///
+	/// Return the underlying (sorted) slice.
+	pub const fn as_slice(self) -> &'a [T] { self.0 }
+}
+
+
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+/// # Byte Class Table.
+///
+/// This is a byte pattern backed by a precomputed `[bool; 256]` lookup
+/// table, indexed by byte, where `true` means "match".
+///
+/// Parsers, lexers, and the like often have such tables lying around
+/// already; wrapping them in a `ClassTable` lets them be passed straight
+/// through to any of the byte-based match-trimming methods, like
+/// [`TrimSliceMatches::trim_matches`](crate::TrimSliceMatches::trim_matches),
+/// with `O(1)` lookups and no conversion.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::{ClassTable, TrimSliceMatches};
+///
+/// // Build a table matching ASCII digits and underscores.
+/// static DIGITISH: [bool; 256] = {
+///     let mut out = [false; 256];
+///     let mut i = b'0';
+///     while i <= b'9' {
+///         out[i as usize] = true;
+///         i += 1;
+///     }
+///     out[b'_' as usize] = true;
+///     out
+/// };
+///
+/// let table = ClassTable::new(&DIGITISH);
+/// assert_eq!(
+///     b"123_Hello World!_456".trim_matches(table),
+///     b"Hello World!",
+/// );
+/// ```
+pub struct ClassTable<'a>(&'a [bool; 256]);
+
+impl<'a> From<&'a [bool; 256]> for ClassTable<'a> {
+	#[inline]
+	fn from(src: &'a [bool; 256]) -> Self { Self(src) }
+}
+
+impl MatchPattern<u8> for ClassTable<'_> {
+	#[inline]
+	/// # Match Table.
+	fn is_match(self, thing: u8) -> bool { self.0[usize::from(thing)] }
+}
+
+impl<'a> ClassTable<'a> {
+	#[inline]
+	#[must_use]
+	/// # New.
+	///
+	/// Wrap a byte lookup table, where `table[b]` is `true` for each byte
+	/// `b` that should match.
+	pub const fn new(table: &'a [bool; 256]) -> Self { Self(table) }
+
+	#[inline]
+	#[must_use]
+	/// # As Array.
+	///
+	/// Return the underlying lookup table.
+	pub const fn as_array(self) -> &'a [bool; 256] { self.0 }
+
+	#[inline]
+	#[must_use]
+	/// # Contains?
+	///
+	/// Returns `true` if the byte is in the table.
+	pub const fn contains(self, b: u8) -> bool { self.0[b as usize] }
+}
+
+
+
 #[cfg(test)]
 mod test {
 	use super::*;
@@ -159,5 +877,151 @@ mod test {
 		let foo = |b: u8| -> bool { b == b'b' };
 		assert!(foo.is_match(b'b'));
 		assert!(! foo.is_match(b'X'));
+
+		// Class table.
+		let mut table = [false; 256];
+		table[usize::from(b'b')] = true;
+		table[usize::from(b'.')] = true;
+		let table = ClassTable::new(&table);
+		assert!(table.is_match(b'b'));
+		assert!(table.is_match(b'.'));
+		assert!(! table.is_match(b'a'));
+		assert!(! table.is_match(255));
+		assert!(table.contains(b'b'));
+
+		// Dynamic.
+		let dynamic: [&dyn DynPattern<u8>; 3] = [&b'b', b"b.", &strip_b];
+		for pat in dynamic {
+			assert!(pat.is_match(b'b'));
+			assert!(! pat.is_match(b'a'));
+		}
+		let dynamic: &(dyn DynPattern<char> + Send + Sync) = &char::is_whitespace;
+		assert!(dynamic.is_match(' '));
+		assert!(! dynamic.is_match('a'));
+
+		// Byte set.
+		let set = ByteSet::from(b"b.!");
+		assert!(set.is_match(b'b'));
+		assert!(set.is_match(b'.'));
+		assert!(set.is_match(b'!'));
+		assert!(! set.is_match(b'a'));
+		// Character classes.
+		let set = CharSet::WHITESPACE | CharSet::NUMERIC;
+		assert!(set.is_match(b' '));
+		assert!(set.is_match(b'1'));
+		assert!(! set.is_match(b'a'));
+		assert!(! set.is_match(0xA0_u8));
+		assert!(set.is_match('\u{a0}'));
+		assert!(set.is_match('٣'));
+		assert!(! set.is_match('a'));
+		assert!(! CharSet::NONE.is_match('a'));
+		assert!(! CharSet::NONE.is_match(b' '));
+
+		// Negation.
+		assert!(! Negated(b'b').is_match(b'b'));
+		assert!(Negated(b'b').is_match(b'a'));
+		assert!(Negated(&arr).is_match(b'a'));
+		assert!(! Negated(&arr).is_match(b'!'));
+		assert!(! Negated(set).is_match('1'));
+		assert!(Negated(set).is_match('a'));
+		assert!(CharSet::NOT_ALPHANUMERIC.is_match('!'));
+		assert!(! CharSet::NOT_ALPHANUMERIC.is_match('ö'));
+		assert!(CharSet::NOT_ALPHANUMERIC.is_match(0xF6_u8));
+		assert!(CharSet::NOT_ASCII_GRAPHIC.is_match(b' '));
+		assert!(! CharSet::NOT_ASCII_GRAPHIC.is_match(b'~'));
+		assert_eq!(!CharSet::NUMERIC, CharSet::NOT_NUMERIC);
+		assert_eq!(!CharSet::ALPHABETIC, CharSet::NOT_ALPHABETIC);
+		assert!(! Negated(Negated('a')).is_match('b'));
+	}
+
+	#[cfg(feature = "arbitrary")]
+	#[test]
+	fn t_arbitrary() {
+		use arbitrary::{Arbitrary, Unstructured};
+		use crate::{Normalizer, Trimmer};
+
+		// Undefined class bits are dropped.
+		let set = CharSet::arbitrary(&mut Unstructured::new(&[u8::MAX])).unwrap();
+		assert_eq!(set.names().count(), CharSet::CLASSES.len());
+		assert_eq!(set, CharSet::CLASSES.iter().fold(CharSet::NONE, |acc, (c, _)| acc | *c));
+
+		// Everything else should build from whatever.
+		let raw: alloc::vec::Vec<u8> = (0..=u8::MAX).rev().cycle().take(1024).collect();
+		let mut u = Unstructured::new(&raw);
+		assert!(ByteSet::arbitrary(&mut u).is_ok());
+		assert!(Normalizer::arbitrary(&mut u).is_ok());
+		assert!(Trimmer::arbitrary(&mut u).is_ok());
+	}
+
+	#[test]
+	fn t_byteset() {
+		let mut set = ByteSet::new();
+		assert!(set.is_empty());
+		assert_eq!(set.len(), 0);
+
+		// Add everything.
+		for b in 0..=u8::MAX {
+			assert!(! set.contains(b));
+			set.insert(b);
+			assert!(set.contains(b));
+			assert_eq!(set.len(), usize::from(b) + 1);
+		}
+		assert!(set.iter().eq(0..=u8::MAX));
+
+		// And take it all away.
+		for b in 0..=u8::MAX {
+			set.remove(b);
+			assert!(! set.contains(b));
+		}
+		assert!(set.is_empty());
+
+		// Conversions should agree.
+		let tree = BTreeSet::from([0_u8, 1, 63, 64, 127, 128, 255]);
+		let set = ByteSet::from(&tree);
+		assert_eq!(set.len(), tree.len());
+		assert!(set.iter().eq(tree.iter().copied()));
+		assert_eq!(set, ByteSet::from([0_u8, 1, 63, 64, 127, 128, 255]));
+		assert_eq!(set, ByteSet::from_bytes(&[255, 128, 127, 64, 63, 1, 0, 0]));
+
+		let set = ByteSet::from(CharSet::WHITESPACE);
+		assert!(set.iter().eq((0..=u8::MAX).filter(u8::is_ascii_whitespace)));
+
+		let mut table = [false; 256];
+		table[usize::from(b'x')] = true;
+		assert!(ByteSet::from(ClassTable::new(&table)).iter().eq([b'x']));
+	}
+
+	#[test]
+	fn t_charset_names() {
+		let mut all = CharSet::NONE;
+		for (class, name) in CharSet::CLASSES {
+			assert_eq!(CharSet::from_name(name), Some(class));
+			assert_eq!(CharSet::from_name(&name.to_ascii_uppercase()), Some(class));
+			assert!(! all.contains(class));
+			all |= class;
+		}
+		assert_eq!(all.names().count(), CharSet::CLASSES.len());
+		assert_eq!(CharSet::from_name("alphanumeric"), Some(CharSet::ALPHANUMERIC));
+		assert_eq!(CharSet::from_name("nope"), None);
+	}
+
+	#[test]
+	fn t_sorted_slice() {
+		const PUNCT: &[u8] = b"!\"#$%&'()*+,-./:;<=>?@[\\]^_`{|}~";
+		let pat = SortedSlice::new(PUNCT).expect("Punctuation should be sorted.");
+		assert_eq!(pat.as_slice(), PUNCT);
+		for b in 0..=u8::MAX {
+			assert_eq!(pat.is_match(b), PUNCT.is_match(b), "{b}");
+		}
+
+		// Chars too.
+		let pat = SortedSlice::new(['-', '_', '¡', '¿'].as_slice()).unwrap();
+		assert!(pat.is_match('¿'));
+		assert!(! pat.is_match('a'));
+
+		// Duplicates are fine; disorder isn't.
+		assert!(SortedSlice::<u8>::new(&[]).is_some());
+		assert!(SortedSlice::new(b"aab".as_slice()).is_some());
+		assert!(SortedSlice::new(b"aba".as_slice()).is_none());
 	}
 }
@@ -0,0 +1,236 @@
+/*!
+# Trimothy: Property-Test Strategies.
+*/
+
+use alloc::{
+	string::String,
+	vec::Vec,
+};
+use proptest::{
+	collection::vec,
+	prelude::*,
+	sample::select,
+};
+
+
+
+/// # Unicode Whitespace.
+///
+/// Every `char` for which [`char::is_whitespace`] returns `true`.
+const WS_CHARS: &[char] = &[
+	'\t', '\n', '\x0B', '\x0C', '\r', ' ', '\u{85}', '\u{A0}', '\u{1680}',
+	'\u{2000}', '\u{2001}', '\u{2002}', '\u{2003}', '\u{2004}', '\u{2005}',
+	'\u{2006}', '\u{2007}', '\u{2008}', '\u{2009}', '\u{200A}', '\u{2028}',
+	'\u{2029}', '\u{202F}', '\u{205F}', '\u{3000}',
+];
+
+/// # ASCII Whitespace.
+///
+/// Every byte for which [`u8::is_ascii_whitespace`] returns `true`.
+const WS_BYTES: &[u8] = b"\t\n\x0C\r ";
+
+
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+/// # Dirty Text.
+///
+/// This is the value generated by the [`dirty_text`] and [`dirty_bytes`]
+/// property-test strategies: a `raw` value with (potentially) messy
+/// whitespace, along with the results trimothy is expected to produce for
+/// it.
+pub struct DirtyText<T> {
+	/// # Raw Value.
+	pub raw: T,
+
+	/// # Expected Trimmed Value.
+	///
+	/// This is what `raw` should look like with its leading and trailing
+	/// whitespace trimmed.
+	pub trimmed: T,
+
+	/// # Expected Normalized Value.
+	///
+	/// This is what `raw` should look like after
+	/// [`TrimNormal::trim_and_normalize`](crate::TrimNormal::trim_and_normalize).
+	pub normalized: T,
+}
+
+
+
+/// # Dirty Text Strategy.
+///
+/// Return a [`proptest`](mod@proptest) strategy generating strings of up to
+/// `max_words` non-whitespace "words", separated — and surrounded — by
+/// whitespace, along with their expected trimmed and normalized forms.
+///
+/// The `dirtiness` (`0..=100`) is the percent chance that any given gap
+/// will contain messy whitespace — a run of arbitrary Unicode whitespace
+/// characters — instead of a single space (or nothing, at the edges).
+///
+/// The expectations are built independently of the crate's own
+/// implementations, making them suitable for property-testing downstream
+/// sanitizers against trimothy's semantics.
+///
+/// This function is only available when the `proptest` crate feature is
+/// enabled.
+///
+/// ## Examples
+///
+/// ```
+/// use proptest::prelude::*;
+/// use trimothy::{dirty_text, TrimNormal};
+///
+/// proptest! {
+///     fn my_sanitizer(text in dirty_text(8, 50)) {
+///         prop_assert_eq!(text.raw.trim(), text.trimmed.as_str());
+///         prop_assert_eq!(text.raw.trim_and_normalize(), text.normalized.as_str());
+///     }
+/// }
+/// # my_sanitizer();
+/// ```
+pub fn dirty_text(max_words: usize, dirtiness: u8)
+-> impl Strategy<Value=DirtyText<String>> {
+	let word = vec(any::<char>().prop_filter("whitespace", |c| ! c.is_whitespace()), 1..8)
+		.prop_map(String::from_iter);
+	let ws = vec(select(WS_CHARS), 1..4).prop_map(String::from_iter);
+
+	(
+		edge(ws.clone(), dirtiness),
+		vec((word, gap(ws.clone(), String::from(" "), dirtiness)), 0..=max_words),
+		edge(ws, dirtiness),
+	)
+		.prop_map(|(head, parts, tail)| {
+			let (trimmed, normalized) = assemble(&parts, &String::from(" "), |out, v| out.push_str(v));
+			let mut raw = head;
+			raw.push_str(&trimmed);
+			raw.push_str(&tail);
+			DirtyText { raw, trimmed, normalized }
+		})
+}
+
+/// # Dirty Bytes Strategy.
+///
+/// Same as [`dirty_text`], but for byte strings, using (only) ASCII
+/// whitespace for the messy bits.
+///
+/// This function is only available when the `proptest` crate feature is
+/// enabled.
+///
+/// ## Examples
+///
+/// ```
+/// use proptest::prelude::*;
+/// use trimothy::{dirty_bytes, TrimNormal};
+///
+/// proptest! {
+///     fn my_sanitizer(text in dirty_bytes(8, 50)) {
+///         prop_assert_eq!(text.raw.trim_ascii(), text.trimmed.as_slice());
+///         prop_assert_eq!(text.raw.trim_and_normalize(), text.normalized.as_slice());
+///     }
+/// }
+/// # my_sanitizer();
+/// ```
+pub fn dirty_bytes(max_words: usize, dirtiness: u8)
+-> impl Strategy<Value=DirtyText<Vec<u8>>> {
+	let word = vec(any::<u8>().prop_filter("whitespace", |b| ! b.is_ascii_whitespace()), 1..8);
+	let ws = vec(select(WS_BYTES), 1..4);
+
+	(
+		edge(ws.clone(), dirtiness),
+		vec((word, gap(ws.clone(), b" ".to_vec(), dirtiness)), 0..=max_words),
+		edge(ws, dirtiness),
+	)
+		.prop_map(|(head, parts, tail)| {
+			let (trimmed, normalized) = assemble(&parts, &b" ".to_vec(), |out, v| out.extend_from_slice(v));
+			let mut raw = head;
+			raw.extend_from_slice(&trimmed);
+			raw.extend_from_slice(&tail);
+			DirtyText { raw, trimmed, normalized }
+		})
+}
+
+
+
+/// # Weights.
+///
+/// Return the clean and dirty weights for a given dirtiness.
+fn weights(dirtiness: u8) -> (u32, u32) {
+	let dirty = u32::from(dirtiness.min(100));
+	(100 - dirty, dirty)
+}
+
+/// # Edge Strategy.
+///
+/// Leading/trailing whitespace: either nothing or a dirty run.
+fn edge<T, S>(ws: S, dirtiness: u8) -> impl Strategy<Value=T>
+where T: Clone + core::fmt::Debug + Default, S: Strategy<Value=T> {
+	let (clean, dirty) = weights(dirtiness);
+	prop_oneof![clean => Just(T::default()), dirty => ws]
+}
+
+/// # Gap Strategy.
+///
+/// Inner whitespace: either a single space or a dirty run.
+fn gap<T, S>(ws: S, space: T, dirtiness: u8) -> impl Strategy<Value=T>
+where T: Clone + core::fmt::Debug, S: Strategy<Value=T> {
+	let (clean, dirty) = weights(dirtiness);
+	prop_oneof![clean => Just(space), dirty => ws]
+}
+
+/// # Assemble.
+///
+/// Join the words and separators together, returning the expected trimmed
+/// and normalized values. (The final separator is dropped.)
+fn assemble<T, F>(parts: &[(T, T)], space: &T, push: F) -> (T, T)
+where T: Default, F: Fn(&mut T, &T) {
+	let mut trimmed = T::default();
+	let mut normalized = T::default();
+	let mut sep = None;
+	for (word, ws) in parts {
+		if let Some(last) = sep.replace(ws) {
+			push(&mut trimmed, last);
+			push(&mut normalized, space);
+		}
+		push(&mut trimmed, word);
+		push(&mut normalized, word);
+	}
+	(trimmed, normalized)
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::TrimNormal;
+
+	proptest! {
+		#[test]
+		fn t_dirty_text(text in dirty_text(8, 50)) {
+			prop_assert_eq!(text.raw.trim(), text.trimmed.as_str());
+			prop_assert_eq!(text.raw.trim_and_normalize(), text.normalized.as_str());
+		}
+
+		#[test]
+		fn t_dirty_bytes(text in dirty_bytes(8, 50)) {
+			prop_assert_eq!(text.raw.trim_ascii(), text.trimmed.as_slice());
+			prop_assert_eq!(text.raw.trim_and_normalize(), text.normalized.as_slice());
+		}
+
+		#[test]
+		fn t_clean(text in dirty_text(8, 0)) {
+			prop_assert_eq!(&text.raw, &text.normalized);
+		}
+	}
+
+	#[test]
+	fn t_ws_tables() {
+		let all: Vec<char> = (0..=0x3000_u32).filter_map(char::from_u32)
+			.filter(|c| c.is_whitespace())
+			.collect();
+		assert_eq!(all, WS_CHARS);
+
+		let all: Vec<u8> = (0..=u8::MAX).filter(u8::is_ascii_whitespace).collect();
+		assert_eq!(all, WS_BYTES);
+	}
+}
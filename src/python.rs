@@ -0,0 +1,176 @@
+/*!
+# Trimothy: Python.
+
+This module holds the `pyo3` bindings: a handful of `#[pyfunction]`
+wrappers around the string/bytes helpers, registered by [`trimothy_py`].
+
+None of the code here is `unsafe` itself, but the `#[pymodule]` and
+`#[pyfunction]` macros generate FFI shims that are. The crate root lowers
+its `unsafe_code` lint from `forbid` to `deny` when the `pyo3` feature is
+enabled, and the `allow` below admits those shims for this file only.
+*/
+
+#![allow(unsafe_code, reason = "The pyo3 glue requires it.")]
+
+use alloc::{
+	borrow::Cow,
+	string::{
+		String,
+		ToString,
+	},
+};
+use crate::{
+	NormalizedCompare,
+	Sanitizer,
+	TrimNormal,
+};
+use pyo3::{
+	exceptions::{
+		PyTypeError,
+		PyValueError,
+	},
+	prelude::*,
+	types::{
+		PyBytes,
+		PyString,
+	},
+};
+
+
+
+#[pymodule]
+#[pyo3(name = "trimothy")]
+/// # Python Module.
+///
+/// This is the `pyo3` module initializer for the Python bindings, exposing
+/// the following functions to Python:
+///
+/// | Function | Description |
+/// | -------- | ----------- |
+/// | `trim_and_normalize(text)` | Trim and normalize a `str` or `bytes`, returning the same type. |
+/// | `normalized_eq(a, b)` | Compare two `str`s or two `bytes` after normalization. |
+/// | `sanitize(text, *, reject_control=True, reject_bidi=True, max_len=None)` | Sanitize a `str`, raising `ValueError` if it breaks the rules. |
+///
+/// Because Cargo crate types can't be feature-dependent, the extension
+/// itself should be built from a small `cdylib` wrapper crate — e.g. via
+/// `maturin` — that re-exports this function.
+///
+/// This function is only available when the `pyo3` crate feature is
+/// enabled.
+///
+/// ## Errors
+///
+/// This will return an error if the functions cannot be added to the module.
+pub fn trimothy_py(m: &Bound<'_, PyModule>) -> PyResult<()> {
+	m.add_function(wrap_pyfunction!(trim_and_normalize, m)?)?;
+	m.add_function(wrap_pyfunction!(normalized_eq, m)?)?;
+	m.add_function(wrap_pyfunction!(sanitize, m)?)?;
+	Ok(())
+}
+
+
+
+#[pyfunction]
+/// # Trim and Normalize.
+///
+/// Trim and normalize a `str` or `bytes` object, returning the same type.
+fn trim_and_normalize<'py>(text: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+	let py = text.py();
+	if let Ok(s) = text.cast::<PyString>() {
+		let src = s.to_cow()?;
+		Ok(PyString::new(py, &src.trim_and_normalize()).into_any())
+	}
+	else if let Ok(b) = text.cast::<PyBytes>() {
+		Ok(PyBytes::new(py, &b.as_bytes().trim_and_normalize()).into_any())
+	}
+	else { Err(type_error()) }
+}
+
+#[pyfunction]
+/// # Normalized Equality.
+///
+/// Compare two `str` or two `bytes` objects after normalization.
+fn normalized_eq(a: &Bound<'_, PyAny>, b: &Bound<'_, PyAny>) -> PyResult<bool> {
+	if let (Ok(a), Ok(b)) = (a.cast::<PyString>(), b.cast::<PyString>()) {
+		Ok(a.to_cow()?.normalized_eq(&b.to_cow()?))
+	}
+	else if let (Ok(a), Ok(b)) = (a.cast::<PyBytes>(), b.cast::<PyBytes>()) {
+		Ok(a.as_bytes().normalized_eq(b.as_bytes()))
+	}
+	else { Err(type_error()) }
+}
+
+#[pyfunction]
+#[pyo3(signature = (text, *, reject_control=true, reject_bidi=true, max_len=None))]
+/// # Sanitize.
+///
+/// Run a `str` through a [`Sanitizer`] configured per the keyword
+/// arguments, raising a `ValueError` describing the first rule broken, if
+/// any.
+fn sanitize(text: &str, reject_control: bool, reject_bidi: bool, max_len: Option<usize>)
+-> PyResult<String> {
+	Sanitizer::new()
+		.with_reject_control(reject_control)
+		.with_reject_bidi(reject_bidi)
+		.with_max_len(max_len)
+		.try_sanitize(text)
+		.map(Cow::into_owned)
+		.map_err(|e| PyValueError::new_err(e.to_string()))
+}
+
+/// # Type Error.
+fn type_error() -> PyErr { PyTypeError::new_err("expected str or bytes") }
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use pyo3::types::IntoPyDict;
+
+	#[test]
+	fn t_python() {
+		Python::initialize();
+		Python::attach(|py| {
+			let s = PyString::new(py, " Hello\r\n\tWorld! ");
+			let out = trim_and_normalize(&s).unwrap();
+			assert_eq!(out.cast::<PyString>().unwrap().to_cow().unwrap(), "Hello World!");
+
+			let b = PyBytes::new(py, b" Hello\r\n\tWorld! ");
+			let out = trim_and_normalize(&b).unwrap();
+			assert_eq!(out.cast::<PyBytes>().unwrap().as_bytes(), b"Hello World!");
+
+			assert!(normalized_eq(&s, &PyString::new(py, "Hello World!")).unwrap());
+			assert!(normalized_eq(&b, &PyBytes::new(py, b"Hello World!")).unwrap());
+			assert!(! normalized_eq(&s, &PyString::new(py, "HelloWorld!")).unwrap());
+
+			// Mixed types aren't allowed.
+			assert!(normalized_eq(&s, &b).is_err());
+			assert!(trim_and_normalize(&1_u8.into_pyobject(py).unwrap()).is_err());
+
+			// The module should build too.
+			let m = PyModule::new(py, "trimothy").unwrap();
+			trimothy_py(&m).unwrap();
+			assert!(m.getattr("trim_and_normalize").is_ok());
+			assert!(m.getattr("normalized_eq").is_ok());
+			assert!(m.getattr("sanitize").is_ok());
+
+			// Sanitization, via the module to exercise the keywords.
+			let f = m.getattr("sanitize").unwrap();
+			let out: String = f.call1((" Hello\r\n\tWorld! ",)).unwrap().extract().unwrap();
+			assert_eq!(out, "Hello World!");
+
+			let err = f.call1(("Hello\u{202E}",)).unwrap_err();
+			assert!(err.is_instance_of::<PyValueError>(py));
+			assert_eq!(err.value(py).to_string(), "bidi formatting character at byte 5");
+
+			let kwargs = [("reject_bidi", false)].into_py_dict(py).unwrap();
+			assert!(f.call(("Hello\u{202E}",), Some(&kwargs)).is_ok());
+
+			let kwargs = [("max_len", 5)].into_py_dict(py).unwrap();
+			assert!(f.call(("Hello",), Some(&kwargs)).is_ok());
+			let err = f.call(("Hello World",), Some(&kwargs)).unwrap_err();
+			assert!(err.is_instance_of::<PyValueError>(py));
+		});
+	}
+}
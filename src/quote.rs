@@ -0,0 +1,488 @@
+/*!
+# Trimothy: Quote-Aware Trimming
+*/
+
+use alloc::{
+	borrow::Cow,
+	string::String,
+};
+use core::{
+	iter::{
+		FusedIterator,
+		Peekable,
+	},
+	str::CharIndices,
+};
+use crate::pattern::MatchPattern;
+
+
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+/// # Quoting Rules.
+///
+/// This struct describes the quote characters — and optional escape
+/// character — recognized by the quote-aware methods of [`TrimQuoted`].
+///
+/// The [`Quotes::DEFAULT`] rules recognize `"` and `'` as quote characters,
+/// and `\` as an escape character.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::Quotes;
+///
+/// // Double quotes only, with the default (backslash) escape.
+/// let quotes = Quotes::new(&['"']);
+///
+/// // Backticks, but no escapes at all.
+/// let quotes = Quotes::new(&['`']).without_escape();
+/// ```
+pub struct Quotes<'a> {
+	/// # Quote Characters.
+	chars: &'a [char],
+
+	/// # Escape Character.
+	escape: Option<char>,
+}
+
+impl Default for Quotes<'static> {
+	#[inline]
+	fn default() -> Self { Self::DEFAULT }
+}
+
+impl Quotes<'static> {
+	/// # Default Rules.
+	///
+	/// Double and single quotes, with backslash escapes.
+	pub const DEFAULT: Self = Self {
+		chars: &['"', '\''],
+		escape: Some('\\'),
+	};
+}
+
+impl<'a> Quotes<'a> {
+	#[must_use]
+	/// # New.
+	///
+	/// Create a new rule set using the specified quote characters, and the
+	/// default (backslash) escape character.
+	pub const fn new(chars: &'a [char]) -> Self {
+		Self { chars, escape: Some('\\') }
+	}
+
+	#[must_use]
+	/// # With Escape Character.
+	///
+	/// Use a different escape character.
+	pub const fn with_escape(self, escape: char) -> Self {
+		Self { chars: self.chars, escape: Some(escape) }
+	}
+
+	#[must_use]
+	/// # Without Escape Character.
+	///
+	/// Disable escape handling altogether.
+	pub const fn without_escape(self) -> Self {
+		Self { chars: self.chars, escape: None }
+	}
+
+	#[must_use]
+	/// # Quote Characters.
+	pub const fn chars(&self) -> &'a [char] { self.chars }
+
+	#[must_use]
+	/// # Escape Character.
+	pub const fn escape(&self) -> Option<char> { self.escape }
+
+	#[must_use]
+	/// # Is Quote?
+	pub(crate) fn is_quote(&self, c: char) -> bool { self.chars.contains(&c) }
+
+	#[must_use]
+	/// # Is Escape?
+	pub(crate) fn is_escape(&self, c: char) -> bool { Some(c) == self.escape }
+
+	#[must_use]
+	/// # Protected End.
+	///
+	/// Return the byte index immediately following the last character of
+	/// `src` that is either quoted, part of an escape sequence, or a quote
+	/// delimiter itself. Unterminated quotes run to the end of the string.
+	fn protected_end(&self, src: &str) -> usize {
+		let mut end = 0;
+		let mut quote = None;
+		let mut iter = src.char_indices();
+		while let Some((idx, c)) = iter.next() {
+			// Escapes protect themselves and whatever comes next.
+			if self.is_escape(c) {
+				end = iter.next().map_or(src.len(), |(idx2, c2)| idx2 + c2.len_utf8());
+			}
+			// Closing quote.
+			else if let Some(q) = quote {
+				if c == q { quote = None; }
+				end = idx + c.len_utf8();
+			}
+			// Opening quote.
+			else if self.is_quote(c) {
+				quote.replace(c);
+				end = idx + c.len_utf8();
+			}
+		}
+
+		// An unterminated quote runs to the end.
+		if quote.is_some() { src.len() }
+		else { end }
+	}
+}
+
+
+
+/// # Quote-Aware Trimming.
+///
+/// The [`TrimQuoted`] trait brings quote-aware match-based trimming to
+/// string slices (and anything that dereferences to one).
+///
+/// The trait methods included are:
+///
+/// | Method | Description |
+/// | ------ | ----------- |
+/// | `trim_outside_quotes` | Trim arbitrary leading and trailing chars, except those that are quoted or escaped. |
+/// | `trim_outside_quotes_with` | Same as above, but with custom [`Quotes`] rules. |
+/// | `shell_words` | Split on unquoted whitespace, shell-style. |
+///
+/// Each of the match methods accept either:
+/// * A single `char`;
+/// * An array or slice of `char`;
+/// * A `&BTreeSet<char>`;
+/// * A callback with the signature `Fn(char) -> bool`;
+pub trait TrimQuoted {
+	/// # Trim Outside Quotes.
+	///
+	/// Trim arbitrary leading and trailing chars as determined by the
+	/// provided pattern, except those that are inside a quoted region, are
+	/// themselves quote delimiters, or are escaped.
+	///
+	/// This uses the [`Quotes::DEFAULT`] rules: double and single quotes,
+	/// with backslash escapes.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::TrimQuoted;
+	///
+	/// // Whitespace outside the quotes is trimmed as usual.
+	/// assert_eq!(
+	///     "  \"  Hello World  \"  ".trim_outside_quotes(char::is_whitespace),
+	///     "\"  Hello World  \"",
+	/// );
+	///
+	/// // But an unterminated quote protects everything after it.
+	/// assert_eq!(
+	///     "  name=\"Björk  ".trim_outside_quotes(char::is_whitespace),
+	///     "name=\"Björk  ",
+	/// );
+	///
+	/// // As do escapes.
+	/// assert_eq!(
+	///     "  trailing\\  ".trim_outside_quotes(char::is_whitespace),
+	///     "trailing\\ ",
+	/// );
+	/// ```
+	fn trim_outside_quotes<P: MatchPattern<char>>(&self, pat: P) -> &str {
+		self.trim_outside_quotes_with(pat, Quotes::DEFAULT)
+	}
+
+	/// # Trim Outside Quotes (Custom Rules).
+	///
+	/// Same as [`TrimQuoted::trim_outside_quotes`], but using custom
+	/// [`Quotes`] rules.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::{Quotes, TrimQuoted};
+	///
+	/// // Double quotes only, no escapes.
+	/// let quotes = Quotes::new(&['"']).without_escape();
+	/// assert_eq!(
+	///     ",,'a',,\"b,,\",,".trim_outside_quotes_with(',', quotes),
+	///     "'a',,\"b,,\"",
+	/// );
+	/// assert_eq!(
+	///     ",,'a,,".trim_outside_quotes_with(',', quotes),
+	///     "'a",
+	/// );
+	/// ```
+	fn trim_outside_quotes_with<P: MatchPattern<char>>(&self, pat: P, quotes: Quotes<'_>)
+	-> &str;
+
+	/// # Shell Words.
+	///
+	/// Return an iterator over the shell-style "words" of the string, split on
+	/// unquoted whitespace, with all quoting and escaping resolved along the
+	/// way, roughly the same way a POSIX shell would do it:
+	///
+	/// * Whitespace separates words, except when quoted or escaped;
+	/// * Single quotes preserve everything literally up to the closing `'`;
+	/// * Double quotes preserve everything except `\"`, `\\`, `\$`, and `` \` `` escapes;
+	/// * Outside quotes, a backslash escapes whatever follows it;
+	/// * A backslash-newline pair is a line continuation, and removed entirely;
+	///
+	/// Unterminated quotes run to the end of the string.
+	///
+	/// Words without any quotes or escapes are borrowed from the original.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::TrimQuoted;
+	///
+	/// let words: Vec<_> = r#"  cp -r "My Documents" 'Jim'\''s Stuff' \
+	///     /tmp/back\ up  "#.shell_words().collect();
+	///
+	/// assert_eq!(
+	///     words,
+	///     ["cp", "-r", "My Documents", "Jim's Stuff", "/tmp/back up"],
+	/// );
+	/// ```
+	fn shell_words(&self) -> ShellWords<'_>;
+}
+
+impl TrimQuoted for str {
+	fn trim_outside_quotes_with<P: MatchPattern<char>>(&self, pat: P, quotes: Quotes<'_>)
+	-> &str {
+		// Nothing before the first quote or escape is protected, so the start
+		// can be trimmed the usual way, sans those.
+		let src = self.trim_start_matches(|c: char|
+			pat.is_match(c) && ! quotes.is_quote(c) && ! quotes.is_escape(c)
+		);
+
+		// The end can only be trimmed after the protected bits.
+		let start = quotes.protected_end(src);
+		let end = start + src[start..].trim_end_matches(|c: char| pat.is_match(c)).len();
+		&src[..end]
+	}
+
+	#[inline]
+	fn shell_words(&self) -> ShellWords<'_> { ShellWords(self) }
+}
+
+
+
+#[derive(Debug, Clone)]
+/// # Shell Words.
+///
+/// This iterator is yielded by [`TrimQuoted::shell_words`]. Refer to its
+/// documentation for more details.
+pub struct ShellWords<'a>(&'a str);
+
+impl<'a> Iterator for ShellWords<'a> {
+	type Item = Cow<'a, str>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			let src = self.0.trim_start();
+			if src.is_empty() {
+				self.0 = src;
+				return None;
+			}
+
+			let (word, end) = Self::word(src);
+			self.0 = &src[end..];
+
+			// Line continuations can leave us with nothing, but that doesn't
+			// count as a word.
+			if let Some(word) = word { return Some(word); }
+		}
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(0, Some(self.0.len().div_ceil(2)))
+	}
+}
+
+impl FusedIterator for ShellWords<'_> {}
+
+impl<'a> ShellWords<'a> {
+	/// # Next Word.
+	///
+	/// Parse and return the word at the start of `src` — if any — along with
+	/// the index where it ended.
+	fn word(src: &'a str) -> (Option<Cow<'a, str>>, usize) {
+		let mut word = ShellWord { src, out: None };
+		let mut quoted = false;
+		let mut quote = None;
+		let mut end = src.len();
+		let mut iter = src.char_indices().peekable();
+		while let Some((idx, c)) = iter.next() {
+			match quote {
+				// Everything is literal inside single quotes.
+				Some('\'') =>
+					if c == '\'' { quote = None; }
+					else { word.push(c); },
+
+				// Double quotes are mostly literal.
+				Some(_) =>
+					if c == '"' { quote = None; }
+					else if c == '\\' {
+						match iter.peek().map(|&(_, c)| c) {
+							Some(c2 @ ('"' | '\\' | '$' | '`')) => {
+								iter.next();
+								word.push(c2);
+							},
+							Some('\n') => { iter.next(); },
+							_ => { word.push(c); },
+						}
+					}
+					else { word.push(c); },
+
+				// Unquoted.
+				None =>
+					if c.is_whitespace() {
+						end = idx;
+						break;
+					}
+					else if c == '\'' || c == '"' {
+						word.own(idx);
+						quote.replace(c);
+						quoted = true;
+					}
+					else if c == '\\' { word.escape(idx, &mut iter); }
+					else { word.push(c); },
+			}
+		}
+
+		let word = word.finish(end);
+		if quoted || ! word.is_empty() { (Some(word), end) }
+		else { (None, end) }
+	}
+}
+
+
+
+/// # Shell Word Builder.
+///
+/// This holds the state for a single [`ShellWords`] word, which remains
+/// borrowed until something is escaped or quoted.
+struct ShellWord<'a> {
+	/// # Source.
+	src: &'a str,
+
+	/// # Owned Output.
+	out: Option<String>,
+}
+
+impl<'a> ShellWord<'a> {
+	/// # Push.
+	///
+	/// Push a character to the owned buffer, if any; borrowed words just
+	/// keep on keeping on.
+	fn push(&mut self, c: char) {
+		if let Some(out) = &mut self.out { out.push(c); }
+	}
+
+	/// # Own.
+	///
+	/// Switch to an owned buffer, if not already, copying everything up to
+	/// `idx`.
+	fn own(&mut self, idx: usize) {
+		if self.out.is_none() { self.out = Some(String::from(&self.src[..idx])); }
+	}
+
+	/// # Unquoted Escape.
+	///
+	/// Handle the character following an unquoted backslash.
+	fn escape(&mut self, idx: usize, iter: &mut Peekable<CharIndices<'_>>) {
+		self.own(idx);
+		match iter.next() {
+			// Line continuation.
+			Some((_, '\n')) => {},
+			// Literal.
+			Some((_, c)) => { self.push(c); },
+			// A trailing backslash has nothing to escape.
+			None => { self.push('\\'); },
+		}
+	}
+
+	/// # Finish.
+	fn finish(self, end: usize) -> Cow<'a, str> {
+		match self.out {
+			Some(out) => Cow::Owned(out),
+			None => Cow::Borrowed(&self.src[..end]),
+		}
+	}
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use alloc::vec::Vec;
+
+	#[test]
+	fn t_trim_outside_quotes() {
+		for (raw, expected) in [
+			("", ""),
+			("   ", ""),
+			("  hello  ", "hello"),
+			("  'hello'  ", "'hello'"),
+			("  ' hello '  ", "' hello '"),
+			("  \" hello \"  ", "\" hello \""),
+			("  \" hello ' \"  ", "\" hello ' \""),
+			("  \" hello   ", "\" hello   "),
+			("  hello\\   ", "hello\\ "),
+			("  \\  hello  ", "\\  hello"),
+			("  hello\\", "hello\\"),
+			("  \"\\\"\"  ", "\"\\\"\""),
+			("  \" \\\"  ", "\" \\\"  "),
+		] {
+			assert_eq!(raw.trim_outside_quotes(' '), expected, "{raw:?}");
+		}
+
+		// Sans escapes.
+		let quotes = Quotes::DEFAULT.without_escape();
+		assert_eq!("  hello\\   ".trim_outside_quotes_with(' ', quotes), "hello\\");
+		assert_eq!("  \" \\\"  ".trim_outside_quotes_with(' ', quotes), "\" \\\"");
+
+		// Custom escapes.
+		let quotes = Quotes::new(&['|']).with_escape('^');
+		assert_eq!("  |a  b|  ".trim_outside_quotes_with(' ', quotes), "|a  b|");
+		assert_eq!("  a^   ".trim_outside_quotes_with(' ', quotes), "a^ ");
+		assert_eq!("  'a  ".trim_outside_quotes_with(' ', quotes), "'a");
+
+		// Patterns matching the quotes themselves can't eat them.
+		assert_eq!("\"\"hello\"\"".trim_outside_quotes('"'), "\"\"hello\"\"");
+	}
+
+	#[test]
+	fn t_shell_words() {
+		for (raw, expected) in [
+			("", &[][..]),
+			(" \t\n ", &[]),
+			("hello", &["hello"]),
+			("  hello   world  ", &["hello", "world"]),
+			("'' \"\"", &["", ""]),
+			("a'b c'd", &["ab cd"]),
+			("'a \\ b'", &["a \\ b"]),
+			("\"a \\\" \\n \\\\ b\"", &["a \" \\n \\ b"]),
+			("\"a\\\nb\"", &["ab"]),
+			("a\\ b c", &["a b", "c"]),
+			("a\\\nb", &["ab"]),
+			("a\\", &["a\\"]),
+			("a \\\n b", &["a", "b"]),
+			("'unterminated  ", &["unterminated  "]),
+			("\u{2003}a\u{3000}b\u{2003}", &["a", "b"]),
+		] {
+			let words: Vec<_> = raw.shell_words().collect();
+			assert_eq!(words, expected, "{raw:?}");
+		}
+
+		// Plain words should be borrowed.
+		let mut words = "hello 'world'".shell_words();
+		assert!(matches!(words.next(), Some(Cow::Borrowed("hello"))));
+		assert!(matches!(words.next(), Some(Cow::Owned(_))));
+		assert!(words.next().is_none());
+	}
+}
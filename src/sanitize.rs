@@ -0,0 +1,381 @@
+/*!
+# Trimothy: Strict Sanitization.
+*/
+
+use alloc::{
+	borrow::Cow,
+	string::String,
+};
+use core::{
+	error::Error,
+	fmt,
+};
+use crate::{
+	EmbeddedNul,
+	Normalizer,
+};
+
+
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+/// # Strict Sanitizer.
+///
+/// This struct pairs a [`Normalizer`] with a handful of _rejection_ rules,
+/// for contexts — usernames, filenames, and the like — where silently
+/// modifying suspicious input would itself be a security concern.
+///
+/// [`Sanitizer::try_sanitize`] normalizes the whitespace as usual, but
+/// returns a [`SanitizeError`] instead if the source contains:
+///
+/// * Control characters the normalizer wouldn't otherwise handle;
+/// * Bidirectional formatting characters (see [`contains_bidi_controls`]);
+/// * More characters — after normalization — than allowed;
+///
+/// By default, control and bidi characters are rejected, and there is no
+/// length limit.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::{SanitizeError, Sanitizer};
+///
+/// let sanitizer = Sanitizer::new().with_max_len(Some(16));
+///
+/// assert_eq!(sanitizer.try_sanitize("  Björk\tGuðmunds ").unwrap(), "Björk Guðmunds");
+///
+/// // Trojan Source, anyone?
+/// assert_eq!(
+///     sanitizer.try_sanitize("admin\u{202E}nimda"),
+///     Err(SanitizeError::Bidi(5)),
+/// );
+///
+/// // Terminal escapes.
+/// assert_eq!(
+///     sanitizer.try_sanitize("\x1B[31mred"),
+///     Err(SanitizeError::Control(0)),
+/// );
+///
+/// // Too long!
+/// assert_eq!(
+///     sanitizer.try_sanitize("Björk Guðmundsdóttir"),
+///     Err(SanitizeError::TooLong(20)),
+/// );
+/// ```
+pub struct Sanitizer {
+	/// # Normalizer.
+	normalizer: Normalizer,
+
+	/// # Reject Control Characters?
+	control: bool,
+
+	/// # Reject Bidi Characters?
+	bidi: bool,
+
+	/// # Max Length (Chars).
+	max_len: Option<usize>,
+}
+
+impl Default for Sanitizer {
+	#[inline]
+	fn default() -> Self { Self::new() }
+}
+
+/// ## Setup.
+impl Sanitizer {
+	#[must_use]
+	/// # New.
+	///
+	/// Return a new sanitizer using the default [`Normalizer`], rejecting
+	/// control and bidi characters, with no length limit.
+	pub const fn new() -> Self {
+		Self {
+			normalizer: Normalizer::new(),
+			control: true,
+			bidi: true,
+			max_len: None,
+		}
+	}
+
+	#[must_use]
+	/// # With Normalizer.
+	///
+	/// Set the normalizer used to clean up the whitespace.
+	pub const fn with_normalizer(mut self, normalizer: Normalizer) -> Self {
+		self.normalizer = normalizer;
+		self
+	}
+
+	#[must_use]
+	/// # With Reject Control.
+	///
+	/// Enable or disable the rejection of control characters. (Those the
+	/// normalizer treats as whitespace, like tabs and line breaks, are
+	/// never rejected.)
+	pub const fn with_reject_control(mut self, reject: bool) -> Self {
+		self.control = reject;
+		self
+	}
+
+	#[must_use]
+	/// # With Reject Bidi.
+	///
+	/// Enable or disable the rejection of bidirectional formatting
+	/// characters. (See [`contains_bidi_controls`] for the full list.)
+	pub const fn with_reject_bidi(mut self, reject: bool) -> Self {
+		self.bidi = reject;
+		self
+	}
+
+	#[must_use]
+	/// # With Max Length.
+	///
+	/// Set the maximum length, in `char`s, of the _normalized_ output, or
+	/// `None` for no limit.
+	pub const fn with_max_len(mut self, max_len: Option<usize>) -> Self {
+		self.max_len = max_len;
+		self
+	}
+}
+
+/// ## Getters.
+impl Sanitizer {
+	#[must_use]
+	/// # Normalizer.
+	pub const fn normalizer(&self) -> Normalizer { self.normalizer }
+
+	#[must_use]
+	/// # Reject Control Characters?
+	pub const fn reject_control(&self) -> bool { self.control }
+
+	#[must_use]
+	/// # Reject Bidi Characters?
+	pub const fn reject_bidi(&self) -> bool { self.bidi }
+
+	#[must_use]
+	/// # Max Length (Chars).
+	pub const fn max_len(&self) -> Option<usize> { self.max_len }
+}
+
+/// ## Sanitization.
+impl Sanitizer {
+	/// # Try Sanitize.
+	///
+	/// Normalize the source, returning it borrowed if nothing needed
+	/// changing, or owned if it did, unless it breaks one of the rules, in
+	/// which case an error is returned instead.
+	///
+	/// ## Errors
+	///
+	/// Returns a [`SanitizeError`] describing the first rule broken, if any.
+	/// Embedded NULs the normalizer is set to
+	/// [reject](crate::NulPolicy::Reject) are reported too.
+	pub fn try_sanitize<'a>(&self, src: &'a str) -> Result<Cow<'a, str>, SanitizeError> {
+		if self.control || self.bidi {
+			for (idx, c) in src.char_indices() {
+				if self.bidi && is_bidi(c) { return Err(SanitizeError::Bidi(idx)); }
+				if self.control && c.is_control() && ! self.normalizer.is_ws(c) {
+					return Err(SanitizeError::Control(idx));
+				}
+			}
+		}
+
+		let out = self.normalizer.try_normalize(src)?;
+		if let Some(max) = self.max_len {
+			let len = out.chars().count();
+			if max < len { return Err(SanitizeError::TooLong(len)); }
+		}
+
+		Ok(out)
+	}
+}
+
+
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+/// # Sanitize Error.
+///
+/// This is the error returned by [`Sanitizer::try_sanitize`]. Positions are
+/// byte indices into the original source.
+pub enum SanitizeError {
+	/// # Bidi Character (Position).
+	Bidi(usize),
+
+	/// # Control Character (Position).
+	Control(usize),
+
+	/// # Embedded NUL (Position).
+	Nul(usize),
+
+	/// # Too Long (Normalized Length, in Chars).
+	TooLong(usize),
+}
+
+impl fmt::Display for SanitizeError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Bidi(pos) => write!(f, "bidi formatting character at byte {pos}"),
+			Self::Control(pos) => write!(f, "control character at byte {pos}"),
+			Self::Nul(pos) => write!(f, "embedded NUL at byte {pos}"),
+			Self::TooLong(len) => write!(f, "too long ({len} chars)"),
+		}
+	}
+}
+
+impl Error for SanitizeError {}
+
+impl From<EmbeddedNul> for SanitizeError {
+	#[inline]
+	fn from(err: EmbeddedNul) -> Self { Self::Nul(err.position()) }
+}
+
+
+
+#[must_use]
+/// # Contains Bidi Controls?
+///
+/// Returns `true` if the string contains any bidirectional formatting
+/// characters, the raw material of "Trojan Source"-style spoofing, where
+/// text is made to _display_ differently than it reads:
+///
+/// | Char | Name |
+/// | ---- | ---- |
+/// | `U+061C` | Arabic Letter Mark (ALM) |
+/// | `U+200E` | Left-to-Right Mark (LRM) |
+/// | `U+200F` | Right-to-Left Mark (RLM) |
+/// | `U+202A` | Left-to-Right Embedding (LRE) |
+/// | `U+202B` | Right-to-Left Embedding (RLE) |
+/// | `U+202C` | Pop Directional Formatting (PDF) |
+/// | `U+202D` | Left-to-Right Override (LRO) |
+/// | `U+202E` | Right-to-Left Override (RLO) |
+/// | `U+2066` | Left-to-Right Isolate (LRI) |
+/// | `U+2067` | Right-to-Left Isolate (RLI) |
+/// | `U+2068` | First Strong Isolate (FSI) |
+/// | `U+2069` | Pop Directional Isolate (PDI) |
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::contains_bidi_controls;
+///
+/// assert!(contains_bidi_controls("access_level != \"user\u{202E} \u{2066}// Check if admin\u{2069} \u{2066}\""));
+/// assert!(! contains_bidi_controls("שלום, world!"));
+/// ```
+pub fn contains_bidi_controls(src: &str) -> bool { src.contains(is_bidi) }
+
+#[must_use]
+/// # Strip Bidi Controls.
+///
+/// Remove all bidirectional formatting characters — see
+/// [`contains_bidi_controls`] for the list — from the string, borrowing if
+/// there were none.
+///
+/// Right-to-left _text_ is left alone; only the invisible controls that
+/// reorder it are affected. To refuse such input outright instead, see
+/// [`Sanitizer`].
+///
+/// ## Examples
+///
+/// ```
+/// use std::borrow::Cow;
+/// use trimothy::strip_bidi_controls;
+///
+/// assert_eq!(strip_bidi_controls("admin\u{202E}nimda\u{202C}"), "adminnimda");
+/// assert!(matches!(strip_bidi_controls("שלום, world!"), Cow::Borrowed(_)));
+/// ```
+pub fn strip_bidi_controls(src: &str) -> Cow<'_, str> {
+	if contains_bidi_controls(src) {
+		Cow::Owned(src.chars().filter(|&c| ! is_bidi(c)).collect::<String>())
+	}
+	else { Cow::Borrowed(src) }
+}
+
+
+
+/// # Is Bidi Formatting Character?
+const fn is_bidi(c: char) -> bool {
+	matches!(c, '\u{061C}' | '\u{200E}' | '\u{200F}' | '\u{202A}'..='\u{202E}' | '\u{2066}'..='\u{2069}')
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::{
+		NulPolicy,
+		WsKind,
+		WsPolicy,
+	};
+
+	#[test]
+	fn t_try_sanitize() {
+		let sanitizer = Sanitizer::new();
+		assert!(sanitizer.reject_control());
+		assert!(sanitizer.reject_bidi());
+		assert_eq!(sanitizer.max_len(), None);
+
+		for (raw, expected) in [
+			("", Ok("")),
+			("Hello", Ok("Hello")),
+			(" Hello\r\n\tWorld\x0B", Ok("Hello World")),
+			("Björk", Ok("Björk")),
+			("Hello\x07", Err(SanitizeError::Control(5))),
+			("\u{9F}", Err(SanitizeError::Control(0))),
+			("a\0b", Err(SanitizeError::Control(1))),
+			("ab\u{200F}", Err(SanitizeError::Bidi(2))),
+			("é\u{2066}x\u{2069}", Err(SanitizeError::Bidi(2))),
+			("\u{061C}", Err(SanitizeError::Bidi(0))),
+		] {
+			let out = sanitizer.try_sanitize(raw);
+			assert_eq!(out.as_deref(), expected.as_deref(), "{raw:?}");
+		}
+
+		// Rejection is configurable.
+		let sanitizer = sanitizer.with_reject_control(false).with_reject_bidi(false);
+		assert_eq!(sanitizer.try_sanitize("a\x07\u{202E}b").unwrap(), "a\x07\u{202E}b");
+
+		// Whitespace the normalizer keeps is still a control.
+		let sanitizer = Sanitizer::new()
+			.with_normalizer(Normalizer::new().with_policy(WsKind::Newline, WsPolicy::Keep));
+		assert_eq!(sanitizer.try_sanitize("Hello\n"), Err(SanitizeError::Control(5)));
+
+		// NULs can be removed or rejected by the normalizer instead.
+		let normalizer = Normalizer::new().with_nul_policy(NulPolicy::Remove);
+		let sanitizer = Sanitizer::new().with_normalizer(normalizer);
+		assert_eq!(sanitizer.try_sanitize("a\0b").unwrap(), "ab");
+		let sanitizer = sanitizer
+			.with_normalizer(normalizer.with_nul_policy(NulPolicy::Reject))
+			.with_reject_control(false);
+		assert_eq!(sanitizer.try_sanitize("a\0b"), Err(SanitizeError::Nul(1)));
+
+		// Length is measured post-normalization.
+		let sanitizer = Sanitizer::new().with_max_len(Some(5));
+		assert_eq!(sanitizer.try_sanitize("  Björk\n\n").unwrap(), "Björk");
+		assert_eq!(sanitizer.try_sanitize("Björks"), Err(SanitizeError::TooLong(6)));
+		assert_eq!(Sanitizer::new().with_max_len(Some(0)).try_sanitize(" ").unwrap(), "");
+
+		// Errors should be readable.
+		assert_eq!(
+			alloc::format!("{}", SanitizeError::Bidi(3)),
+			"bidi formatting character at byte 3",
+		);
+		assert_eq!(alloc::format!("{}", SanitizeError::TooLong(9)), "too long (9 chars)");
+	}
+
+	#[test]
+	fn t_bidi_controls() {
+		let all = "\u{061C}\u{200E}\u{200F}\u{202A}\u{202B}\u{202C}\u{202D}\u{202E}\u{2066}\u{2067}\u{2068}\u{2069}";
+		assert!(all.chars().all(is_bidi));
+		assert!(contains_bidi_controls(all));
+		assert_eq!(strip_bidi_controls(all), "");
+
+		// Neighbors and RTL text are fine.
+		for raw in ["", "Hello", "\u{200D}\u{2029}\u{202F}\u{2065}\u{206A}", "שלום", "مرحبا"] {
+			assert!(! contains_bidi_controls(raw), "{raw:?}");
+			assert!(matches!(strip_bidi_controls(raw), Cow::Borrowed(_)), "{raw:?}");
+		}
+
+		assert_eq!(strip_bidi_controls("\u{2067}שלום\u{2069} World"), "שלום World");
+	}
+}
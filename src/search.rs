@@ -0,0 +1,277 @@
+/*!
+# Trimothy: Whitespace-Insensitive Search.
+*/
+
+use core::{
+	iter::FusedIterator,
+	ops::Range,
+};
+
+
+
+/// # Whitespace-Insensitive Search.
+///
+/// This trait adds whitespace-insensitive searching to string slices, for
+/// situations where the haystack — PDF extractions, OCR output, etc. — has
+/// its whitespace all over the place.
+///
+/// Both the needle and haystack are compared as if they had been trimmed
+/// and normalized, the same as [`TrimNormal`](crate::TrimNormal), but
+/// without allocating any normalized copies; the results, meanwhile, refer
+/// to the _original_ haystack.
+///
+/// | Method | Description |
+/// | ------ | ----------- |
+/// | `contains_normalized` | Test for a whitespace-insensitive match. |
+/// | `find_normalized` | Find the first whitespace-insensitive match. |
+/// | `match_indices_normalized` | Iterate over all whitespace-insensitive matches. |
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::NormalizedSearch;
+///
+/// let haystack = "The quick\n  brown\tfox jumps over the lazy dog.";
+/// let range = haystack.find_normalized("quick brown fox").unwrap();
+/// assert_eq!(range, 4..21);
+/// assert_eq!(&haystack[range], "quick\n  brown\tfox");
+/// ```
+pub trait NormalizedSearch {
+	/// # Contains Normalized.
+	///
+	/// Returns `true` if any portion of the haystack's normalized form
+	/// matches the normalized needle.
+	///
+	/// As with [`str::contains`], an empty (or all-whitespace) needle always
+	/// matches.
+	fn contains_normalized(&self, needle: &str) -> bool {
+		self.find_normalized(needle).is_some()
+	}
+
+	/// # Find Normalized.
+	///
+	/// Return the byte range of the first portion of the haystack whose
+	/// normalized form matches the normalized needle, if any.
+	///
+	/// The range never begins or ends with whitespace. If the needle is empty
+	/// or all whitespace, `Some(0..0)` is returned.
+	fn find_normalized(&self, needle: &str) -> Option<Range<usize>>;
+
+	/// # Match Indices Normalized.
+	///
+	/// Return an iterator over the non-overlapping, whitespace-insensitive
+	/// matches of the needle within the haystack, yielding the starting byte
+	/// index and original slice of each.
+	///
+	/// Unlike [`str::match_indices`], empty (or all-whitespace) needles
+	/// yield nothing.
+	fn match_indices_normalized<'a, 'b>(&'a self, needle: &'b str)
+	-> MatchIndicesNormalized<'a, 'b>;
+}
+
+impl NormalizedSearch for str {
+	/// # Find Normalized.
+	///
+	/// Return the byte range of the first portion of the haystack whose
+	/// normalized form matches the normalized needle, if any.
+	///
+	/// The range never begins or ends with whitespace. If the needle is empty
+	/// or all whitespace, `Some(0..0)` is returned.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::NormalizedSearch;
+	///
+	/// let haystack = "Lorem ipsum\r\n\tdolor sit amet.";
+	/// assert_eq!(haystack.find_normalized("  ipsum dolor "), Some(6..19));
+	/// assert_eq!(haystack.find_normalized("sum\tdo"), Some(8..16));
+	///
+	/// // Whitespace is collapsed, not ignored.
+	/// assert_eq!(haystack.find_normalized("ipsumdolor"), None);
+	/// assert_eq!(haystack.find_normalized("lo rem"), None);
+	/// ```
+	fn find_normalized(&self, needle: &str) -> Option<Range<usize>> {
+		find_normalized(self, needle, 0)
+	}
+
+	/// # Match Indices Normalized.
+	///
+	/// Return an iterator over the non-overlapping, whitespace-insensitive
+	/// matches of the needle within the haystack, yielding the starting byte
+	/// index and original slice of each.
+	///
+	/// Unlike [`str::match_indices`], empty (or all-whitespace) needles
+	/// yield nothing.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::NormalizedSearch;
+	///
+	/// let haystack = "one two\nthree one  two three one\ttwo";
+	/// assert_eq!(
+	///     haystack.match_indices_normalized("one two").collect::<Vec<_>>(),
+	///     [(0, "one two"), (14, "one  two"), (29, "one\ttwo")],
+	/// );
+	///
+	/// // Counting is easy too.
+	/// assert_eq!(haystack.match_indices_normalized("two three").count(), 2);
+	/// ```
+	fn match_indices_normalized<'a, 'b>(&'a self, needle: &'b str)
+	-> MatchIndicesNormalized<'a, 'b> {
+		MatchIndicesNormalized {
+			src: self,
+			needle,
+			pos: if needle.trim_start().is_empty() { None } else { Some(0) },
+		}
+	}
+}
+
+
+
+#[derive(Debug, Clone)]
+/// # Whitespace-Insensitive Match Indices.
+///
+/// This iterator is returned by
+/// [`NormalizedSearch::match_indices_normalized`], yielding the starting
+/// byte index and original slice of each non-overlapping match.
+pub struct MatchIndicesNormalized<'a, 'b> {
+	/// # Haystack.
+	src: &'a str,
+
+	/// # Needle.
+	needle: &'b str,
+
+	/// # Search Position.
+	///
+	/// This is `None` once the search has been exhausted.
+	pos: Option<usize>,
+}
+
+impl<'a> Iterator for MatchIndicesNormalized<'a, '_> {
+	type Item = (usize, &'a str);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let range = self.pos.and_then(|pos| find_normalized(self.src, self.needle, pos));
+		if let Some(range) = range {
+			self.pos = Some(range.end);
+			Some((range.start, &self.src[range]))
+		}
+		else {
+			self.pos = None;
+			None
+		}
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.pos.map_or(0, |pos| self.src.len() - pos);
+		(0, Some(len))
+	}
+}
+
+impl FusedIterator for MatchIndicesNormalized<'_, '_> {}
+
+
+
+/// # Find Normalized (From).
+///
+/// Search for the needle within `src`, starting from byte index `from`.
+fn find_normalized(src: &str, needle: &str, from: usize) -> Option<Range<usize>> {
+	// The first needle character is the only one that can start a match.
+	let Some(first) = needle.trim_start().chars().next() else {
+		return Some(from..from);
+	};
+
+	src[from..].match_indices(first).find_map(|(idx, _)| {
+		let start = from + idx;
+		match_at(src, start, needle).map(|end| start..end)
+	})
+}
+
+/// # Match At.
+///
+/// Check whether the normalized needle matches the (normalized) source
+/// starting at byte index `start`, returning the (exclusive) end index of
+/// the match if so.
+///
+/// Each whitespace gap between needle words must correspond to at least one
+/// whitespace character in the source; everything else must be identical.
+fn match_at(src: &str, start: usize, needle: &str) -> Option<usize> {
+	let mut hay = src[start..].char_indices().peekable();
+	let mut end = start;
+	for (idx, word) in needle.split_whitespace().enumerate() {
+		// Words after the first must be preceded by whitespace.
+		if idx != 0 {
+			hay.next_if(|(_, c)| c.is_whitespace())?;
+			while hay.next_if(|(_, c)| c.is_whitespace()).is_some() {}
+		}
+
+		for nc in word.chars() {
+			let (pos, _) = hay.next_if(|(_, c)| *c == nc)?;
+			end = start + pos + nc.len_utf8();
+		}
+	}
+
+	Some(end)
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn t_find_normalized() {
+		for (hay, needle, expected) in [
+			("", "", Some(0..0)),
+			("", " \t", Some(0..0)),
+			("Hello", "", Some(0..0)),
+			("", "Hello", None),
+			("Hello World", "Hello World", Some(0..11)),
+			("Hello World", "World", Some(6..11)),
+			("Hello World", "world", None),
+			("Hello \t\n World", " Hello World ", Some(0..14)),
+			("Hello\u{3000}World", "o W", Some(4..9)),
+			("Hello World", "HelloWorld", None),
+			("Hello World", "Hello  World!", None),
+			// False starts.
+			("He Hel Hello World", "Hello World", Some(7..18)),
+			("aab", "ab", Some(1..3)),
+			// Multi-byte.
+			("Björk  Guðmundsdóttir", "björk", None),
+			("Björk  Guðmundsdóttir", "Björk Guð", Some(0..12)),
+			("Björk  Guðmundsdóttir", "ö", Some(2..4)),
+		] {
+			let res = hay.find_normalized(needle);
+			assert_eq!(res.is_some(), hay.contains_normalized(needle));
+			assert_eq!(res, expected, "Find {needle:?} in {hay:?}.");
+
+			// The first match should be the same.
+			let next = hay.match_indices_normalized(needle).next();
+			if needle.trim().is_empty() { assert!(next.is_none()); }
+			else {
+				assert_eq!(next.map(|(idx, m)| idx..idx + m.len()), expected);
+			}
+		}
+	}
+
+	#[test]
+	fn t_match_indices_normalized() {
+		let hay = "aaa a a\ta  a";
+		assert!(hay.match_indices_normalized("a a").eq([(2, "a a"), (6, "a\ta")]));
+		assert!(hay.match_indices_normalized("a").eq([
+			(0, "a"), (1, "a"), (2, "a"), (4, "a"), (6, "a"), (8, "a"), (11, "a"),
+		]));
+		assert_eq!(hay.match_indices_normalized("b").next(), None);
+		assert_eq!(hay.match_indices_normalized("  ").next(), None);
+
+		// Fused.
+		let mut iter = hay.match_indices_normalized(" a\ta ");
+		assert_eq!(iter.next(), Some((2, "a a")));
+		assert_eq!(iter.next(), Some((6, "a\ta")));
+		assert_eq!(iter.next(), None);
+		assert_eq!(iter.next(), None);
+	}
+}
@@ -0,0 +1,319 @@
+/*!
+# Trimothy: Strip and Normalize.
+*/
+
+use alloc::{
+	borrow::Cow,
+	string::String,
+	vec::Vec,
+};
+use crate::{
+	MatchPattern,
+	TrimMatchesMut,
+	TrimSliceMatches,
+};
+
+
+
+/// # Strip and Normalize.
+///
+/// This trait fuses pattern-based removal with whitespace normalization,
+/// stripping _every_ unit matching a pattern — not just the leading and
+/// trailing ones — while trimming the whitespace and compacting the inner
+/// spans to a single horizontal space, all in a single pass.
+///
+/// Stripped units are treated as if they never existed, so any whitespace
+/// surrounding them is collapsed as a single run.
+///
+/// As with [`TrimNormal`](crate::TrimNormal), "whitespace" here means
+/// [`char::is_whitespace`] for string sources, and [`u8::is_ascii_whitespace`]
+/// for byte sources.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::StripNormal;
+///
+/// assert_eq!(
+///     " (555) 867-5309 ".strip_and_normalize(['(', ')', '-']),
+///     "555 8675309",
+/// );
+///
+/// // Include the space in the pattern to remove it entirely.
+/// assert_eq!(
+///     " (555) 867-5309 ".strip_and_normalize(['(', ')', '-', ' ']),
+///     "5558675309",
+/// );
+/// ```
+pub trait StripNormal<T: Copy + Eq + Ord + Sized> {
+	/// # Output Type.
+	type Normalized;
+
+	/// # Strip and Normalize.
+	///
+	/// Remove all units matching the pattern, trim the leading/trailing
+	/// whitespace, and compact/normalize spans of _inner_ whitespace to a
+	/// single horizontal space.
+	fn strip_and_normalize<P: MatchPattern<T>>(self, pat: P) -> Self::Normalized;
+}
+
+
+
+impl<'a> StripNormal<char> for &'a str {
+	/// # Output Type.
+	type Normalized = Cow<'a, str>;
+
+	/// # Strip and Normalize.
+	///
+	/// Remove all chars matching the pattern, trim the leading/trailing
+	/// whitespace, and compact/normalize spans of _inner_ whitespace to a
+	/// single horizontal space.
+	///
+	/// If the (edge-trimmed) source is already clean, it is returned
+	/// borrowed; otherwise a new string is allocated.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// # extern crate alloc;
+	/// # use alloc::borrow::Cow;
+	/// use trimothy::StripNormal;
+	///
+	/// let id = "  AB-12 \t-34-  ".strip_and_normalize('-');
+	/// assert_eq!(id, "AB12 34");
+	/// assert!(matches!(id, Cow::Owned(_)));
+	///
+	/// // Edges are free.
+	/// let id = "--AB12 34--".strip_and_normalize('-');
+	/// assert_eq!(id, "AB12 34");
+	/// assert!(matches!(id, Cow::Borrowed(_)));
+	/// ```
+	fn strip_and_normalize<P: MatchPattern<char>>(self, pat: P) -> Self::Normalized {
+		// Leading/trailing junk can simply be sliced off.
+		let src = self.trim_matches(|c: char| c.is_whitespace() || pat.is_match(c));
+
+		// Look for the first thing that needs fixing, if any.
+		let mut ws = false;
+		let Some(idx) = src.char_indices().find_map(|(idx, c)|
+			if pat.is_match(c) { Some(idx) }
+			else if c.is_whitespace() {
+				if ws || c != ' ' { Some(idx) }
+				else {
+					ws = true;
+					None
+				}
+			}
+			else {
+				ws = false;
+				None
+			}
+		) else { return Cow::Borrowed(src); };
+
+		// Copy over the good part, then build out the rest char-by-char.
+		let mut out = String::with_capacity(src.len());
+		out.push_str(&src[..idx]);
+		for c in src[idx..].chars() {
+			if pat.is_match(c) {}
+			else if c.is_whitespace() {
+				if ! ws {
+					ws = true;
+					out.push(' ');
+				}
+			}
+			else {
+				ws = false;
+				out.push(c);
+			}
+		}
+
+		Cow::Owned(out)
+	}
+}
+
+impl StripNormal<char> for String {
+	/// # Output Type.
+	type Normalized = Self;
+
+	/// # Strip and Normalize.
+	///
+	/// Remove all chars matching the pattern, trim the leading/trailing
+	/// whitespace, and compact/normalize spans of _inner_ whitespace to a
+	/// single horizontal space.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::StripNormal;
+	///
+	/// let phone = String::from(" +1 (555)  867-5309\n");
+	/// assert_eq!(
+	///     phone.strip_and_normalize(['(', ')', '-']),
+	///     "+1 555 8675309",
+	/// );
+	/// ```
+	fn strip_and_normalize<P: MatchPattern<char>>(mut self, pat: P) -> Self::Normalized {
+		self.trim_matches_mut(|c: char| c.is_whitespace() || pat.is_match(c));
+		match self.as_str().strip_and_normalize(pat) {
+			Cow::Owned(s) => s,
+			Cow::Borrowed(_) => self,
+		}
+	}
+}
+
+impl<'a> StripNormal<u8> for &'a [u8] {
+	/// # Output Type.
+	type Normalized = Cow<'a, [u8]>;
+
+	/// # Strip and Normalize.
+	///
+	/// Remove all bytes matching the pattern, trim the leading/trailing
+	/// (ASCII) whitespace, and compact/normalize spans of _inner_ whitespace
+	/// to a single horizontal space.
+	///
+	/// If the (edge-trimmed) source is already clean, it is returned
+	/// borrowed; otherwise a new vector is allocated.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// # extern crate alloc;
+	/// # use alloc::borrow::Cow;
+	/// use trimothy::StripNormal;
+	///
+	/// let id = b"  AB-12 \t-34-  ".strip_and_normalize(b'-');
+	/// assert_eq!(id.as_ref(), b"AB12 34");
+	/// assert!(matches!(id, Cow::Owned(_)));
+	///
+	/// // Edges are free.
+	/// let id = b"--AB12 34--".strip_and_normalize(b'-');
+	/// assert_eq!(id.as_ref(), b"AB12 34");
+	/// assert!(matches!(id, Cow::Borrowed(_)));
+	/// ```
+	fn strip_and_normalize<P: MatchPattern<u8>>(self, pat: P) -> Self::Normalized {
+		// Leading/trailing junk can simply be sliced off.
+		let src = self.trim_matches(|b: u8| b.is_ascii_whitespace() || pat.is_match(b));
+
+		// Look for the first thing that needs fixing, if any.
+		let mut ws = false;
+		let Some(idx) = src.iter().position(|&b|
+			if pat.is_match(b) { true }
+			else if b.is_ascii_whitespace() {
+				if ws || b != b' ' { true }
+				else {
+					ws = true;
+					false
+				}
+			}
+			else {
+				ws = false;
+				false
+			}
+		) else { return Cow::Borrowed(src); };
+
+		// Copy over the good part, then build out the rest byte-by-byte.
+		let mut out = Vec::with_capacity(src.len());
+		out.extend_from_slice(&src[..idx]);
+		for &b in &src[idx..] {
+			if pat.is_match(b) {}
+			else if b.is_ascii_whitespace() {
+				if ! ws {
+					ws = true;
+					out.push(b' ');
+				}
+			}
+			else {
+				ws = false;
+				out.push(b);
+			}
+		}
+
+		Cow::Owned(out)
+	}
+}
+
+impl StripNormal<u8> for Vec<u8> {
+	/// # Output Type.
+	type Normalized = Self;
+
+	/// # Strip and Normalize.
+	///
+	/// Remove all bytes matching the pattern, trim the leading/trailing
+	/// (ASCII) whitespace, and compact/normalize spans of _inner_ whitespace
+	/// to a single horizontal space.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::StripNormal;
+	///
+	/// let phone = b" +1 (555)  867-5309\n".to_vec();
+	/// assert_eq!(
+	///     phone.strip_and_normalize(b"()-".as_slice()),
+	///     b"+1 555 8675309",
+	/// );
+	/// ```
+	fn strip_and_normalize<P: MatchPattern<u8>>(mut self, pat: P) -> Self::Normalized {
+		// Bytes are simple enough to handle in place.
+		let mut ws = true;
+		self.retain_mut(|b|
+			if pat.is_match(*b) { false }
+			else if b.is_ascii_whitespace() {
+				if ws { false }
+				else {
+					ws = true;
+					*b = b' ';
+					true
+				}
+			}
+			else {
+				ws = false;
+				true
+			}
+		);
+
+		// There may be one trailing space left over.
+		if ws { self.pop(); }
+		self
+	}
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn t_strip_and_normalize() {
+		for (raw, pat, expected) in [
+			("", &['-'][..], ""),
+			("  ", &['-'], ""),
+			("--", &['-'], ""),
+			(" - - ", &['-'], ""),
+			("Hello World", &['-'], "Hello World"),
+			("Hello World", &[], "Hello World"),
+			(" Hello\t\nWorld ", &[], "Hello World"),
+			("Hello - World", &['-'], "Hello World"),
+			("Hello-World", &['-'], "HelloWorld"),
+			("Hello -\t- World", &['-'], "Hello World"),
+			("(555) 867-5309", &['(', ')', '-'], "555 8675309"),
+			("(555) 867-5309", &['(', ')', '-', ' '], "5558675309"),
+			("a b", &[' '], "ab"),
+			("a\u{3000}-b", &['-'], "a b"),
+		] {
+			let res = raw.strip_and_normalize(pat);
+			assert_eq!(res, expected, "{raw:?} {pat:?}");
+			assert_eq!(String::from(raw).strip_and_normalize(pat), expected);
+
+			if raw.is_ascii() {
+				let pat: Vec<u8> = pat.iter().map(|&c| u8::try_from(c).unwrap()).collect();
+				let res = raw.as_bytes().strip_and_normalize(pat.as_slice());
+				assert_eq!(res.as_ref(), expected.as_bytes(), "{raw:?} {pat:?}");
+				assert_eq!(
+					raw.as_bytes().to_vec().strip_and_normalize(pat.as_slice()),
+					expected.as_bytes(),
+				);
+			}
+		}
+	}
+}
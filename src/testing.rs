@@ -0,0 +1,193 @@
+/*!
+# Trimothy: Testing Helpers.
+*/
+
+use core::fmt;
+use crate::{
+	show_whitespace,
+	TrimNormal,
+};
+
+
+
+#[macro_export]
+/// # Assert Trimmed Equal.
+///
+/// This works just like [`assert_eq!`], except the (string-like) values are
+/// compared with their leading/trailing whitespace trimmed.
+///
+/// On failure, the trimmed values are printed with their (remaining)
+/// whitespace made visible via [`show_whitespace`](crate::show_whitespace),
+/// along with the position of the first difference.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::assert_trimmed_eq;
+///
+/// assert_trimmed_eq!("  Hello World\n", "Hello World");
+/// assert_trimmed_eq!(String::from("\tHello World"), "Hello World ", "Oh no!");
+/// ```
+///
+/// ```should_panic
+/// use trimothy::assert_trimmed_eq;
+///
+/// // Inner whitespace still matters!
+/// assert_trimmed_eq!("Hello  World", "Hello World");
+/// ```
+macro_rules! assert_trimmed_eq {
+	($left:expr, $right:expr $(,)?) => (
+		$crate::__assert_trimmed_eq(&$left, &$right, ::core::option::Option::None)
+	);
+	($left:expr, $right:expr, $($arg:tt)+) => (
+		$crate::__assert_trimmed_eq(
+			&$left,
+			&$right,
+			::core::option::Option::Some(::core::format_args!($($arg)+)),
+		)
+	);
+}
+
+#[macro_export]
+/// # Assert Normalized Equal.
+///
+/// This works just like [`assert_eq!`], except the (string-like) values are
+/// compared with their leading/trailing whitespace trimmed and spans of
+/// _inner_ whitespace normalized to a single horizontal space, the same as
+/// [`TrimNormal`](crate::TrimNormal).
+///
+/// On failure, the normalized values are printed with their whitespace made
+/// visible via [`show_whitespace`](crate::show_whitespace), along with the
+/// position of the first difference.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::assert_normalized_eq;
+///
+/// assert_normalized_eq!("  Hello\n\tWorld\n", "Hello World");
+/// assert_normalized_eq!(
+///     String::from("<p>\n    Hello World\n</p>"),
+///     "<p> Hello World </p>",
+///     "Golden file mismatch!",
+/// );
+/// ```
+///
+/// ```should_panic
+/// use trimothy::assert_normalized_eq;
+///
+/// assert_normalized_eq!("Hello World", "Hello  World!");
+/// ```
+macro_rules! assert_normalized_eq {
+	($left:expr, $right:expr $(,)?) => (
+		$crate::__assert_normalized_eq(&$left, &$right, ::core::option::Option::None)
+	);
+	($left:expr, $right:expr, $($arg:tt)+) => (
+		$crate::__assert_normalized_eq(
+			&$left,
+			&$right,
+			::core::option::Option::Some(::core::format_args!($($arg)+)),
+		)
+	);
+}
+
+
+
+#[doc(hidden)]
+#[track_caller]
+/// # Assert Trimmed Equal (Implementation).
+///
+/// This is the implementation behind [`assert_trimmed_eq!`]; it is not
+/// intended for direct use.
+pub fn __assert_trimmed_eq<A, B>(left: &A, right: &B, msg: Option<fmt::Arguments<'_>>)
+where A: AsRef<str> + ?Sized, B: AsRef<str> + ?Sized {
+	let left = left.as_ref().trim();
+	let right = right.as_ref().trim();
+	if left != right { assert_failed("trimmed", left, right, msg); }
+}
+
+#[doc(hidden)]
+#[track_caller]
+/// # Assert Normalized Equal (Implementation).
+///
+/// This is the implementation behind [`assert_normalized_eq!`]; it is not
+/// intended for direct use.
+pub fn __assert_normalized_eq<A, B>(left: &A, right: &B, msg: Option<fmt::Arguments<'_>>)
+where A: AsRef<str> + ?Sized, B: AsRef<str> + ?Sized {
+	let left = left.as_ref();
+	let right = right.as_ref();
+	if ! left.split_whitespace().eq(right.split_whitespace()) {
+		assert_failed(
+			"normalized",
+			&left.trim_and_normalize(),
+			&right.trim_and_normalize(),
+			msg,
+		);
+	}
+}
+
+#[cold]
+#[track_caller]
+/// # Assertion Failed.
+///
+/// Panic with a message describing the (whitespace-visualized) mismatch.
+fn assert_failed(kind: &str, left: &str, right: &str, msg: Option<fmt::Arguments<'_>>) -> ! {
+	let pos = left.chars()
+		.zip(right.chars())
+		.position(|(a, b)| a != b)
+		.unwrap_or_else(|| left.chars().count().min(right.chars().count()));
+
+	panic!(
+		"assertion `left == right` failed ({kind}){}\n  left: {}\n right: {}\n  diff: first mismatch at char {pos}",
+		Message(msg),
+		show_whitespace(left),
+		show_whitespace(right),
+	);
+}
+
+
+
+/// # Custom Message.
+///
+/// This writes the optional custom assertion message, if any, prefixed with
+/// a colon.
+struct Message<'a>(Option<fmt::Arguments<'a>>);
+
+impl fmt::Display for Message<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.0.map_or(Ok(()), |msg| write!(f, ": {msg}"))
+	}
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use alloc::string::String;
+
+	#[test]
+	fn t_assert_trimmed_eq() {
+		assert_trimmed_eq!("", " \t\n");
+		assert_trimmed_eq!(" Hello World\n", "Hello World");
+		assert_trimmed_eq!(String::from("\u{3000}Hello World"), "Hello World", "Unicode!");
+	}
+
+	#[test]
+	#[should_panic(expected = "left: Hello·World\n right: Hello→World\n  diff: first mismatch at char 5")]
+	fn t_assert_trimmed_eq_fail() {
+		assert_trimmed_eq!(" Hello World ", "Hello\tWorld");
+	}
+
+	#[test]
+	fn t_assert_normalized_eq() {
+		assert_normalized_eq!("", " \t\n");
+		assert_normalized_eq!(" Hello\n\t World\n", "Hello World");
+		assert_normalized_eq!(String::from("Hello\u{3000}World"), "Hello World", "Unicode!");
+	}
+
+	#[test]
+	#[should_panic(expected = "failed (normalized): Custom!\n  left: Hello·World\n right: Hello·World!\n  diff: first mismatch at char 11")]
+	fn t_assert_normalized_eq_fail() {
+		assert_normalized_eq!("Hello \n World", "Hello World!", "Custom!");
+	}
+}
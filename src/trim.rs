@@ -0,0 +1,250 @@
+/*!
+# Trimothy: Umbrella Trait.
+*/
+
+use alloc::{
+	borrow::{
+		Cow,
+		ToOwned,
+	},
+	boxed::Box,
+	string::String,
+	vec::Vec,
+};
+use crate::{
+	MatchPattern,
+	TrimMatchesMut,
+	TrimMut,
+	TrimNormal,
+	TrimSliceMatches,
+};
+
+
+
+/// # Trim (Umbrella).
+///
+/// The [`Trim`] trait rolls the borrowed, mutable, match-based, and
+/// normalizing operations for owned text containers — `String`, `Vec<u8>`,
+/// `Box<[u8]>`, and their `Cow` counterparts — into a single bound, so
+/// generic code can accept "anything trimmable" with `T: Trim` rather than
+/// juggling several traits.
+///
+/// [`TrimMut`] and [`TrimMatchesMut`] are supertraits, so all of their
+/// methods come along for the ride; the match unit — `char` or `u8` — is
+/// [`TrimMatchesMut::MatchUnit`], and the borrowed form — `str` or `[u8]` —
+/// is [`Trim::Output`].
+///
+/// The additional trait methods included are:
+///
+/// | Method | Description |
+/// | ------ | ----------- |
+/// | `trimmed` | Borrow the value with leading and trailing whitespace trimmed. |
+/// | `trimmed_start` | Borrow the value with leading whitespace trimmed. |
+/// | `trimmed_end` | Borrow the value with trailing whitespace trimmed. |
+/// | `trimmed_matches` | Borrow the value with arbitrary leading and trailing units trimmed. |
+/// | `normalized` | Trim and normalize, borrowing if possible. |
+/// | `normalize_mut` | Trim and normalize (mutably). |
+///
+/// In keeping with the rest of the library, "whitespace" here means
+/// [`char::is_whitespace`] for string sources, and [`u8::is_ascii_whitespace`]
+/// for byte sources.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::Trim;
+///
+/// /// # Clean Up.
+/// fn clean<T: Trim>(mut src: T) -> T {
+///     src.normalize_mut();
+///     src
+/// }
+///
+/// /// # Blank?
+/// fn is_blank<T: Trim>(src: &T) -> bool
+/// where T::Output: AsRef<[u8]> {
+///     src.trimmed().as_ref().is_empty()
+/// }
+///
+/// assert_eq!(clean(String::from(" Hello\t\tWorld ")), "Hello World");
+/// assert_eq!(clean(b" Hello\t\tWorld ".to_vec()), b"Hello World");
+/// assert!(is_blank(&String::from(" \n ")));
+/// assert!(! is_blank(&b" Hi ".to_vec()));
+/// ```
+pub trait Trim: TrimMut + TrimMatchesMut {
+	/// # Output Type.
+	///
+	/// The borrowed form of the container, i.e. `str` or `[u8]`.
+	type Output: ?Sized + ToOwned;
+
+	/// # Trimmed.
+	///
+	/// Return a reference to the value with leading and trailing whitespace
+	/// trimmed.
+	fn trimmed(&self) -> &Self::Output;
+
+	/// # Trimmed Start.
+	///
+	/// Return a reference to the value with leading whitespace trimmed.
+	fn trimmed_start(&self) -> &Self::Output;
+
+	/// # Trimmed End.
+	///
+	/// Return a reference to the value with trailing whitespace trimmed.
+	fn trimmed_end(&self) -> &Self::Output;
+
+	/// # Trimmed Matches.
+	///
+	/// Return a reference to the value with arbitrary leading and trailing
+	/// units trimmed as determined by the provided pattern.
+	fn trimmed_matches<P: MatchPattern<Self::MatchUnit>>(&self, pat: P) -> &Self::Output;
+
+	/// # Normalized.
+	///
+	/// Trim the leading/trailing whitespace, and compact/normalize spans of
+	/// _inner_ whitespace to a single horizontal space, borrowing if the
+	/// value is already clean.
+	fn normalized(&self) -> Cow<'_, Self::Output>;
+
+	/// # Normalize Mut.
+	///
+	/// Same as [`Trim::normalized`], but in place.
+	fn normalize_mut(&mut self);
+}
+
+/// # Helper: String Implementations.
+macro_rules! trim_str {
+	($($ty:ty: $normalize:expr),+ $(,)?) => ($(
+		impl Trim for $ty {
+			/// # Output Type.
+			type Output = str;
+
+			#[inline]
+			/// # Trimmed.
+			fn trimmed(&self) -> &str { str::trim(self) }
+
+			#[inline]
+			/// # Trimmed Start.
+			fn trimmed_start(&self) -> &str { str::trim_start(self) }
+
+			#[inline]
+			/// # Trimmed End.
+			fn trimmed_end(&self) -> &str { str::trim_end(self) }
+
+			#[inline]
+			/// # Trimmed Matches.
+			fn trimmed_matches<P: MatchPattern<char>>(&self, pat: P) -> &str {
+				str::trim_matches(self, |c| pat.is_match(c))
+			}
+
+			#[inline]
+			/// # Normalized.
+			fn normalized(&self) -> Cow<'_, str> {
+				<&str as TrimNormal>::trim_and_normalize(self)
+			}
+
+			#[inline]
+			/// # Normalize Mut.
+			fn normalize_mut(&mut self) { ($normalize)(self) }
+		}
+	)+);
+}
+
+trim_str!(
+	String: |s: &mut String| { <&mut String as TrimNormal>::trim_and_normalize(s); },
+	Cow<'_, str>: |s: &mut Cow<'_, str>| { *s = core::mem::take(s).trim_and_normalize(); },
+);
+
+/// # Helper: Byte Implementations.
+macro_rules! trim_bytes {
+	($($ty:ty: $normalize:expr),+ $(,)?) => ($(
+		impl Trim for $ty {
+			/// # Output Type.
+			type Output = [u8];
+
+			#[inline]
+			/// # Trimmed.
+			fn trimmed(&self) -> &[u8] { self.trim_ascii() }
+
+			#[inline]
+			/// # Trimmed Start.
+			fn trimmed_start(&self) -> &[u8] { self.trim_ascii_start() }
+
+			#[inline]
+			/// # Trimmed End.
+			fn trimmed_end(&self) -> &[u8] { self.trim_ascii_end() }
+
+			#[inline]
+			/// # Trimmed Matches.
+			fn trimmed_matches<P: MatchPattern<u8>>(&self, pat: P) -> &[u8] {
+				<[u8] as TrimSliceMatches>::trim_matches(self, pat)
+			}
+
+			#[inline]
+			/// # Normalized.
+			fn normalized(&self) -> Cow<'_, [u8]> {
+				<&[u8] as TrimNormal>::trim_and_normalize(self)
+			}
+
+			#[inline]
+			/// # Normalize Mut.
+			fn normalize_mut(&mut self) { ($normalize)(self) }
+		}
+	)+);
+}
+
+trim_bytes!(
+	Vec<u8>: |s: &mut Vec<u8>| { <&mut Vec<u8> as TrimNormal>::trim_and_normalize(s); },
+	Box<[u8]>: |s: &mut Box<[u8]>| { <&mut Box<[u8]> as TrimNormal>::trim_and_normalize(s); },
+	Cow<'_, [u8]>: |s: &mut Cow<'_, [u8]>| { *s = core::mem::take(s).trim_and_normalize(); },
+);
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	/// # Exercise Everything.
+	///
+	/// Run a value through each of the trait methods, comparing against the
+	/// expected (string) results.
+	fn check<T>(mut src: T, raw: &str)
+	where T: Trim + core::fmt::Debug, T::Output: AsRef<[u8]> {
+		assert_eq!(src.trimmed().as_ref(), raw.trim().as_bytes(), "{raw:?}");
+		assert_eq!(src.trimmed_start().as_ref(), raw.trim_start().as_bytes(), "{raw:?}");
+		assert_eq!(src.trimmed_end().as_ref(), raw.trim_end().as_bytes(), "{raw:?}");
+
+		let normal = raw.trim_and_normalize();
+		assert_eq!((*src.normalized()).as_ref(), normal.as_bytes(), "{raw:?}");
+		src.normalize_mut();
+		assert_eq!(src.trimmed().as_ref(), normal.as_bytes(), "{raw:?}");
+
+		// The supertraits should be there too.
+		src.trim_mut();
+		assert_eq!(src.trimmed().as_ref(), normal.as_bytes(), "{raw:?}");
+	}
+
+	#[test]
+	fn t_trim() {
+		for raw in ["", " ", "Hello", " Hello\t\tWorld\n", "\r\nHello  World"] {
+			check(String::from(raw), raw);
+			check(Cow::Borrowed(raw), raw);
+			check(raw.as_bytes().to_vec(), raw);
+			check(Box::<[u8]>::from(raw.as_bytes()), raw);
+			check(Cow::Borrowed(raw.as_bytes()), raw);
+		}
+
+		// Matches.
+		assert_eq!(String::from("--Hello--").trimmed_matches('-'), "Hello");
+		assert_eq!(b"--Hello--".to_vec().trimmed_matches(b'-'), b"Hello");
+
+		// Clean Cows shouldn't become owned.
+		let mut cow = Cow::Borrowed("Hello World");
+		cow.normalize_mut();
+		assert!(matches!(cow, Cow::Borrowed(_)));
+		let mut cow = Cow::Borrowed(&b"Hello World"[..]);
+		cow.normalize_mut();
+		assert!(matches!(cow, Cow::Borrowed(_)));
+	}
+}
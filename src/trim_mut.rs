@@ -8,7 +8,16 @@ use alloc::{
 	string::String,
 	vec::Vec,
 };
+use core::{
+	cell::{
+		Cell,
+		RefMut,
+	},
+	error::Error,
+	fmt,
+};
 use crate::{
+	DynPattern,
 	pattern::MatchPattern,
 	TrimSliceMatches,
 };
@@ -32,7 +41,24 @@ use crate::{
 /// [`char::is_whitespace`] for string sources, and [`u8::is_ascii_whitespace`]
 /// for byte sources.
 ///
-/// Refer to the individual implementations for examples.
+/// Both [`TrimMut`] and [`TrimMatchesMut`] are also implemented for `&mut T`,
+/// `Box<T>`, and [`RefMut<T>`](core::cell::RefMut) — plus `MutexGuard<T>`
+/// and `RwLockWriteGuard<T>` when the `std` crate feature is enabled — so
+/// long as `T` implements them, allowing generic code to trim through
+/// pointers and lock guards without any manual reborrowing.
+///
+/// ```
+/// use std::cell::RefCell;
+/// use trimothy::TrimMut;
+///
+/// fn clean<T: TrimMut>(mut value: T) { value.trim_mut(); }
+///
+/// let cell = RefCell::new(String::from("  Hello World  "));
+/// clean(cell.borrow_mut());
+/// assert_eq!(*cell.borrow(), "Hello World");
+/// ```
+///
+/// Refer to the individual implementations for more examples.
 pub trait TrimMut {
 	/// # Trim Mut.
 	///
@@ -55,10 +81,36 @@ pub trait TrimMut {
 
 
 
+/// # Mutable Trim (Keep Newline).
+///
+/// The [`TrimKeepNewlineMut`] trait complements [`TrimMut`] with a
+/// line-ending-aware variant of `trim_end_mut` for `String`, `Vec<u8>`, and
+/// `Box<[u8]>`.
+///
+/// | Method | Description |
+/// | ------ | ----------- |
+/// | `trim_end_keep_newline_mut` | Trim trailing whitespace, except the final line ending (mutably). |
+///
+/// Like [`TrimMut`], it is also implemented for `&mut T`, `Box<T>`,
+/// `RefMut<T>`, and (with `std`) the lock guards.
+pub trait TrimKeepNewlineMut {
+	/// # Trim End Mut (Keep Newline).
+	///
+	/// Remove trailing whitespace, mutably, _except_ line breaks, preserving
+	/// the final line ending (`\n` or `\r\n`), if any. Refer to the
+	/// individual implementations for examples.
+	///
+	/// Because no line breaks are ever removed, this will not change the
+	/// number of lines in the source.
+	fn trim_end_keep_newline_mut(&mut self);
+}
+
+
+
 /// # Mutable Trim (Matches).
 ///
 /// The [`TrimMatchesMut`] trait exposes mutable match-based trimming methods for
-/// `String`, `Vec<u8>`, and `Box<[u8]>`.
+/// `String`, `Vec<T>`, and `Box<[T]>`.
 ///
 /// The trait methods included are:
 ///
@@ -66,7 +118,10 @@ pub trait TrimMut {
 /// | ------ | ----------- |
 /// | `trim_matches_mut` | Trim arbitrary leading and trailing bytes (mutably). |
 /// | `trim_start_matches_mut` | Trim arbitrary leading bytes (mutably). |
+/// | `trim_start_split_mut` | Measure arbitrary leading bytes, deferring their removal. |
 /// | `trim_end_matches_mut` | Trim arbitrary trailing bytes (mutably). |
+/// | `trim_layers_mut` | Trim alternating layers of patterns until nothing changes (mutably). |
+/// | `trim_repeat_mut` | Same as above, but with a cap on the number of passes. |
 ///
 /// Each of these match methods accept either:
 /// * A single T;
@@ -74,7 +129,9 @@ pub trait TrimMut {
 /// * A `&BtreeSet<T>`
 /// * A custom callback with signature `Fn(T) -> bool`
 ///
-/// Where T is `char` for string sources, and `u8` for byte sources.
+/// Where T is `char` for string sources, and the element type — usually
+/// `u8` — for vectors and boxed slices. (Callbacks for element types other
+/// than `char` and `u8` need to be wrapped in a [`Predicate`](crate::Predicate).)
 ///
 /// Refer to the individual implementations for examples.
 pub trait TrimMatchesMut {
@@ -96,11 +153,799 @@ pub trait TrimMatchesMut {
 	/// pattern. Refer to the individual implementations for examples.
 	fn trim_start_matches_mut<P: MatchPattern<Self::MatchUnit>>(&mut self, pat: P);
 
+	/// # Trim Start Split Mut.
+	///
+	/// Return the length `n` — in bytes for strings, elements otherwise —
+	/// of the leading portion matching the provided pattern that is still
+	/// present in `self`, such that `&self[n..]` holds the start-trimmed
+	/// value.
+	///
+	/// All of the crate's own implementations defer the removal, leaving
+	/// `self` untouched and returning the full prefix length, so the memmove
+	/// can be skipped when the value is about to be sliced, boxed, or shared
+	/// anyway. Refer to the individual implementations for examples.
+	///
+	/// The default implementation can't defer anything, so trims eagerly
+	/// via [`TrimMatchesMut::trim_start_matches_mut`] and returns `0`, which
+	/// still satisfies the `&self[n..]` contract.
+	fn trim_start_split_mut<P: MatchPattern<Self::MatchUnit>>(&mut self, pat: P) -> usize {
+		self.trim_start_matches_mut(pat);
+		0
+	}
+
 	/// # Trim End Matches Mut.
 	///
 	/// Trim arbitrary trailing bytes as determined by the provided
 	/// pattern. Refer to the individual implementations for examples.
 	fn trim_end_matches_mut<P: MatchPattern<Self::MatchUnit>>(&mut self, pat: P);
+
+	/// # Trim Layers Mut.
+	///
+	/// Trim each pattern in turn — leading and trailing — repeating the
+	/// whole sequence until a full pass leaves the value unchanged.
+	///
+	/// This makes it easy to peel apart nested wrappers, like whitespace
+	/// inside quotes inside whitespace, in a single call.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::{DynPattern, TrimMatchesMut};
+	///
+	/// let ws = |c: char| c.is_whitespace();
+	/// let layers: [&dyn DynPattern<char>; 2] = [&ws, &'"'];
+	///
+	/// let mut s = String::from("  \"  value \" ");
+	/// s.trim_layers_mut(&layers);
+	/// assert_eq!(s, "value");
+	///
+	/// let mut v = b"\t'[ value ]'\n".to_vec();
+	/// v.trim_layers_mut(&[&b"\t\n ".as_slice(), &b'\'', &[b'[', b']']]);
+	/// assert_eq!(v, b"value");
+	/// ```
+	fn trim_layers_mut(&mut self, layers: &[&dyn DynPattern<Self::MatchUnit>]) {
+		self.trim_repeat_mut(layers, usize::MAX);
+	}
+
+	/// # Trim Repeat Mut.
+	///
+	/// Same as [`TrimMatchesMut::trim_layers_mut`], but giving up after at
+	/// most `max` passes.
+	///
+	/// Returns `true` if a fixed point was reached — i.e. the final pass
+	/// left the value unchanged, so trimming again would be pointless — or
+	/// `false` if the pass limit was hit first.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::{DynPattern, TrimMatchesMut};
+	///
+	/// let layers: [&dyn DynPattern<char>; 2] = [&' ', &'"'];
+	///
+	/// // The first pass strips the outer spaces and quotes, the second the
+	/// // inner ones, and the third confirms there's nothing left to do.
+	/// let mut s = String::from(" \" value \" ");
+	/// assert!(s.trim_repeat_mut(&layers, 3));
+	/// assert_eq!(s, "value");
+	///
+	/// // But we can stop short.
+	/// let mut s = String::from(" \" value \" ");
+	/// assert!(! s.trim_repeat_mut(&layers, 1));
+	/// assert_eq!(s, " value ");
+	/// ```
+	fn trim_repeat_mut(&mut self, layers: &[&dyn DynPattern<Self::MatchUnit>], max: usize)
+	-> bool {
+		for _ in 0..max {
+			let changed = Cell::new(false);
+			for layer in layers {
+				self.trim_matches_mut(Layer { pat: *layer, changed: &changed });
+			}
+			if ! changed.get() { return true; }
+		}
+
+		false
+	}
+}
+
+
+
+/// # Mutable Trim (Substrings).
+///
+/// The [`TrimStrMut`] trait is the owned, mutable counterpart of passing a
+/// `&str` needle to [`str::trim_start_matches`] or [`str::trim_end_matches`],
+/// repeatedly stripping an exact substring from an edge of a `String`
+/// without reallocating.
+///
+/// | Method | Description |
+/// | ------ | ----------- |
+/// | `trim_start_str_mut` | Trim leading repetitions of a substring (mutably). |
+/// | `trim_end_str_mut` | Trim trailing repetitions of a substring (mutably). |
+/// | `trim_trailing_separator_mut` | Trim a single dangling list separator, and any whitespace around it (mutably). |
+///
+/// Empty needles are ignored.
+pub trait TrimStrMut {
+	/// # Trim Start Str Mut.
+	///
+	/// Remove every leading repetition of `needle`, mutably.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::TrimStrMut;
+	///
+	/// let mut s = String::from("foofoofoobar");
+	/// s.trim_start_str_mut("foo");
+	/// assert_eq!(s, "bar");
+	/// ```
+	fn trim_start_str_mut(&mut self, needle: &str);
+
+	/// # Trim End Str Mut.
+	///
+	/// Remove every trailing repetition of `needle`, mutably.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::TrimStrMut;
+	///
+	/// let mut s = String::from("Hello World!\r\n\r\n");
+	/// s.trim_end_str_mut("\r\n");
+	/// assert_eq!(s, "Hello World!");
+	/// ```
+	fn trim_end_str_mut(&mut self, needle: &str);
+
+	/// # Trim Trailing Separator Mut.
+	///
+	/// Remove a single dangling separator from the end of the string, along
+	/// with any whitespace on either side of it, mutably, e.g. turning
+	/// `"a, b, c, "` into `"a, b, c"`.
+	///
+	/// Whitespace around `sep` itself is ignored for matching purposes, so
+	/// `","` and `", "` work equally well. (Whitespace-only separators are
+	/// ignored entirely.)
+	///
+	/// If the (trimmed) string doesn't end with the separator, it is left
+	/// completely unchanged, trailing whitespace and all.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::TrimStrMut;
+	///
+	/// let mut s = String::from("a, b, c, ");
+	/// s.trim_trailing_separator_mut(", ");
+	/// assert_eq!(s, "a, b, c");
+	///
+	/// // Only one separator is removed.
+	/// let mut s = String::from("a | b |  | \n");
+	/// s.trim_trailing_separator_mut("|");
+	/// assert_eq!(s, "a | b |");
+	///
+	/// // Strings without one are left alone.
+	/// let mut s = String::from("a, b, c ");
+	/// s.trim_trailing_separator_mut(",");
+	/// assert_eq!(s, "a, b, c ");
+	/// ```
+	fn trim_trailing_separator_mut(&mut self, sep: &str);
+}
+
+impl TrimStrMut for String {
+	/// # Trim Start Str Mut.
+	///
+	/// Remove every leading repetition of `needle`, mutably.
+	fn trim_start_str_mut(&mut self, needle: &str) {
+		if needle.is_empty() { return; }
+		let mut start = 0;
+		while self[start..].starts_with(needle) { start += needle.len(); }
+		if start == self.len() { self.truncate(0); }
+		else if start != 0 { self.replace_range(..start, ""); }
+	}
+
+	/// # Trim End Str Mut.
+	///
+	/// Remove every trailing repetition of `needle`, mutably.
+	fn trim_end_str_mut(&mut self, needle: &str) {
+		if needle.is_empty() { return; }
+		let mut end = self.len();
+		while self[..end].ends_with(needle) { end -= needle.len(); }
+		self.truncate(end);
+	}
+
+	/// # Trim Trailing Separator Mut.
+	///
+	/// Remove a single dangling separator from the end of the string, along
+	/// with any whitespace on either side of it, mutably.
+	fn trim_trailing_separator_mut(&mut self, sep: &str) {
+		let sep = sep.trim();
+		if sep.is_empty() { return; }
+		if let Some(rest) = self.trim_end().strip_suffix(sep) {
+			let end = rest.trim_end().len();
+			self.truncate(end);
+		}
+	}
+}
+
+
+
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, PartialEq)]
+/// # Shrink Policy.
+///
+/// This determines whether or not [`TrimShrinkMut::trim_mut_and_shrink`]
+/// releases the excess capacity left behind by trimming.
+///
+/// Shrinking means reallocating and copying the trimmed value, so it is
+/// only worth doing when a lot of capacity would otherwise be stranded,
+/// e.g. after trimming megabytes down to a few bytes.
+pub enum ShrinkPolicy {
+	#[default]
+	/// # Never Shrink.
+	///
+	/// Keep the capacity as-is, same as [`TrimMut::trim_mut`].
+	Never,
+
+	/// # Always Shrink.
+	///
+	/// Shrink the capacity to fit the trimmed length.
+	Always,
+
+	/// # Shrink If Wasteful.
+	///
+	/// Shrink the capacity to fit only if the unused portion would exceed
+	/// this many bytes.
+	Waste(usize),
+}
+
+impl ShrinkPolicy {
+	#[must_use]
+	/// # Should Shrink?
+	///
+	/// Returns `true` if a buffer of length `len` and capacity `capacity`
+	/// should be shrunk according to this policy.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::ShrinkPolicy;
+	///
+	/// assert!(! ShrinkPolicy::Never.should_shrink(0, 1000));
+	/// assert!(ShrinkPolicy::Always.should_shrink(999, 1000));
+	/// assert!(! ShrinkPolicy::Waste(100).should_shrink(900, 1000));
+	/// assert!(ShrinkPolicy::Waste(100).should_shrink(899, 1000));
+	/// ```
+	pub const fn should_shrink(self, len: usize, capacity: usize) -> bool {
+		match self {
+			Self::Never => false,
+			Self::Always => len < capacity,
+			Self::Waste(max) => max < capacity.saturating_sub(len),
+		}
+	}
+}
+
+/// # Mutable Trim (and Shrink).
+///
+/// The [`TrimShrinkMut`] trait adds a capacity-aware variant of
+/// [`TrimMut::trim_mut`] to `String` and `Vec<u8>`, optionally releasing
+/// excess capacity afterward according to an explicit [`ShrinkPolicy`].
+///
+/// | Method | Description |
+/// | ------ | ----------- |
+/// | `trim_mut_and_shrink` | Trim leading and trailing whitespace, then maybe shrink (mutably). |
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::{ShrinkPolicy, TrimShrinkMut};
+///
+/// let mut s = String::with_capacity(64);
+/// s.push_str("  Hello World  ");
+///
+/// // Not enough waste to bother.
+/// s.trim_mut_and_shrink(ShrinkPolicy::Waste(128));
+/// assert_eq!(s, "Hello World");
+/// assert_eq!(s.capacity(), 64);
+///
+/// // Enough!
+/// s.trim_mut_and_shrink(ShrinkPolicy::Waste(32));
+/// assert_eq!(s.capacity(), s.len());
+/// ```
+pub trait TrimShrinkMut {
+	/// # Trim Mut and Shrink.
+	///
+	/// Remove leading and trailing whitespace, mutably, then shrink the
+	/// capacity if the policy calls for it.
+	fn trim_mut_and_shrink(&mut self, policy: ShrinkPolicy);
+}
+
+impl TrimShrinkMut for String {
+	#[inline]
+	/// # Trim Mut and Shrink.
+	///
+	/// Remove leading and trailing whitespace, mutably, then shrink the
+	/// capacity if the policy calls for it.
+	fn trim_mut_and_shrink(&mut self, policy: ShrinkPolicy) {
+		self.trim_mut();
+		if policy.should_shrink(self.len(), self.capacity()) { self.shrink_to_fit(); }
+	}
+}
+
+impl TrimShrinkMut for Vec<u8> {
+	#[inline]
+	/// # Trim Mut and Shrink.
+	///
+	/// Remove leading and trailing ASCII whitespace, mutably, then shrink the
+	/// capacity if the policy calls for it.
+	fn trim_mut_and_shrink(&mut self, policy: ShrinkPolicy) {
+		self.trim_mut();
+		if policy.should_shrink(self.len(), self.capacity()) { self.shrink_to_fit(); }
+	}
+}
+
+
+
+/// # UTF-8-Safe Truncation.
+///
+/// The [`TruncateUtf8Mut`] trait brings message-size capping — SMS
+/// segments, database columns, etc. — to `String` and `Vec<u8>`, truncating
+/// to a maximum _byte_ length without ever splitting a UTF-8 sequence.
+///
+/// | Method | Description |
+/// | ------ | ----------- |
+/// | `truncate_utf8_mut` | Truncate to at most `n` bytes on a char boundary. |
+/// | `truncate_utf8_trim_mut` | Same as above, then trim trailing whitespace. |
+///
+/// For `Vec<u8>`, the content is assumed to be UTF-8; only continuation
+/// bytes are backed over, and only ASCII whitespace is trimmed.
+pub trait TruncateUtf8Mut {
+	/// # Truncate UTF-8 Mut.
+	///
+	/// Truncate to at most `n` bytes, backing up to the previous char
+	/// boundary if `n` would otherwise split a UTF-8 sequence.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::TruncateUtf8Mut;
+	///
+	/// let mut s = String::from("Björk");
+	/// s.truncate_utf8_mut(3); // Splits the ö.
+	/// assert_eq!(s, "Bj");
+	///
+	/// let mut v = "Björk".as_bytes().to_vec();
+	/// v.truncate_utf8_mut(4);
+	/// assert_eq!(v, "Bjö".as_bytes());
+	/// ```
+	fn truncate_utf8_mut(&mut self, n: usize);
+
+	/// # Truncate UTF-8 Mut (and Trim).
+	///
+	/// Same as [`TruncateUtf8Mut::truncate_utf8_mut`], but also trims any
+	/// trailing whitespace left behind.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::TruncateUtf8Mut;
+	///
+	/// let mut s = String::from("Hello World!");
+	/// s.truncate_utf8_trim_mut(6);
+	/// assert_eq!(s, "Hello");
+	/// ```
+	fn truncate_utf8_trim_mut(&mut self, n: usize);
+}
+
+impl TruncateUtf8Mut for String {
+	/// # Truncate UTF-8 Mut.
+	///
+	/// Truncate to at most `n` bytes, backing up to the previous char
+	/// boundary if needed.
+	fn truncate_utf8_mut(&mut self, mut n: usize) {
+		if n < self.len() {
+			while ! self.is_char_boundary(n) { n -= 1; }
+			self.truncate(n);
+		}
+	}
+
+	/// # Truncate UTF-8 Mut (and Trim).
+	///
+	/// Same as `truncate_utf8_mut`, but also trims any trailing whitespace.
+	fn truncate_utf8_trim_mut(&mut self, n: usize) {
+		self.truncate_utf8_mut(n);
+		self.trim_end_mut();
+	}
+}
+
+impl TruncateUtf8Mut for Vec<u8> {
+	/// # Truncate UTF-8 Mut.
+	///
+	/// Truncate to at most `n` bytes, backing up over any UTF-8
+	/// continuation bytes (up to three) to avoid splitting a sequence.
+	fn truncate_utf8_mut(&mut self, mut n: usize) {
+		if n < self.len() {
+			let min = n.saturating_sub(3);
+			while min < n && self[n] & 0b1100_0000 == 0b1000_0000 { n -= 1; }
+			self.truncate(n);
+		}
+	}
+
+	/// # Truncate UTF-8 Mut (and Trim).
+	///
+	/// Same as `truncate_utf8_mut`, but also trims any trailing ASCII
+	/// whitespace.
+	fn truncate_utf8_trim_mut(&mut self, n: usize) {
+		self.truncate_utf8_mut(n);
+		self.trim_end_mut();
+	}
+}
+
+
+
+/// # Minimum-Length Trim.
+///
+/// The [`TrimMinLenMut`] trait adds length-preserving trimming to `String`
+/// and `Vec<u8>`, stopping once the content would drop below `n` units
+/// (`char`s for `String`, bytes for `Vec<u8>`). Display names, labels, and
+/// the like can thus be sanitized without ever being emptied.
+///
+/// | Method | Description |
+/// | ------ | ----------- |
+/// | `trim_mut_min_len` | Trim leading and trailing whitespace, keeping at least `n` units (mutably). |
+/// | `trim_matches_mut_min_len` | Trim arbitrary leading and trailing units, keeping at least `n` units (mutably). |
+///
+/// Leading units are trimmed first, then trailing ones. Values already
+/// shorter than `n` are left as-is.
+pub trait TrimMinLenMut {
+	/// # Matches Type.
+	///
+	/// This is the "unit" type of the collection, e.g. `char` for `String`,
+	/// `u8` for `Vec<u8>`.
+	type MatchUnit: Copy + Eq + Ord + Sized;
+
+	/// # Trim Mut (Minimum Length).
+	///
+	/// Remove leading and trailing whitespace, mutably, stopping before the
+	/// content drops below `n` units.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::TrimMinLenMut;
+	///
+	/// let mut s = String::from("  Björk  ");
+	/// s.trim_mut_min_len(1);
+	/// assert_eq!(s, "Björk");
+	///
+	/// // All whitespace, but something has to stay.
+	/// let mut s = String::from(" \t\n ");
+	/// s.trim_mut_min_len(1);
+	/// assert_eq!(s, " ");
+	///
+	/// let mut v = b"  Hi  ".to_vec();
+	/// v.trim_mut_min_len(4);
+	/// assert_eq!(v, b"Hi  ");
+	/// ```
+	fn trim_mut_min_len(&mut self, n: usize);
+
+	/// # Trim Matches Mut (Minimum Length).
+	///
+	/// Trim arbitrary leading and trailing units as determined by the
+	/// provided pattern, mutably, stopping before the content drops below
+	/// `n` units.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::TrimMinLenMut;
+	///
+	/// let mut s = String::from("__--__");
+	/// s.trim_matches_mut_min_len(['-', '_'], 2);
+	/// assert_eq!(s, "__");
+	/// ```
+	fn trim_matches_mut_min_len<P: MatchPattern<Self::MatchUnit>>(&mut self, pat: P, n: usize);
+}
+
+impl TrimMinLenMut for String {
+	/// # Matches Type.
+	type MatchUnit = char;
+
+	#[inline]
+	/// # Trim Mut (Minimum Length).
+	///
+	/// Remove leading and trailing whitespace, mutably, keeping at least `n`
+	/// chars.
+	fn trim_mut_min_len(&mut self, n: usize) {
+		self.trim_matches_mut_min_len(char::is_whitespace, n);
+	}
+
+	/// # Trim Matches Mut (Minimum Length).
+	///
+	/// Trim arbitrary leading and trailing chars, mutably, keeping at least
+	/// `n` chars.
+	fn trim_matches_mut_min_len<P: MatchPattern<char>>(&mut self, pat: P, n: usize) {
+		if n == 0 { return self.trim_matches_mut(pat); }
+
+		let mut left = self.chars().count();
+		if left <= n { return; }
+
+		// Leading.
+		let mut start = self.len();
+		for (idx, c) in self.char_indices() {
+			if left <= n || ! pat.is_match(c) {
+				start = idx;
+				break;
+			}
+			left -= 1;
+		}
+
+		// Trailing.
+		let mut end = start;
+		for (idx, c) in self[start..].char_indices().rev() {
+			if left <= n || ! pat.is_match(c) {
+				end = start + idx + c.len_utf8();
+				break;
+			}
+			left -= 1;
+		}
+
+		self.truncate(end);
+		self.drain(..start);
+	}
+}
+
+impl TrimMinLenMut for Vec<u8> {
+	/// # Matches Type.
+	type MatchUnit = u8;
+
+	#[inline]
+	/// # Trim Mut (Minimum Length).
+	///
+	/// Remove leading and trailing ASCII whitespace, mutably, keeping at
+	/// least `n` bytes.
+	fn trim_mut_min_len(&mut self, n: usize) {
+		self.trim_matches_mut_min_len(|b: u8| b.is_ascii_whitespace(), n);
+	}
+
+	/// # Trim Matches Mut (Minimum Length).
+	///
+	/// Trim arbitrary leading and trailing bytes, mutably, keeping at least
+	/// `n` bytes.
+	fn trim_matches_mut_min_len<P: MatchPattern<u8>>(&mut self, pat: P, n: usize) {
+		if n == 0 { return self.trim_matches_mut(pat); }
+		if self.len() <= n { return; }
+
+		// Only so much can go.
+		let mut budget = self.len() - n;
+		let start = self.iter().take(budget).take_while(|&&b| pat.is_match(b)).count();
+		budget -= start;
+		let end = self.len() - self[start..].iter()
+			.rev()
+			.take(budget)
+			.take_while(|&&b| pat.is_match(b))
+			.count();
+
+		self.truncate(end);
+		self.drain(..start);
+	}
+}
+
+
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+/// # Trim Limit.
+///
+/// This is the maximum amount of content the [`TryTrimMatchesMut`] methods
+/// are allowed to remove, either as an absolute number of units (`char`s
+/// for `String`, bytes for `Vec<u8>`), or as a percentage of the original
+/// length.
+pub enum TrimLimit {
+	/// # Maximum Units.
+	Units(usize),
+
+	/// # Maximum Percentage (`0..=100`).
+	Percent(u8),
+}
+
+impl TrimLimit {
+	#[must_use]
+	/// # Allows?
+	///
+	/// Returns `true` if removing `removed` units from a value `total` units
+	/// long is within the limit.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::TrimLimit;
+	///
+	/// assert!(TrimLimit::Units(3).allows(3, 10));
+	/// assert!(! TrimLimit::Units(3).allows(4, 10));
+	/// assert!(TrimLimit::Percent(50).allows(5, 10));
+	/// assert!(! TrimLimit::Percent(50).allows(6, 10));
+	/// ```
+	pub const fn allows(self, removed: usize, total: usize) -> bool {
+		match self {
+			Self::Units(n) => removed <= n,
+			Self::Percent(p) => {
+				let p = if p < 100 { p as usize } else { 100 };
+				removed.saturating_mul(100) <= total.saturating_mul(p)
+			},
+		}
+	}
+}
+
+
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+/// # Too Much Trimmed.
+///
+/// This is the error returned by the [`TryTrimMatchesMut`] methods when
+/// trimming would exceed the [`TrimLimit`].
+pub struct TooMuchTrimmed {
+	/// # Units That Would Have Been Removed.
+	removed: usize,
+
+	/// # Original Length (Units).
+	total: usize,
+}
+
+impl fmt::Display for TooMuchTrimmed {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "trimming would remove {} of {} units", self.removed, self.total)
+	}
+}
+
+impl Error for TooMuchTrimmed {}
+
+impl TooMuchTrimmed {
+	#[must_use]
+	/// # Removed.
+	///
+	/// Return the number of units trimming would have removed.
+	pub const fn removed(self) -> usize { self.removed }
+
+	#[must_use]
+	/// # Total.
+	///
+	/// Return the original length, in units.
+	pub const fn total(self) -> usize { self.total }
+}
+
+
+
+/// # Checked Trim.
+///
+/// The [`TryTrimMatchesMut`] trait adds guarded trimming to `String` and
+/// `Vec<u8>`, refusing to trim — and leaving the value untouched — if doing
+/// so would remove more than a given [`TrimLimit`], protecting pipelines
+/// from patterns that accidentally wipe out whole fields.
+///
+/// | Method | Description |
+/// | ------ | ----------- |
+/// | `try_trim_mut` | Trim leading and trailing whitespace, within limits (mutably). |
+/// | `try_trim_matches_mut` | Trim arbitrary leading and trailing units, within limits (mutably). |
+///
+/// Units are `char`s for `String`, and bytes for `Vec<u8>`.
+pub trait TryTrimMatchesMut {
+	/// # Matches Type.
+	///
+	/// This is the "unit" type of the collection, e.g. `char` for `String`,
+	/// `u8` for `Vec<u8>`.
+	type MatchUnit: Copy + Eq + Ord + Sized;
+
+	/// # Try Trim Mut.
+	///
+	/// Remove leading and trailing whitespace, mutably, unless doing so
+	/// would exceed the limit.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::{TrimLimit, TryTrimMatchesMut};
+	///
+	/// let mut s = String::from(" Hello ");
+	/// assert!(s.try_trim_mut(TrimLimit::Units(2)).is_ok());
+	/// assert_eq!(s, "Hello");
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// If trimming would remove more than allowed, an error is returned and
+	/// the value is left unchanged.
+	fn try_trim_mut(&mut self, limit: TrimLimit) -> Result<(), TooMuchTrimmed>;
+
+	/// # Try Trim Matches Mut.
+	///
+	/// Trim arbitrary leading and trailing units as determined by the
+	/// provided pattern, mutably, unless doing so would exceed the limit.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::{TrimLimit, TryTrimMatchesMut};
+	///
+	/// // A pattern that matches far more than intended.
+	/// let mut s = String::from("2024-01-01");
+	/// let err = s.try_trim_matches_mut(|c: char| c.is_ascii_digit() || c == '-', TrimLimit::Percent(25))
+	///     .unwrap_err();
+	/// assert_eq!(err.removed(), 10);
+	/// assert_eq!(s, "2024-01-01"); // Untouched.
+	///
+	/// let mut v = b"--Hello--".to_vec();
+	/// assert!(v.try_trim_matches_mut(b'-', TrimLimit::Percent(50)).is_ok());
+	/// assert_eq!(v, b"Hello");
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// If trimming would remove more than allowed, an error is returned and
+	/// the value is left unchanged.
+	fn try_trim_matches_mut<P: MatchPattern<Self::MatchUnit>>(&mut self, pat: P, limit: TrimLimit)
+	-> Result<(), TooMuchTrimmed>;
+}
+
+impl TryTrimMatchesMut for String {
+	/// # Matches Type.
+	type MatchUnit = char;
+
+	#[inline]
+	/// # Try Trim Mut.
+	///
+	/// Remove leading and trailing whitespace, mutably, unless doing so
+	/// would exceed the limit.
+	fn try_trim_mut(&mut self, limit: TrimLimit) -> Result<(), TooMuchTrimmed> {
+		self.try_trim_matches_mut(char::is_whitespace, limit)
+	}
+
+	/// # Try Trim Matches Mut.
+	///
+	/// Trim arbitrary leading and trailing chars, mutably, unless doing so
+	/// would exceed the limit.
+	fn try_trim_matches_mut<P: MatchPattern<char>>(&mut self, pat: P, limit: TrimLimit)
+	-> Result<(), TooMuchTrimmed> {
+		let start = self.len() - self.trim_start_matches(|c| pat.is_match(c)).len();
+		let end = start + self[start..].trim_end_matches(|c| pat.is_match(c)).len();
+		if start == 0 && end == self.len() { return Ok(()); }
+
+		let removed = self[..start].chars().count() + self[end..].chars().count();
+		let total = removed + self[start..end].chars().count();
+		if ! limit.allows(removed, total) { return Err(TooMuchTrimmed { removed, total }); }
+
+		self.truncate(end);
+		self.drain(..start);
+		Ok(())
+	}
+}
+
+impl TryTrimMatchesMut for Vec<u8> {
+	/// # Matches Type.
+	type MatchUnit = u8;
+
+	#[inline]
+	/// # Try Trim Mut.
+	///
+	/// Remove leading and trailing ASCII whitespace, mutably, unless doing
+	/// so would exceed the limit.
+	fn try_trim_mut(&mut self, limit: TrimLimit) -> Result<(), TooMuchTrimmed> {
+		self.try_trim_matches_mut(|b: u8| b.is_ascii_whitespace(), limit)
+	}
+
+	/// # Try Trim Matches Mut.
+	///
+	/// Trim arbitrary leading and trailing bytes, mutably, unless doing so
+	/// would exceed the limit.
+	fn try_trim_matches_mut<P: MatchPattern<u8>>(&mut self, pat: P, limit: TrimLimit)
+	-> Result<(), TooMuchTrimmed> {
+		let total = self.len();
+		let start = total - self.trim_start_matches(pat).len();
+		let end = start + self[start..].trim_end_matches(pat).len();
+		let removed = total - (end - start);
+		if removed == 0 { return Ok(()); }
+		if ! limit.allows(removed, total) { return Err(TooMuchTrimmed { removed, total }); }
+
+		self.truncate(end);
+		self.drain(..start);
+		Ok(())
+	}
 }
 
 
@@ -161,6 +1006,33 @@ impl TrimMut for String {
 	}
 }
 
+impl TrimKeepNewlineMut for String {
+	/// # Trim End Mut (Keep Newline).
+	///
+	/// Remove trailing whitespace, mutably, _except_ line breaks, preserving
+	/// the final line ending (`\n` or `\r\n`), if any.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::TrimKeepNewlineMut;
+	///
+	/// let mut s = String::from(" Hello World! \t\r\n");
+	/// s.trim_end_keep_newline_mut();
+	/// assert_eq!(s, " Hello World!\r\n");
+	///
+	/// // Without a line ending, it's just like trim_end_mut.
+	/// let mut s = String::from(" Hello World! ");
+	/// s.trim_end_keep_newline_mut();
+	/// assert_eq!(s, " Hello World!");
+	/// ```
+	fn trim_end_keep_newline_mut(&mut self) {
+		let body = self.len() - eol_len(self.as_bytes());
+		let trimmed_len = self[..body].trim_end_matches(is_horizontal_ws).len();
+		if trimmed_len < body { self.replace_range(trimmed_len..body, ""); }
+	}
+}
+
 impl TrimMatchesMut for String {
 	type MatchUnit = char;
 
@@ -215,10 +1087,40 @@ impl TrimMatchesMut for String {
 	/// assert_eq!(s, "ello World! ");
 	/// ```
 	fn trim_start_matches_mut<P: MatchPattern<char>>(&mut self, pat: P) {
-		if let Some(start) = self.find(#[inline(always)] |c| ! pat.is_match(c)) {
-			if start != 0 { self.replace_range(..start, ""); }
-		}
-		else { self.truncate(0); }
+		let start = self.trim_start_split_mut(pat);
+		if start == self.len() { self.truncate(0); }
+		else if start != 0 { self.replace_range(..start, ""); }
+	}
+
+	#[inline]
+	/// # Trim Start Split Mut.
+	///
+	/// Return the byte length of the leading chars matching the provided
+	/// pattern, leaving the string itself untouched.
+	///
+	/// This is the deferred half of
+	/// [`trim_start_matches_mut`](TrimMatchesMut::trim_start_matches_mut),
+	/// useful when the value is about to be sliced, boxed, or shared anyway
+	/// and the memmove would be wasted effort.
+	///
+	/// The returned index always falls on a char boundary.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use std::sync::Arc;
+	/// use trimothy::TrimMatchesMut;
+	///
+	/// let mut s = String::from("  Hello World!");
+	/// let n = s.trim_start_split_mut(' ');
+	/// assert_eq!(n, 2);
+	/// assert_eq!(s, "  Hello World!"); // Nothing moved.
+	///
+	/// let shared: Arc<str> = Arc::from(&s[n..]);
+	/// assert_eq!(shared.as_ref(), "Hello World!");
+	/// ```
+	fn trim_start_split_mut<P: MatchPattern<char>>(&mut self, pat: P) -> usize {
+		self.find(#[inline(always)] |c| ! pat.is_match(c)).unwrap_or(self.len())
 	}
 
 	#[inline]
@@ -348,6 +1250,56 @@ impl TrimMut for Cow<'_, str> {
 	}
 }
 
+impl TrimKeepNewlineMut for Cow<'_, str> {
+	/// # Trim End Mut (Keep Newline).
+	///
+	/// Remove trailing whitespace, mutably, _except_ line breaks, preserving
+	/// the final line ending (`\n` or `\r\n`), if any.
+	///
+	/// Borrowed values remain borrowed unless they end with a line ending
+	/// _and_ have whitespace to remove in front of it, in which case they'll
+	/// be converted to owned.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// # extern crate alloc;
+	/// # use alloc::borrow::Cow;
+	/// use trimothy::TrimKeepNewlineMut;
+	///
+	/// // No line ending: trailing whitespace is trimmed, borrow kept.
+	/// let mut s: Cow<str> = Cow::Borrowed(" Hello World! ");
+	/// s.trim_end_keep_newline_mut();
+	/// assert_eq!(s.as_ref(), " Hello World!");
+	/// assert!(matches!(s, Cow::Borrowed(_)));
+	///
+	/// // Borrowed data can't have a hole punched in the middle.
+	/// let mut s: Cow<str> = Cow::Borrowed(" Hello World! \n");
+	/// s.trim_end_keep_newline_mut();
+	/// assert_eq!(s.as_ref(), " Hello World!\n");
+	/// assert!(matches!(s, Cow::Owned(_)));
+	/// ```
+	fn trim_end_keep_newline_mut(&mut self) {
+		match self {
+			Cow::Borrowed(s) => {
+				let eol = eol_len(s.as_bytes());
+				let body = s.len() - eol;
+				let trimmed_len = s[..body].trim_end_matches(is_horizontal_ws).len();
+				if trimmed_len < body {
+					if eol == 0 { *self = Cow::Borrowed(&s[..trimmed_len]); }
+					else {
+						let mut out = String::with_capacity(trimmed_len + eol);
+						out.push_str(&s[..trimmed_len]);
+						out.push_str(&s[body..]);
+						*self = Cow::Owned(out);
+					}
+				}
+			},
+			Cow::Owned(s) => { s.trim_end_keep_newline_mut(); },
+		}
+	}
+}
+
 impl TrimMatchesMut for Cow<'_, str> {
 	type MatchUnit = char;
 
@@ -427,6 +1379,30 @@ impl TrimMatchesMut for Cow<'_, str> {
 		}
 	}
 
+	#[inline]
+	/// # Trim Start Split Mut.
+	///
+	/// Return the byte length of the leading chars matching the provided
+	/// pattern, leaving the value itself untouched — borrowed or owned —
+	/// the same as `String`.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// # extern crate alloc;
+	/// # use alloc::borrow::Cow;
+	/// use trimothy::TrimMatchesMut;
+	///
+	/// let mut s: Cow<str> = Cow::Borrowed("  Hello World!");
+	/// let n = s.trim_start_split_mut(' ');
+	/// assert_eq!(n, 2);
+	/// assert_eq!(s, "  Hello World!"); // Nothing changed.
+	/// assert_eq!(&s[n..], "Hello World!");
+	/// ```
+	fn trim_start_split_mut<P: MatchPattern<char>>(&mut self, pat: P) -> usize {
+		self.len() - self.trim_start_matches(#[inline(always)] |c| pat.is_match(c)).len()
+	}
+
 	#[inline]
 	/// # Trim End Matches Mut.
 	///
@@ -530,18 +1506,46 @@ impl TrimMut for Box<[u8]> {
 	}
 }
 
-impl TrimMatchesMut for Box<[u8]> {
-	type MatchUnit = u8;
+impl TrimKeepNewlineMut for Box<[u8]> {
+	/// # Trim End Mut (Keep Newline).
+	///
+	/// Remove trailing (ASCII) whitespace, _except_ line breaks, preserving
+	/// the final line ending (`\n` or `\r\n`), if any, replacing `Self` with
+	/// a new boxed slice if necessary.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::TrimKeepNewlineMut;
+	///
+	/// let mut v = Box::<[u8]>::from(&b" Hello World! \t\n"[..]);
+	/// v.trim_end_keep_newline_mut();
+	/// assert_eq!(v, Box::from(&b" Hello World!\n"[..]));
+	/// ```
+	fn trim_end_keep_newline_mut(&mut self) {
+		let body = self.len() - eol_len(self);
+		let trimmed_len = self[..body].trim_end_matches(is_horizontal_ascii_ws).len();
+		if trimmed_len < body {
+			let mut out = Vec::with_capacity(trimmed_len + self.len() - body);
+			out.extend_from_slice(&self[..trimmed_len]);
+			out.extend_from_slice(&self[body..]);
+			*self = out.into_boxed_slice();
+		}
+	}
+}
+
+impl<T: Copy + Eq + Ord + Sized> TrimMatchesMut for Box<[T]> {
+	type MatchUnit = T;
 
 	#[inline]
 	/// # Trim Matches Mut.
 	///
-	/// Trim arbitrary leading and trailing bytes as determined by the provided
+	/// Trim arbitrary leading and trailing elements as determined by the provided
 	/// pattern, which can be:
-	/// * A single `u8`;
-	/// * An array or slice of `u8`;
-	/// * A `&BTreeSet<u8>`;
-	/// * A callback with the signature `Fn(u8) -> bool`;
+	/// * A single `T`;
+	/// * An array or slice of `T`;
+	/// * A `&BTreeSet<T>`;
+	/// * A [`Predicate`](crate::Predicate) callback (or for bytes, a bare `Fn(u8) -> bool`);
 	///
 	/// ## Examples
 	///
@@ -552,7 +1556,7 @@ impl TrimMatchesMut for Box<[u8]> {
 	/// v.trim_matches_mut(|b: u8| b'!' == b || b.is_ascii_whitespace());
 	/// assert_eq!(v, Box::from(&b"Hello World"[..]));
 	/// ```
-	fn trim_matches_mut<P: MatchPattern<u8>>(&mut self, pat: P) {
+	fn trim_matches_mut<P: MatchPattern<T>>(&mut self, pat: P) {
 		let trimmed = self.trim_matches(pat);
 		if trimmed.len() < self.len() { *self = Self::from(trimmed); }
 	}
@@ -560,12 +1564,12 @@ impl TrimMatchesMut for Box<[u8]> {
 	#[inline]
 	/// # Trim Start Matches Mut.
 	///
-	/// Trim arbitrary leading bytes as determined by the provided
+	/// Trim arbitrary leading elements as determined by the provided
 	/// pattern, which can be:
-	/// * A single `u8`;
-	/// * An array or slice of `u8`;
-	/// * A `&BTreeSet<u8>`;
-	/// * A callback with the signature `Fn(u8) -> bool`;
+	/// * A single `T`;
+	/// * An array or slice of `T`;
+	/// * A `&BTreeSet<T>`;
+	/// * A [`Predicate`](crate::Predicate) callback (or for bytes, a bare `Fn(u8) -> bool`);
 	///
 	/// ## Examples
 	///
@@ -576,20 +1580,40 @@ impl TrimMatchesMut for Box<[u8]> {
 	/// v.trim_start_matches_mut(|b: u8| b'!' == b || b.is_ascii_whitespace());
 	/// assert_eq!(v, Box::from(&b"Hello World! "[..]));
 	/// ```
-	fn trim_start_matches_mut<P: MatchPattern<u8>>(&mut self, pat: P) {
+	fn trim_start_matches_mut<P: MatchPattern<T>>(&mut self, pat: P) {
 		let trimmed = self.trim_start_matches(pat);
 		if trimmed.len() < self.len() { *self = Self::from(trimmed); }
 	}
 
+	#[inline]
+	/// # Trim Start Split Mut.
+	///
+	/// Return the length of the leading elements matching the provided pattern,
+	/// leaving the slice itself untouched (and unreallocated).
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::TrimMatchesMut;
+	///
+	/// let mut v = Box::<[u8]>::from(&b"  Hello World!"[..]);
+	/// let n = v.trim_start_split_mut(b' ');
+	/// assert_eq!(n, 2);
+	/// assert_eq!(&v[n..], b"Hello World!");
+	/// ```
+	fn trim_start_split_mut<P: MatchPattern<T>>(&mut self, pat: P) -> usize {
+		self.len() - self.trim_start_matches(pat).len()
+	}
+
 	#[inline]
 	/// # Trim End Matches Mut.
 	///
-	/// Trim arbitrary trailing bytes as determined by the provided
+	/// Trim arbitrary trailing elements as determined by the provided
 	/// pattern, which can be:
-	/// * A single `u8`;
-	/// * An array or slice of `u8`;
-	/// * A `&BTreeSet<u8>`;
-	/// * A callback with the signature `Fn(u8) -> bool`;
+	/// * A single `T`;
+	/// * An array or slice of `T`;
+	/// * A `&BTreeSet<T>`;
+	/// * A [`Predicate`](crate::Predicate) callback (or for bytes, a bare `Fn(u8) -> bool`);
 	///
 	/// ## Examples
 	///
@@ -600,7 +1624,7 @@ impl TrimMatchesMut for Box<[u8]> {
 	/// v.trim_end_matches_mut(|b: u8| b'!' == b || b.is_ascii_whitespace());
 	/// assert_eq!(v, Box::from(&b" Hello World"[..]));
 	/// ```
-	fn trim_end_matches_mut<P: MatchPattern<u8>>(&mut self, pat: P) {
+	fn trim_end_matches_mut<P: MatchPattern<T>>(&mut self, pat: P) {
 		let trimmed = self.trim_end_matches(pat);
 		if trimmed.len() < self.len() { *self = Self::from(trimmed); }
 	}
@@ -671,17 +1695,40 @@ impl TrimMut for Vec<u8> {
 	}
 }
 
-impl TrimMatchesMut for Vec<u8> {
-	type MatchUnit = u8;
+impl TrimKeepNewlineMut for Vec<u8> {
+	#[inline]
+	/// # Trim End Mut (Keep Newline).
+	///
+	/// Remove trailing (ASCII) whitespace, mutably, _except_ line breaks,
+	/// preserving the final line ending (`\n` or `\r\n`), if any.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::TrimKeepNewlineMut;
+	///
+	/// let mut v = b" Hello World! \t\n".to_vec();
+	/// v.trim_end_keep_newline_mut();
+	/// assert_eq!(v, b" Hello World!\n");
+	/// ```
+	fn trim_end_keep_newline_mut(&mut self) {
+		let body = self.len() - eol_len(self);
+		let trimmed_len = self[..body].trim_end_matches(is_horizontal_ascii_ws).len();
+		if trimmed_len < body { self.drain(trimmed_len..body); }
+	}
+}
+
+impl<T: Copy + Eq + Ord + Sized> TrimMatchesMut for Vec<T> {
+	type MatchUnit = T;
 
 	/// # Trim Matches Mut.
 	///
-	/// Trim arbitrary leading and trailing bytes as determined by the provided
+	/// Trim arbitrary leading and trailing elements as determined by the provided
 	/// pattern, which can be:
-	/// * A single `u8`;
-	/// * An array or slice of `u8`;
-	/// * A `&BTreeSet<u8>`;
-	/// * A callback with the signature `Fn(u8) -> bool`;
+	/// * A single `T`;
+	/// * An array or slice of `T`;
+	/// * A `&BTreeSet<T>`;
+	/// * A [`Predicate`](crate::Predicate) callback (or for bytes, a bare `Fn(u8) -> bool`);
 	///
 	/// ## Examples
 	///
@@ -692,20 +1739,23 @@ impl TrimMatchesMut for Vec<u8> {
 	/// v.trim_matches_mut(|b: u8| b.is_ascii_whitespace() || b.is_ascii_uppercase());
 	/// assert_eq!(v, b"ello World!");
 	/// ```
-	fn trim_matches_mut<P: MatchPattern<u8>>(&mut self, pat: P) {
-		self.trim_end_matches_mut(pat);
-		self.trim_start_matches_mut(pat);
+	fn trim_matches_mut<P: MatchPattern<T>>(&mut self, pat: P) {
+		// Find both boundaries up front so the data only has to move once.
+		let (trimmed, start, _) = self.trim_matches_counted(pat);
+		let end = start + trimmed.len();
+		if 0 != start { self.copy_within(start..end, 0); }
+		self.truncate(end - start);
 	}
 
 	#[inline]
 	/// # Trim Start Matches Mut.
 	///
-	/// Trim arbitrary leading bytes as determined by the provided
+	/// Trim arbitrary leading elements as determined by the provided
 	/// pattern, which can be:
-	/// * A single `u8`;
-	/// * An array or slice of `u8`;
-	/// * A `&BTreeSet<u8>`;
-	/// * A callback with the signature `Fn(u8) -> bool`;
+	/// * A single `T`;
+	/// * An array or slice of `T`;
+	/// * A `&BTreeSet<T>`;
+	/// * A [`Predicate`](crate::Predicate) callback (or for bytes, a bare `Fn(u8) -> bool`);
 	///
 	/// ## Examples
 	///
@@ -716,26 +1766,54 @@ impl TrimMatchesMut for Vec<u8> {
 	/// v.trim_start_matches_mut(|b: u8| b.is_ascii_whitespace() || b.is_ascii_uppercase());
 	/// assert_eq!(v, b"ello World! ");
 	/// ```
-	fn trim_start_matches_mut<P: MatchPattern<u8>>(&mut self, pat: P) {
-		if let Some(start) = self.iter().copied().position(#[inline(always)] |b| ! pat.is_match(b)) {
-			if 0 != start {
-				let trimmed_len = self.len() - start;
-				self.copy_within(start.., 0);
-				self.truncate(trimmed_len);
-			}
+	fn trim_start_matches_mut<P: MatchPattern<T>>(&mut self, pat: P) {
+		let start = self.trim_start_split_mut(pat);
+		if start == self.len() { self.truncate(0); }
+		else if 0 != start {
+			let trimmed_len = self.len() - start;
+			self.copy_within(start.., 0);
+			self.truncate(trimmed_len);
 		}
-		else { self.truncate(0); }
+	}
+
+	#[inline]
+	/// # Trim Start Split Mut.
+	///
+	/// Return the length of the leading elements matching the provided pattern,
+	/// leaving the vector itself untouched.
+	///
+	/// This is the deferred half of
+	/// [`trim_start_matches_mut`](TrimMatchesMut::trim_start_matches_mut),
+	/// useful when the value is about to be sliced, boxed, or shared anyway
+	/// and the memmove would be wasted effort.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use std::sync::Arc;
+	/// use trimothy::TrimMatchesMut;
+	///
+	/// let mut v = b"  Hello World!".to_vec();
+	/// let n = v.trim_start_split_mut(b' ');
+	/// assert_eq!(n, 2);
+	/// assert_eq!(v, b"  Hello World!"); // Nothing moved.
+	///
+	/// let shared: Arc<[u8]> = Arc::from(&v[n..]);
+	/// assert_eq!(shared.as_ref(), b"Hello World!");
+	/// ```
+	fn trim_start_split_mut<P: MatchPattern<T>>(&mut self, pat: P) -> usize {
+		self.iter().copied().position(#[inline(always)] |b| ! pat.is_match(b)).unwrap_or(self.len())
 	}
 
 	#[inline]
 	/// # Trim End Matches Mut.
 	///
-	/// Trim arbitrary trailing bytes as determined by the provided
+	/// Trim arbitrary trailing elements as determined by the provided
 	/// pattern, which can be:
-	/// * A single `u8`;
-	/// * An array or slice of `u8`;
-	/// * A `&BTreeSet<u8>`;
-	/// * A callback with the signature `Fn(u8) -> bool`;
+	/// * A single `T`;
+	/// * An array or slice of `T`;
+	/// * A `&BTreeSet<T>`;
+	/// * A [`Predicate`](crate::Predicate) callback (or for bytes, a bare `Fn(u8) -> bool`);
 	///
 	/// ## Examples
 	///
@@ -746,7 +1824,7 @@ impl TrimMatchesMut for Vec<u8> {
 	/// v.trim_end_matches_mut(|b: u8| b.is_ascii_whitespace() || b.is_ascii_uppercase());
 	/// assert_eq!(v, b" Hello World!");
 	/// ```
-	fn trim_end_matches_mut<P: MatchPattern<u8>>(&mut self, pat: P) {
+	fn trim_end_matches_mut<P: MatchPattern<T>>(&mut self, pat: P) {
 		let end = self.iter()
 			.copied()
 			.rposition(#[inline(always)] |b| ! pat.is_match(b))
@@ -853,6 +1931,56 @@ impl TrimMut for Cow<'_, [u8]> {
 	}
 }
 
+impl TrimKeepNewlineMut for Cow<'_, [u8]> {
+	/// # Trim End Mut (Keep Newline).
+	///
+	/// Remove trailing (ASCII) whitespace, mutably, _except_ line breaks,
+	/// preserving the final line ending (`\n` or `\r\n`), if any.
+	///
+	/// Borrowed values remain borrowed unless they end with a line ending
+	/// _and_ have whitespace to remove in front of it, in which case they'll
+	/// be converted to owned.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// # extern crate alloc;
+	/// # use alloc::borrow::Cow;
+	/// use trimothy::TrimKeepNewlineMut;
+	///
+	/// // No line ending: trailing whitespace is trimmed, borrow kept.
+	/// let mut s: Cow<[u8]> = Cow::Borrowed(b" Hello World! ");
+	/// s.trim_end_keep_newline_mut();
+	/// assert_eq!(s.as_ref(), b" Hello World!");
+	/// assert!(matches!(s, Cow::Borrowed(_)));
+	///
+	/// // Borrowed data can't have a hole punched in the middle.
+	/// let mut s: Cow<[u8]> = Cow::Borrowed(b" Hello World! \n");
+	/// s.trim_end_keep_newline_mut();
+	/// assert_eq!(s.as_ref(), b" Hello World!\n");
+	/// assert!(matches!(s, Cow::Owned(_)));
+	/// ```
+	fn trim_end_keep_newline_mut(&mut self) {
+		match self {
+			Cow::Borrowed(s) => {
+				let eol = eol_len(s);
+				let body = s.len() - eol;
+				let trimmed_len = s[..body].trim_end_matches(is_horizontal_ascii_ws).len();
+				if trimmed_len < body {
+					if eol == 0 { *self = Cow::Borrowed(&s[..trimmed_len]); }
+					else {
+						let mut out = Vec::with_capacity(trimmed_len + eol);
+						out.extend_from_slice(&s[..trimmed_len]);
+						out.extend_from_slice(&s[body..]);
+						*self = Cow::Owned(out);
+					}
+				}
+			},
+			Cow::Owned(s) => { s.trim_end_keep_newline_mut(); },
+		}
+	}
+}
+
 impl TrimMatchesMut for Cow<'_, [u8]> {
 	type MatchUnit = u8;
 
@@ -932,6 +2060,30 @@ impl TrimMatchesMut for Cow<'_, [u8]> {
 		}
 	}
 
+	#[inline]
+	/// # Trim Start Split Mut.
+	///
+	/// Return the length of the leading bytes matching the provided
+	/// pattern, leaving the value itself untouched — borrowed or owned —
+	/// the same as `Vec<u8>`.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// # extern crate alloc;
+	/// # use alloc::borrow::Cow;
+	/// use trimothy::TrimMatchesMut;
+	///
+	/// let mut s: Cow<[u8]> = Cow::Borrowed(b"  Hello World!");
+	/// let n = s.trim_start_split_mut(b' ');
+	/// assert_eq!(n, 2);
+	/// assert_eq!(s.as_ref(), b"  Hello World!"); // Nothing changed.
+	/// assert_eq!(&s[n..], b"Hello World!");
+	/// ```
+	fn trim_start_split_mut<P: MatchPattern<u8>>(&mut self, pat: P) -> usize {
+		self.len() - self.trim_start_matches(pat).len()
+	}
+
 	#[inline]
 	/// # Trim End Matches Mut.
 	///
@@ -973,10 +2125,284 @@ impl TrimMatchesMut for Cow<'_, [u8]> {
 
 
 
+/// # Helper: Smart Pointer Passthrough.
+///
+/// Mutable references, boxes, and the like implement the traits too, simply
+/// forwarding the calls to whatever they point at, so generic code can
+/// accept a `MutexGuard<String>` or `RefMut<Vec<u8>>` as readily as the
+/// real thing.
+macro_rules! deref_mut {
+	($($(#[$meta:meta])* $ty:ty),+ $(,)?) => ($(
+		$(#[$meta])*
+		impl<T: TrimMut + ?Sized> TrimMut for $ty {
+			#[inline]
+			/// # Trim Mut.
+			///
+			/// Remove leading and trailing whitespace from the inner value,
+			/// mutably.
+			fn trim_mut(&mut self) { (**self).trim_mut(); }
+
+			#[inline]
+			/// # Trim Start Mut.
+			///
+			/// Remove leading whitespace from the inner value, mutably.
+			fn trim_start_mut(&mut self) { (**self).trim_start_mut(); }
+
+			#[inline]
+			/// # Trim End Mut.
+			///
+			/// Remove trailing whitespace from the inner value, mutably.
+			fn trim_end_mut(&mut self) { (**self).trim_end_mut(); }
+		}
+
+		$(#[$meta])*
+		impl<T: TrimKeepNewlineMut + ?Sized> TrimKeepNewlineMut for $ty {
+			#[inline]
+			/// # Trim End Mut (Keep Newline).
+			///
+			/// Remove trailing whitespace from the inner value, mutably,
+			/// _except_ line breaks.
+			fn trim_end_keep_newline_mut(&mut self) {
+				(**self).trim_end_keep_newline_mut();
+			}
+		}
+
+		$(#[$meta])*
+		impl<T: TrimMatchesMut + ?Sized> TrimMatchesMut for $ty {
+			type MatchUnit = T::MatchUnit;
+
+			#[inline]
+			/// # Trim Matches Mut.
+			///
+			/// Trim arbitrary leading and trailing units from the inner
+			/// value as determined by the provided pattern.
+			fn trim_matches_mut<P: MatchPattern<Self::MatchUnit>>(&mut self, pat: P) {
+				(**self).trim_matches_mut(pat);
+			}
+
+			#[inline]
+			/// # Trim Start Matches Mut.
+			///
+			/// Trim arbitrary leading units from the inner value as
+			/// determined by the provided pattern.
+			fn trim_start_matches_mut<P: MatchPattern<Self::MatchUnit>>(&mut self, pat: P) {
+				(**self).trim_start_matches_mut(pat);
+			}
+
+			#[inline]
+			/// # Trim Start Split Mut.
+			///
+			/// Find the length of the inner value's leading portion
+			/// matching the provided pattern _without_ removing it.
+			fn trim_start_split_mut<P: MatchPattern<Self::MatchUnit>>(&mut self, pat: P) -> usize {
+				(**self).trim_start_split_mut(pat)
+			}
+
+			#[inline]
+			/// # Trim End Matches Mut.
+			///
+			/// Trim arbitrary trailing units from the inner value as
+			/// determined by the provided pattern.
+			fn trim_end_matches_mut<P: MatchPattern<Self::MatchUnit>>(&mut self, pat: P) {
+				(**self).trim_end_matches_mut(pat);
+			}
+
+			#[inline]
+			/// # Trim Layers Mut.
+			///
+			/// Trim each pattern in turn from the inner value, repeating
+			/// until a full pass leaves it unchanged.
+			fn trim_layers_mut(&mut self, layers: &[&dyn DynPattern<Self::MatchUnit>]) {
+				(**self).trim_layers_mut(layers);
+			}
+
+			#[inline]
+			/// # Trim Repeat Mut.
+			///
+			/// Same as `trim_layers_mut`, but giving up after at most `max`
+			/// passes.
+			fn trim_repeat_mut(&mut self, layers: &[&dyn DynPattern<Self::MatchUnit>], max: usize)
+			-> bool {
+				(**self).trim_repeat_mut(layers, max)
+			}
+		}
+	)+);
+}
+
+deref_mut!(
+	&mut T,
+	Box<T>,
+	RefMut<'_, T>,
+	#[cfg(feature = "std")] std::sync::MutexGuard<'_, T>,
+	#[cfg(feature = "std")] std::sync::RwLockWriteGuard<'_, T>,
+);
+
+
+
+#[inline]
+/// # Line Ending Length.
+///
+/// Return the length of the trailing `\n` or `\r\n`, if any.
+const fn eol_len(src: &[u8]) -> usize {
+	match src {
+		[.., b'\r', b'\n'] => 2,
+		[.., b'\n'] => 1,
+		_ => 0,
+	}
+}
+
+#[inline]
+/// # Is Horizontal (ASCII) Whitespace?
+///
+/// Same as [`u8::is_ascii_whitespace`], but excluding line breaks.
+const fn is_horizontal_ascii_ws(b: u8) -> bool {
+	matches!(b, b'\t' | b'\x0C' | b' ')
+}
+
+#[inline]
+/// # Is Horizontal Whitespace?
+///
+/// Same as [`char::is_whitespace`], but excluding line breaks, including
+/// the Unicode next-line, line-separator, and paragraph-separator
+/// characters.
+fn is_horizontal_ws(c: char) -> bool {
+	c.is_whitespace() && ! matches!(c, '\n' | '\r' | '\u{85}' | '\u{2028}' | '\u{2029}')
+}
+
+
+
+/// # Trim Layer.
+///
+/// This wraps a dynamic pattern for [`TrimMatchesMut::trim_layers_mut`],
+/// noting whether or not it ever matched (i.e. trimmed) anything.
+///
+/// This is also used by [`TrimMatchesMut::trim_repeat_mut`].
+struct Layer<'a, T> {
+	/// # Pattern.
+	pat: &'a dyn DynPattern<T>,
+
+	/// # Changed?
+	changed: &'a Cell<bool>,
+}
+
+impl<T> Clone for Layer<'_, T> {
+	#[inline]
+	fn clone(&self) -> Self { *self }
+}
+
+impl<T> Copy for Layer<'_, T> {}
+
+impl<T: Copy + Eq + Ord + Sized> MatchPattern<T> for Layer<'_, T> {
+	#[inline]
+	fn is_match(self, thing: T) -> bool {
+		let hit = self.pat.dyn_is_match(thing);
+		if hit { self.changed.set(true); }
+		hit
+	}
+}
+
+
 #[cfg(test)]
 mod tests {
 	use super::*;
 
+	#[test]
+	fn trim_mut_and_shrink() {
+		for (policy, shrunk) in [
+			(ShrinkPolicy::Never, false),
+			(ShrinkPolicy::Always, true),
+			(ShrinkPolicy::Waste(50), true),
+			(ShrinkPolicy::Waste(100), false),
+		] {
+			let mut s = String::with_capacity(100);
+			s.push_str("  Hello World  ");
+			s.trim_mut_and_shrink(policy);
+			assert_eq!(s, "Hello World");
+			assert_eq!(s.capacity() == s.len(), shrunk, "{policy:?}");
+
+			let mut v = Vec::with_capacity(100);
+			v.extend_from_slice(b"  Hello World  ");
+			v.trim_mut_and_shrink(policy);
+			assert_eq!(v, b"Hello World");
+			assert_eq!(v.capacity() == v.len(), shrunk, "{policy:?}");
+		}
+	}
+
+	#[test]
+	fn truncate_utf8_mut() {
+		let raw = "a 🌎 Björk\u{3000}!";
+		for n in 0..=raw.len() + 1 {
+			let mut expected = raw.len().min(n);
+			while ! raw.is_char_boundary(expected) { expected -= 1; }
+			let expected = &raw[..expected];
+
+			let mut s = String::from(raw);
+			s.truncate_utf8_mut(n);
+			assert_eq!(s, expected, "{n}");
+
+			let mut v = raw.as_bytes().to_vec();
+			v.truncate_utf8_mut(n);
+			assert_eq!(v, expected.as_bytes(), "{n}");
+
+			let mut s = String::from(raw);
+			s.truncate_utf8_trim_mut(n);
+			assert_eq!(s, expected.trim_end(), "{n}");
+
+			let mut v = raw.as_bytes().to_vec();
+			v.truncate_utf8_trim_mut(n);
+			assert_eq!(v, expected.trim_end_matches(|c: char| c.is_ascii_whitespace()).as_bytes(), "{n}");
+		}
+
+		// Invalid UTF-8 only backs up so far.
+		let mut v = b"a\x80\x80\x80\x80\x80".to_vec();
+		v.truncate_utf8_mut(5);
+		assert_eq!(v, b"a\x80");
+	}
+
+	#[test]
+	fn trim_str_mut() {
+		for (raw, needle) in [
+			("", "ab"),
+			("abab", "ab"),
+			("ababa", "ab"),
+			("aab", "ab"),
+			("ab", ""),
+			("ÐÐxÐ", "Ð"),
+			("--x----", "--"),
+			("\r\n\r\nHello\r\n\n", "\r\n"),
+		] {
+			let mut s = String::from(raw);
+			s.trim_start_str_mut(needle);
+			assert_eq!(s, raw.trim_start_matches(needle), "{raw:?} {needle:?}");
+
+			let mut s = String::from(raw);
+			s.trim_end_str_mut(needle);
+			assert_eq!(s, raw.trim_end_matches(needle), "{raw:?} {needle:?}");
+		}
+
+		for (raw, sep, expected) in [
+			("", ",", ""),
+			(",", ",", ""),
+			(" , ", ", ", ""),
+			("a, b, c", ",", "a, b, c"),
+			("a, b, c, ", ",", "a, b, c"),
+			("a, b, c, ", ", ", "a, b, c"),
+			("a, b, c,", " , ", "a, b, c"),
+			("a, b, c ,\t\n", ",", "a, b, c"),
+			("a,,", ",", "a,"),
+			("a, b, c ", ",", "a, b, c "),
+			("a; b; c; ", ",", "a; b; c; "),
+			("a ", " ", "a "),
+			("a ", "", "a "),
+			("Björk • Sigur Rós • ", "•", "Björk • Sigur Rós"),
+			("a AND b AND", " AND ", "a AND b"),
+		] {
+			let mut s = String::from(raw);
+			s.trim_trailing_separator_mut(sep);
+			assert_eq!(s, expected, "{raw:?} {sep:?}");
+		}
+	}
+
 	#[test]
 	fn trim_str() {
 		use alloc::borrow::ToOwned;
@@ -1023,4 +2449,351 @@ mod tests {
 			assert_eq!(v2, v.trim_matches(|c| c == '\t'));
 		}
 	}
+
+	#[test]
+	fn trim_bytes_matches() {
+		for raw in [
+			&b""[..],
+			b"  ",
+			b"Hello",
+			b" Hello",
+			b"Hello ",
+			b" \t Hello World\n\n",
+			b"xHello Worldx",
+		] {
+			for pat in [&b" \t\n"[..], b"x", b"Hello World", b""] {
+				let mut v = raw.to_vec();
+				v.trim_matches_mut(pat);
+				assert_eq!(v, raw.trim_matches(pat), "{raw:?} {pat:?}");
+
+				let mut v = raw.to_vec();
+				v.trim_start_matches_mut(pat);
+				assert_eq!(v, raw.trim_start_matches(pat));
+
+				let mut v = raw.to_vec();
+				v.trim_end_matches_mut(pat);
+				assert_eq!(v, raw.trim_end_matches(pat));
+			}
+		}
+	}
+
+	#[test]
+	fn trim_generic_matches() {
+		use crate::Predicate;
+
+		let raw = [0_u64, 0, 3, 0, 4, 0];
+		let mut v = raw.to_vec();
+		v.trim_matches_mut(0);
+		assert_eq!(v, [3, 0, 4]);
+
+		let mut v = raw.to_vec();
+		v.trim_start_matches_mut(0);
+		assert_eq!(v, [3, 0, 4, 0]);
+		assert_eq!(v.trim_start_split_mut(Predicate(|n: u64| n > 0)), 1);
+
+		let mut v = raw.to_vec();
+		v.trim_end_matches_mut([0, 4]);
+		assert_eq!(v, [0, 0, 3]);
+
+		let mut v = Box::<[u64]>::from(raw);
+		v.trim_matches_mut(Predicate(|n: u64| n != 4));
+		assert_eq!(v.as_ref(), [4]);
+		v.trim_layers_mut(&[&4]);
+		assert!(v.is_empty());
+	}
+
+	#[test]
+	fn trim_end_keep_newline() {
+		for (raw, expected) in [
+			("", ""),
+			("\n", "\n"),
+			("\r\n", "\r\n"),
+			(" \t\n", "\n"),
+			("Hello \t", "Hello"),
+			("Hello \t\n", "Hello\n"),
+			("Hello \t\r\n", "Hello\r\n"),
+			("Hello \t\r", "Hello \t\r"),
+			("Hello \n\n", "Hello \n\n"),
+			("Hello \n \n", "Hello \n\n"),
+			("Hello\n", "Hello\n"),
+			("Hello \u{85}", "Hello \u{85}"),
+			("Hello \u{2028}", "Hello \u{2028}"),
+			("Hello \u{2029}", "Hello \u{2029}"),
+			("Hello\u{85} \n", "Hello\u{85}\n"),
+			("Hello\u{2028} \n", "Hello\u{2028}\n"),
+			("Hello\u{2029} \n", "Hello\u{2029}\n"),
+		] {
+			let mut v = String::from(raw);
+			v.trim_end_keep_newline_mut();
+			assert_eq!(v, expected);
+
+			let mut v = Cow::Borrowed(raw);
+			v.trim_end_keep_newline_mut();
+			assert_eq!(v, expected);
+
+			let mut v = raw.as_bytes().to_vec();
+			v.trim_end_keep_newline_mut();
+			assert_eq!(v, expected.as_bytes());
+
+			let mut v = Box::<[u8]>::from(raw.as_bytes());
+			v.trim_end_keep_newline_mut();
+			assert_eq!(&*v, expected.as_bytes());
+
+			let mut v = Cow::Borrowed(raw.as_bytes());
+			v.trim_end_keep_newline_mut();
+			assert_eq!(v.as_ref(), expected.as_bytes());
+		}
+
+		// Strings handle unicode whitespace too.
+		let mut v = String::from("Hello\u{2003}\n");
+		v.trim_end_keep_newline_mut();
+		assert_eq!(v, "Hello\n");
+	}
+
+	#[test]
+	fn trim_start_split() {
+		for raw in ["", " ", " \t\n", "Hello", " \tHello ", "\u{2003}Hello World"] {
+			let expected = raw.trim_start();
+
+			let mut v = String::from(raw);
+			let n = v.trim_start_split_mut(char::is_whitespace);
+			assert_eq!(v, raw);
+			assert_eq!(&v[n..], expected);
+
+			let mut v: Cow<str> = Cow::Owned(String::from(raw));
+			let n = v.trim_start_split_mut(char::is_whitespace);
+			assert_eq!(&v[n..], expected);
+
+			let mut v = Cow::Borrowed(raw);
+			let n = v.trim_start_split_mut(char::is_whitespace);
+			assert_eq!(v, raw);
+			assert_eq!(&v[n..], expected);
+
+			let expected = raw.as_bytes().trim_ascii_start();
+
+			let mut v = raw.as_bytes().to_vec();
+			let n = v.trim_start_split_mut(|b: u8| b.is_ascii_whitespace());
+			assert_eq!(v, raw.as_bytes());
+			assert_eq!(&v[n..], expected);
+
+			let mut v = Box::<[u8]>::from(raw.as_bytes());
+			let n = v.trim_start_split_mut(|b: u8| b.is_ascii_whitespace());
+			assert_eq!(&v[n..], expected);
+
+			let mut v = Cow::Borrowed(raw.as_bytes());
+			let n = v.trim_start_split_mut(|b: u8| b.is_ascii_whitespace());
+			assert_eq!(v.as_ref(), raw.as_bytes());
+			assert_eq!(&v[n..], expected);
+		}
+	}
+
+	#[test]
+	fn trim_start_split_default() {
+		/// # Eager Implementor.
+		struct Eager(String);
+		impl TrimMatchesMut for Eager {
+			type MatchUnit = char;
+			fn trim_matches_mut<P: MatchPattern<char>>(&mut self, pat: P) {
+				self.0.trim_matches_mut(pat);
+			}
+			fn trim_start_matches_mut<P: MatchPattern<char>>(&mut self, pat: P) {
+				self.0.trim_start_matches_mut(pat);
+			}
+			fn trim_end_matches_mut<P: MatchPattern<char>>(&mut self, pat: P) {
+				self.0.trim_end_matches_mut(pat);
+			}
+		}
+
+		// The default implementation trims eagerly.
+		let mut v = Eager(String::from("  Hello "));
+		let n = v.trim_start_split_mut(' ');
+		assert_eq!(n, 0);
+		assert_eq!(&v.0[n..], "Hello ");
+	}
+
+	#[test]
+	fn trim_layers() {
+		let ws = |c: char| c.is_whitespace();
+		let quote = |c: char| c == '"' || c == '\'';
+		let layers: [&dyn DynPattern<char>; 2] = [&ws, &quote];
+
+		let ascii_ws = |b: u8| b.is_ascii_whitespace();
+		let ascii_quote = |b: u8| b == b'"' || b == b'\'';
+		let byte_layers: [&dyn DynPattern<u8>; 2] = [&ascii_ws, &ascii_quote];
+
+		for (raw, expected) in [
+			("", ""),
+			("  ", ""),
+			("value", "value"),
+			("  \"  value \" ", "value"),
+			("' \" ' value ' \" '", "value"),
+			("\"inner \"quotes\" stay\"", "inner \"quotes\" stay"),
+			("\" \"", ""),
+		] {
+			let mut v = String::from(raw);
+			v.trim_layers_mut(&layers);
+			assert_eq!(v, expected, "{raw:?}");
+
+			let mut v: Cow<str> = Cow::Borrowed(raw);
+			v.trim_layers_mut(&layers);
+			assert_eq!(v, expected, "{raw:?}");
+			assert!(matches!(v, Cow::Borrowed(_)));
+
+			let mut v = raw.as_bytes().to_vec();
+			v.trim_layers_mut(&byte_layers);
+			assert_eq!(v, expected.as_bytes(), "{raw:?}");
+
+			let mut v = Box::<[u8]>::from(raw.as_bytes());
+			v.trim_layers_mut(&byte_layers);
+			assert_eq!(&*v, expected.as_bytes(), "{raw:?}");
+
+			let mut v = Cow::Borrowed(raw.as_bytes());
+			v.trim_layers_mut(&byte_layers);
+			assert_eq!(v.as_ref(), expected.as_bytes(), "{raw:?}");
+		}
+
+		// No layers, no changes.
+		let mut v = String::from(" Hello ");
+		v.trim_layers_mut(&[]);
+		assert_eq!(v, " Hello ");
+	}
+
+	#[test]
+	fn trim_repeat() {
+		let layers: [&dyn DynPattern<u8>; 2] = [&b' ', &b'"'];
+		for (max, expected, done) in [
+			(0, &b" \" \" value \" \" "[..], false),
+			(1, b" \" value \" ", false),
+			(2, b" value ", false),
+			(3, b"value", false),
+			(4, b"value", true),
+			(usize::MAX, b"value", true),
+		] {
+			let mut v = b" \" \" value \" \" ".to_vec();
+			assert_eq!(v.trim_repeat_mut(&layers, max), done, "{max}");
+			assert_eq!(v, expected, "{max}");
+		}
+
+		// Stable values need just the one pass.
+		let mut v = String::from("value");
+		assert!(v.trim_repeat_mut(&[&' '], 1));
+		assert!(! v.trim_repeat_mut(&[&' '], 0));
+	}
+
+	#[test]
+	fn trim_mut_min_len() {
+		for (raw, n, expected) in [
+			("", 1, ""),
+			("  ", 0, ""),
+			("  ", 1, " "),
+			("  ", 5, "  "),
+			("  Hello  ", 0, "Hello"),
+			("  Hello  ", 5, "Hello"),
+			("  Hello  ", 6, "Hello "),
+			("  Hello  ", 8, " Hello  "),
+			("  Hello  ", 9, "  Hello  "),
+			("\u{3000}Björk\u{3000}", 5, "Björk"),
+			("\u{3000}Björk\u{3000}", 6, "Björk\u{3000}"),
+		] {
+			let mut s = String::from(raw);
+			s.trim_mut_min_len(n);
+			assert_eq!(s, expected, "{raw:?} {n}");
+
+			// Bytes count differently, but ASCII is ASCII.
+			if raw.is_ascii() {
+				let mut v = raw.as_bytes().to_vec();
+				v.trim_mut_min_len(n);
+				assert_eq!(v, expected.as_bytes(), "{raw:?} {n}");
+			}
+		}
+
+		// Patterns.
+		let mut s = String::from("--Hello--");
+		s.trim_matches_mut_min_len('-', 6);
+		assert_eq!(s, "Hello-");
+		let mut v = b"--Hello--".to_vec();
+		v.trim_matches_mut_min_len(b'-', 1);
+		assert_eq!(v, b"Hello");
+	}
+
+	#[test]
+	fn try_trim_matches_mut() {
+		for (raw, limit, expected) in [
+			("", TrimLimit::Units(0), Ok("")),
+			("Hello", TrimLimit::Units(0), Ok("Hello")),
+			(" Hello ", TrimLimit::Units(2), Ok("Hello")),
+			(" Hello ", TrimLimit::Units(1), Err((2, 7))),
+			("   ", TrimLimit::Percent(99), Err((3, 3))),
+			("   ", TrimLimit::Percent(100), Ok("")),
+			("   ", TrimLimit::Percent(255), Ok("")),
+			("  Hi  ", TrimLimit::Percent(50), Err((4, 6))),
+			("  Hello  ", TrimLimit::Percent(50), Ok("Hello")),
+			("\u{3000}Björk\u{3000}", TrimLimit::Units(2), Ok("Björk")),
+		] {
+			let expected = expected.map_err(|(removed, total)| TooMuchTrimmed { removed, total });
+
+			let mut s = String::from(raw);
+			let res = s.try_trim_mut(limit);
+			assert_eq!(res, expected.map(|_| ()), "{raw:?}");
+			assert_eq!(s, expected.unwrap_or(raw), "{raw:?}");
+
+			if raw.is_ascii() {
+				let mut v = raw.as_bytes().to_vec();
+				let res = v.try_trim_mut(limit);
+				assert_eq!(res, expected.map(|_| ()), "{raw:?}");
+				assert_eq!(v, expected.unwrap_or(raw).as_bytes(), "{raw:?}");
+			}
+		}
+
+		// Errors should be readable.
+		let err = String::from("----").try_trim_matches_mut('-', TrimLimit::Units(1)).unwrap_err();
+		assert_eq!(alloc::format!("{err}"), "trimming would remove 4 of 4 units");
+	}
+
+	#[test]
+	fn trim_mut_deref() {
+		use core::cell::RefCell;
+
+		/// # Generic Trim.
+		fn trim<T: TrimMut>(mut value: T) { value.trim_mut(); }
+
+		/// # Generic Trim Matches.
+		fn trim_dashes<T: TrimMatchesMut<MatchUnit=char>>(mut value: T) {
+			value.trim_matches_mut('-');
+		}
+
+		// References.
+		let mut s = String::from(" Hello ");
+		trim(&mut s);
+		assert_eq!(s, "Hello");
+		let mut v = b" Hello ".to_vec();
+		trim(&mut &mut v);
+		assert_eq!(v, b"Hello");
+
+		// Boxes.
+		let mut b = Box::new(String::from(" Hello "));
+		b.trim_mut();
+		assert_eq!(*b, "Hello");
+		trim_dashes(Box::new(&mut s));
+
+		// Cells.
+		let cell = RefCell::new(String::from("--Hello--"));
+		trim_dashes(cell.borrow_mut());
+		assert_eq!(*cell.borrow(), "Hello");
+		cell.borrow_mut().trim_end_keep_newline_mut();
+		assert_eq!(*cell.borrow(), "Hello");
+
+		// Locks.
+		#[cfg(feature = "std")]
+		{
+			let lock = std::sync::Mutex::new(String::from("\t-Hello-\n"));
+			trim(lock.lock().unwrap());
+			trim_dashes(lock.lock().unwrap());
+			assert_eq!(*lock.lock().unwrap(), "Hello");
+
+			let lock = std::sync::RwLock::new(b" Hello ".to_vec());
+			lock.write().unwrap().trim_start_mut();
+			assert_eq!(*lock.read().unwrap(), b"Hello ");
+		}
+	}
 }
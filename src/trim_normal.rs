@@ -4,9 +4,13 @@
 
 use alloc::{
 	borrow::Cow,
+	boxed::Box,
 	string::String,
 	vec::Vec,
 };
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
+use core::iter::Copied;
 use crate::TrimMut;
 
 
@@ -14,13 +18,13 @@ use crate::TrimMut;
 /// # Trim and (Maybe) Normalize Whitespace.
 ///
 /// This trait adds a single `trim_and_normalize` method to owned and borrowed
-/// string and byte slices that trims leading/trailing whitespace, and
-/// compacts/normalizes spans of _inner_ whitespace to a single horizontal
+/// string, byte, and `char` slices that trims leading/trailing whitespace,
+/// and compacts/normalizes spans of _inner_ whitespace to a single horizontal
 /// space.
 ///
 /// In keeping with the rest of the library, "whitespace" here means
-/// [`char::is_whitespace`] for string sources, and [`u8::is_ascii_whitespace`]
-/// for byte sources.
+/// [`char::is_whitespace`] for string and `char` sources, and
+/// [`u8::is_ascii_whitespace`] for byte sources.
 ///
 /// ## Examples
 ///
@@ -66,6 +70,29 @@ pub trait TrimNormalChars<I: Iterator<Item=char>> {
 	/// Filter an `Iterator<Item=char>` to omit leading/trailing whitespace,
 	/// and reduce inner spans of whitespace to single horizontal spaces.
 	fn trim_and_normalize(self) -> TrimNormalIter<char, I>;
+
+	/// # Normalize Whitespace (Max): `char` Iterator Adapter.
+	///
+	/// Same as `trim_and_normalize`, except inner spans of whitespace are
+	/// reduced to _at most_ `max` horizontal spaces — shorter spans are
+	/// kept at their original length — so double-spaced sentences and
+	/// column-ish alignment survive while extreme runs are still cleaned
+	/// up.
+	///
+	/// A `max` of `1` is equivalent to `trim_and_normalize`; a `max` of `0`
+	/// removes inner whitespace altogether.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::TrimNormalChars;
+	///
+	/// let foo = " Hello.  World.\t\t\t\tBye.\n".chars()
+	///     .normalized_whitespace_max(2)
+	///     .collect::<String>();
+	/// assert_eq!(foo, "Hello.  World.  Bye.");
+	/// ```
+	fn normalized_whitespace_max(self, max: usize) -> TrimNormalMaxIter<char, I>;
 }
 
 impl<I: Iterator<Item=char>> TrimNormalChars<I> for I {
@@ -79,6 +106,94 @@ impl<I: Iterator<Item=char>> TrimNormalChars<I> for I {
 		let next = self.by_ref().find(|c| ! c.is_whitespace());
 		TrimNormalIter { iter: self, next }
 	}
+
+	#[inline]
+	/// # Normalize Whitespace (Max).
+	///
+	/// Filter an `Iterator<Item=char>` to omit leading/trailing whitespace,
+	/// and reduce inner spans of whitespace to at most `max` horizontal
+	/// spaces.
+	fn normalized_whitespace_max(mut self, max: usize) -> TrimNormalMaxIter<char, I> {
+		let next = self.by_ref().find(|c| ! c.is_whitespace());
+		TrimNormalMaxIter { iter: self, next, pending: 0, max }
+	}
+}
+
+impl<'a, I: Iterator<Item=&'a char>> TrimNormalChars<Copied<I>> for I {
+	#[inline]
+	/// # Trim and Normalize Whitespace.
+	///
+	/// Filter an `Iterator<Item=&char>` to omit leading/trailing whitespace,
+	/// and reduce inner spans of whitespace to single horizontal spaces.
+	///
+	/// This saves having to call `copied` first.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::TrimNormalChars;
+	///
+	/// let chars = [' ', 'H', '\t', '\t', 'I', ' '];
+	/// let foo = chars.iter().trim_and_normalize().collect::<String>();
+	/// assert_eq!(foo, "H I");
+	/// ```
+	fn trim_and_normalize(self) -> TrimNormalIter<char, Copied<I>> {
+		self.copied().trim_and_normalize()
+	}
+
+	#[inline]
+	/// # Normalize Whitespace (Max).
+	///
+	/// Filter an `Iterator<Item=&char>` to omit leading/trailing whitespace,
+	/// and reduce inner spans of whitespace to at most `max` horizontal
+	/// spaces.
+	fn normalized_whitespace_max(self, max: usize) -> TrimNormalMaxIter<char, Copied<I>> {
+		self.copied().normalized_whitespace_max(max)
+	}
+}
+
+
+
+/// # Trim and (Maybe) Normalize Whitespace: `(usize, char)` Iterator Adapter.
+///
+/// This trait provides the equivalent of [`TrimNormal`] for arbitrary
+/// iterators of `(usize, char)`, like [`str::char_indices`], preserving the
+/// original index of each character.
+///
+/// The horizontal space standing in for each span of inner whitespace is
+/// given the index of the first character of that span.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::TrimNormalCharIndices;
+///
+/// let foo = " H\r\nI!".char_indices()
+///     .trim_and_normalize()
+///     .collect::<Vec<_>>();
+/// assert_eq!(foo, [(1, 'H'), (2, ' '), (4, 'I'), (5, '!')]);
+/// ```
+pub trait TrimNormalCharIndices<I: Iterator<Item=(usize, char)>> {
+	/// # Trim and Normalize Whitespace: `(usize, char)` Iterator Adapter.
+	///
+	/// Filter an `Iterator<Item=(usize, char)>` to omit leading/trailing
+	/// whitespace, and reduce inner spans of whitespace to single horizontal
+	/// spaces.
+	fn trim_and_normalize(self) -> TrimNormalIter<(usize, char), I>;
+}
+
+impl<I: Iterator<Item=(usize, char)>> TrimNormalCharIndices<I> for I {
+	#[inline]
+	/// # Trim and Normalize Whitespace.
+	///
+	/// Filter an `Iterator<Item=(usize, char)>` to omit leading/trailing
+	/// whitespace, and reduce inner spans of whitespace to single horizontal
+	/// spaces.
+	fn trim_and_normalize(mut self) -> TrimNormalIter<(usize, char), I> {
+		// We can trim the start before, er, starting.
+		let next = self.by_ref().find(|(_, c)| ! c.is_whitespace());
+		TrimNormalIter { iter: self, next }
+	}
 }
 
 
@@ -105,6 +220,25 @@ pub trait TrimNormalBytes<I: Iterator<Item=u8>> {
 	/// Filter an `Iterator<Item=u8>` to omit leading/trailing whitespace,
 	/// and reduce inner spans of whitespace to single horizontal spaces.
 	fn trim_and_normalize(self) -> TrimNormalIter<u8, I>;
+
+	/// # Normalize Whitespace (Max): `u8` Iterator Adapter.
+	///
+	/// Same as `trim_and_normalize`, except inner spans of whitespace are
+	/// reduced to _at most_ `max` horizontal spaces.
+	///
+	/// See [`TrimNormalChars::normalized_whitespace_max`] for more details.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::TrimNormalBytes;
+	///
+	/// let foo = b"Name:\t\t\tBjork\r\nAge:  50 ".iter()
+	///     .normalized_whitespace_max(2)
+	///     .collect::<Vec<u8>>();
+	/// assert_eq!(foo, b"Name:  Bjork  Age:  50");
+	/// ```
+	fn normalized_whitespace_max(self, max: usize) -> TrimNormalMaxIter<u8, I>;
 }
 
 impl<I: Iterator<Item=u8>> TrimNormalBytes<I> for I {
@@ -118,15 +252,61 @@ impl<I: Iterator<Item=u8>> TrimNormalBytes<I> for I {
 		let next = self.by_ref().find(|c| ! c.is_ascii_whitespace());
 		TrimNormalIter { iter: self, next }
 	}
+
+	#[inline]
+	/// # Normalize Whitespace (Max).
+	///
+	/// Filter an `Iterator<Item=u8>` to omit leading/trailing whitespace,
+	/// and reduce inner spans of whitespace to at most `max` horizontal
+	/// spaces.
+	fn normalized_whitespace_max(mut self, max: usize) -> TrimNormalMaxIter<u8, I> {
+		let next = self.by_ref().find(|c| ! c.is_ascii_whitespace());
+		TrimNormalMaxIter { iter: self, next, pending: 0, max }
+	}
+}
+
+impl<'a, I: Iterator<Item=&'a u8>> TrimNormalBytes<Copied<I>> for I {
+	#[inline]
+	/// # Trim and Normalize Whitespace.
+	///
+	/// Filter an `Iterator<Item=&u8>` to omit leading/trailing whitespace,
+	/// and reduce inner spans of whitespace to single horizontal spaces.
+	///
+	/// This saves having to call `copied` first.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::TrimNormalBytes;
+	///
+	/// let foo = b" H E  L\r\nL O\n".iter()
+	///     .trim_and_normalize()
+	///     .collect::<Vec<u8>>();
+	/// assert_eq!(foo, b"H E L L O");
+	/// ```
+	fn trim_and_normalize(self) -> TrimNormalIter<u8, Copied<I>> {
+		self.copied().trim_and_normalize()
+	}
+
+	#[inline]
+	/// # Normalize Whitespace (Max).
+	///
+	/// Filter an `Iterator<Item=&u8>` to omit leading/trailing whitespace,
+	/// and reduce inner spans of whitespace to at most `max` horizontal
+	/// spaces.
+	fn normalized_whitespace_max(self, max: usize) -> TrimNormalMaxIter<u8, Copied<I>> {
+		self.copied().normalized_whitespace_max(max)
+	}
 }
 
 
 
 #[derive(Debug, Clone)]
-/// # Iterator for [`TrimNormalBytes`] and [`TrimNormalChars`].
+/// # Iterator for [`TrimNormalBytes`], [`TrimNormalChars`], and [`TrimNormalCharIndices`].
 ///
-/// This struct is yielded by [`TrimNormalBytes::trim_and_normalize`] and
-/// [`TrimNormalChars::trim_and_normalize`].
+/// This struct is yielded by [`TrimNormalBytes::trim_and_normalize`],
+/// [`TrimNormalChars::trim_and_normalize`], and
+/// [`TrimNormalCharIndices::trim_and_normalize`].
 ///
 /// Refer to their documentation for more details.
 pub struct TrimNormalIter<T: Copy + Sized, I: Iterator<Item=T>> {
@@ -180,6 +360,117 @@ iter!(u8, b' ', is_ascii_whitespace);
 
 
 
+#[derive(Debug, Clone)]
+/// # Iterator for [`TrimNormalBytes`] and [`TrimNormalChars`] (Max).
+///
+/// This struct is yielded by [`TrimNormalBytes::normalized_whitespace_max`]
+/// and [`TrimNormalChars::normalized_whitespace_max`].
+///
+/// Refer to their documentation for more details.
+pub struct TrimNormalMaxIter<T: Copy + Sized, I: Iterator<Item=T>> {
+	/// # The Iterator.
+	iter: I,
+
+	/// # Next Buffer.
+	///
+	/// The first non-whitespace value following a run, held back until its
+	/// spaces have been emitted.
+	next: Option<T>,
+
+	/// # Pending Spaces.
+	pending: usize,
+
+	/// # Max Run Length.
+	max: usize,
+}
+
+/// # Helper: Iteration (Max).
+macro_rules! iter_max {
+	($ty:ty, $space:literal, $cmp:ident) => (
+		impl<I: Iterator<Item=$ty>> Iterator for TrimNormalMaxIter<$ty, I> {
+			type Item = $ty;
+
+			fn next(&mut self) -> Option<Self::Item> {
+				// Finish the current run, if any.
+				if self.pending != 0 {
+					self.pending -= 1;
+					return Some($space);
+				}
+
+				// If we have something in the buffer, return it.
+				if let Some(next) = self.next.take() { return Some(next); }
+
+				// Pull the next thing.
+				let next = self.iter.next()?;
+
+				// Normalization required?
+				if next.$cmp() {
+					// Fast-forward to the next non-whitespace, counting as
+					// we go.
+					let mut len = 1_usize;
+					self.next = loop {
+						match self.iter.next() {
+							Some(c) if c.$cmp() => { len = len.saturating_add(1); },
+							next => break next,
+						}
+					};
+
+					// Trailing whitespace gets dropped.
+					self.next?;
+
+					let len = len.min(self.max);
+					if len == 0 { self.next.take() }
+					else {
+						self.pending = len - 1;
+						Some($space)
+					}
+				}
+				// Return it as-is.
+				else { Some(next) }
+			}
+
+			fn size_hint(&self) -> (usize, Option<usize>) {
+				let lower = usize::from(self.next.is_some()) + self.pending; // Definitely.
+				let (_, upper) = self.iter.size_hint();                      // Maybe.
+				(lower, upper.and_then(|n| n.checked_add(lower)))
+			}
+		}
+	);
+}
+
+iter_max!(char, ' ', is_whitespace);
+iter_max!(u8, b' ', is_ascii_whitespace);
+
+impl<I: Iterator<Item=(usize, char)>> Iterator for TrimNormalIter<(usize, char), I> {
+	type Item = (usize, char);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		// If we have something in the buffer, return it.
+		if let Some(next) = self.next.take() { return Some(next); }
+
+		// Pull the next thing.
+		let (idx, c) = self.iter.next()?;
+
+		// Normalization required?
+		if c.is_whitespace() {
+			// Fast-forward to the next non-whitespace.
+			self.next = self.iter.by_ref().find(|(_, c)| ! c.is_whitespace());
+			if self.next.is_some() { Some((idx, ' ')) }
+			else { None }
+		}
+		// Return it as-is.
+		else { Some((idx, c)) }
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let lower = usize::from(self.next.is_some()); // Definitely.
+		let (_, upper) = self.iter.size_hint();       // Maybe.
+		(lower, upper.map(|n| n + lower))
+	}
+}
+
+
+
 impl<'a> TrimNormal for &'a str {
 	/// # Output Type.
 	type Normalized = Cow<'a, str>;
@@ -265,6 +556,12 @@ impl<'a> TrimNormal for &'a str {
 				));
 
 				// Done!
+				trace_debug!(
+					"trim_and_normalize: rebuilt string",
+					before = self.len(),
+					after = out.len(),
+					removed = self.len() - out.len(),
+				);
 				return Cow::Owned(out);
 			}
 
@@ -303,7 +600,10 @@ impl TrimNormal for Cow<'_, str> {
 	fn trim_and_normalize(self) -> Self::Normalized {
 		match self {
 			Cow::Borrowed(s) => s.trim_and_normalize(),
-			Cow::Owned(s) => Cow::Owned(s.trim_and_normalize()),
+			// Already-clean owned values can be passed straight through.
+			Cow::Owned(s) =>
+				if is_normalized_str(&s) { Cow::Owned(s) }
+				else { Cow::Owned(s.trim_and_normalize()) },
 		}
 	}
 }
@@ -317,6 +617,10 @@ impl TrimNormal for &mut String {
 	/// Trim the leading/trailing whitespace, and compact/normalize spans of
 	/// _inner_ whitespace to a single horizontal space.
 	///
+	/// The work is done entirely in place; no allocations are made, and the
+	/// capacity is left unchanged. See [`TrimNormalFit`] if you'd rather
+	/// shrink it afterward.
+	///
 	/// ## Examples
 	///
 	/// ```
@@ -337,51 +641,66 @@ impl TrimNormal for &mut String {
 	/// assert_eq!(abnormal, "H E L L O");
 	/// ```
 	fn trim_and_normalize(self) -> Self::Normalized {
-		// Trim the trailing whitespace.
+		// Swap out any non-space whitespace with an equal number of spaces.
+		// Same-length replacements never reallocate or shift the tail.
+		let mut from = 0;
+		while let Some((pos, len)) = self[from..].char_indices().find_map(|(idx, c)|
+			if c != ' ' && c.is_whitespace() { Some((from + idx, c.len_utf8())) }
+			else { None }
+		) {
+			self.replace_range(pos..pos + len, &"   "[..len]);
+			from = pos + len;
+		}
+
+		// Trim the end.
 		self.trim_end_mut();
 
-		// Now trim the beginning and inner whitespace.
+		// Now trim the beginning and collapse the inner spaces.
 		let mut ws = true;
-		let mut other = 0;
 		self.retain(|v|
-			if v.is_whitespace() {
-				if ws { false }
-				else {
-					ws = true;
-					if v != ' ' { other += 1; } // We'll need a second pass.
-					true
-				}
-			}
+			if v == ' ' { ! core::mem::replace(&mut ws, true) }
 			else {
 				ws = false;
 				true
 			}
 		);
 
-		// If any non-space whitespace remains, we'll need to loop back through
-		// and swap them out with regular spaces.
-		let mut end = self.len();
-		while 0 < other {
-			let mut len = 0;
-			if let Some(pos) = self[..end].rfind(|c: char|
-				if c.is_whitespace() && c != ' ' {
-					len = c.len_utf8(); // Number of bytes to replace.
-					true
-				}
-				else { false }
-			) {
-				self.replace_range(pos..pos + len, " ");
-				end = pos; // Don't retread parts we've already looked at.
-				other -= 1;
-			}
-			else { break; }
-		}
-
 		// Done!
 		self
 	}
 }
 
+impl TrimNormal for &mut Box<str> {
+	/// # Output Type.
+	type Normalized = Self;
+
+	/// # Trim and Normalize Whitespace.
+	///
+	/// Trim the leading/trailing whitespace, and compact/normalize spans of
+	/// _inner_ whitespace to a single horizontal space.
+	///
+	/// The work is done in place, reusing the existing allocation (though
+	/// it may need to be shrunk afterward).
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::TrimNormal;
+	///
+	/// let mut abnormal: Box<str> = Box::from(" H\r\nE\u{2001}L  L\tO  ");
+	/// (&mut abnormal).trim_and_normalize();
+	/// assert_eq!(&*abnormal, "H E L L O");
+	/// ```
+	fn trim_and_normalize(self) -> Self::Normalized {
+		// Boxes and their owned counterparts share the same allocation, so
+		// the round trip is free unless the length changes.
+		let mut tmp = core::mem::take(self).into_string();
+		<&mut String as TrimNormal>::trim_and_normalize(&mut tmp);
+		*self = tmp.into_boxed_str();
+		self
+	}
+}
+
 impl<'a> TrimNormal for &'a String {
 	/// # Output Type.
 	type Normalized = Cow<'a, str>;
@@ -527,6 +846,12 @@ impl<'a> TrimNormal for &'a [u8] {
 				));
 
 				// Done!
+				trace_debug!(
+					"trim_and_normalize: rebuilt bytes",
+					before = self.len(),
+					after = out.len(),
+					removed = self.len() - out.len(),
+				);
 				return Cow::Owned(out);
 			}
 
@@ -566,7 +891,10 @@ impl TrimNormal for Cow<'_, [u8]> {
 	fn trim_and_normalize(self) -> Self::Normalized {
 		match self {
 			Cow::Borrowed(s) => s.trim_and_normalize(),
-			Cow::Owned(s) => Cow::Owned(s.trim_and_normalize()),
+			// Already-clean owned values can be passed straight through.
+			Cow::Owned(s) =>
+				if is_normalized_bytes(&s) { Cow::Owned(s) }
+				else { Cow::Owned(s.trim_and_normalize()) },
 		}
 	}
 }
@@ -580,6 +908,10 @@ impl TrimNormal for &mut Vec<u8> {
 	/// Trim the leading/trailing whitespace, and compact/normalize spans of
 	/// _inner_ whitespace to a single horizontal space.
 	///
+	/// The work is done entirely in place; no allocations are made, and the
+	/// capacity is left unchanged. See [`TrimNormalFit`] if you'd rather
+	/// shrink it afterward.
+	///
 	/// ## Examples
 	///
 	/// ```
@@ -624,6 +956,37 @@ impl TrimNormal for &mut Vec<u8> {
 	}
 }
 
+impl TrimNormal for &mut Box<[u8]> {
+	/// # Output Type.
+	type Normalized = Self;
+
+	/// # Trim and Normalize Whitespace.
+	///
+	/// Trim the leading/trailing whitespace, and compact/normalize spans of
+	/// _inner_ whitespace to a single horizontal space.
+	///
+	/// The work is done in place, reusing the existing allocation (though
+	/// it may need to be shrunk afterward).
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::TrimNormal;
+	///
+	/// let mut abnormal: Box<[u8]> = Box::from(&b" H\r\nE L  \nL\tO  "[..]);
+	/// (&mut abnormal).trim_and_normalize();
+	/// assert_eq!(&*abnormal, b"H E L L O");
+	/// ```
+	fn trim_and_normalize(self) -> Self::Normalized {
+		// Boxes and their owned counterparts share the same allocation, so
+		// the round trip is free unless the length changes.
+		let mut tmp = core::mem::take(self).into_vec();
+		<&mut Vec<u8> as TrimNormal>::trim_and_normalize(&mut tmp);
+		*self = tmp.into_boxed_slice();
+		self
+	}
+}
+
 impl TrimNormal for Vec<u8> {
 	/// # Output Type.
 	type Normalized = Self;
@@ -654,6 +1017,347 @@ impl TrimNormal for Vec<u8> {
 	}
 }
 
+impl<'a> TrimNormal for &'a [char] {
+	/// # Output Type.
+	type Normalized = Cow<'a, [char]>;
+
+	/// # Trim and Normalize Whitespace.
+	///
+	/// Trim the leading/trailing whitespace, and compact/normalize spans of
+	/// _inner_ whitespace to a single horizontal space.
+	///
+	/// As with string slices, "whitespace" here means [`char::is_whitespace`].
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// # extern crate alloc;
+	/// # use alloc::borrow::Cow;
+	/// use trimothy::TrimNormal;
+	///
+	/// const ABNORMAL: &[char] = &[' ', 'H', '\u{3000}', '\t', 'I', '\n'];
+	///
+	/// assert_eq!(ABNORMAL.trim_and_normalize().as_ref(), &['H', ' ', 'I']);
+	///
+	/// // Collect into a String, if you prefer.
+	/// let s: String = ABNORMAL.trim_and_normalize().iter().collect();
+	/// assert_eq!(s, "H I");
+	///
+	/// // No allocation is needed if only the edges need trimming.
+	/// assert!(matches!(
+	///     [' ', 'H', ' ', 'I', '\n'][..].trim_and_normalize(),
+	///     Cow::Borrowed(_),
+	/// ));
+	/// ```
+	fn trim_and_normalize(self) -> Self::Normalized {
+		// Trim leading/trailing whitespace to make life easier on ourselves.
+		let start = self.iter().position(|c| ! c.is_whitespace()).unwrap_or(self.len());
+		let end = self.iter().rposition(|c| ! c.is_whitespace()).map_or(start, |e| e + 1);
+		let src = &self[start..end];
+
+		if is_normalized_chars(src) { Cow::Borrowed(src) }
+		else { Cow::Owned(src.iter().trim_and_normalize().collect()) }
+	}
+}
+
+impl TrimNormal for &mut Vec<char> {
+	/// # Output Type.
+	type Normalized = Self;
+
+	/// # Trim and Normalize Whitespace.
+	///
+	/// Trim the leading/trailing whitespace, and compact/normalize spans of
+	/// _inner_ whitespace to a single horizontal space.
+	///
+	/// The work is done entirely in place; no allocations are made, and the
+	/// capacity is left unchanged.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::TrimNormal;
+	///
+	/// let mut abnormal: Vec<char> = " H\r\nE L  \u{3000}L\tO  ".chars().collect();
+	/// (&mut abnormal).trim_and_normalize();
+	/// assert_eq!(abnormal, ['H', ' ', 'E', ' ', 'L', ' ', 'L', ' ', 'O']);
+	/// ```
+	fn trim_and_normalize(self) -> Self::Normalized {
+		// Trim the beginning and normalize the rest.
+		let mut ws = true;
+		self.retain_mut(|v|
+			if v.is_whitespace() {
+				if ws { false }
+				else {
+					ws = true;
+					*v = ' ';
+					true
+				}
+			}
+			else {
+				ws = false;
+				true
+			}
+		);
+
+		// Trim the end, if needed. (There can be at most one.)
+		if ws { self.pop(); }
+
+		self
+	}
+}
+
+impl TrimNormal for Vec<char> {
+	/// # Output Type.
+	type Normalized = Self;
+
+	#[inline]
+	/// # Trim and Normalize Whitespace.
+	///
+	/// Trim the leading/trailing whitespace, and compact/normalize spans of
+	/// _inner_ whitespace to a single horizontal space.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::TrimNormal;
+	///
+	/// let abnormal = vec![' ', 'H', '\t', '\t', 'I', ' '];
+	/// assert_eq!(abnormal.trim_and_normalize(), ['H', ' ', 'I']);
+	/// ```
+	fn trim_and_normalize(mut self) -> Self::Normalized {
+		<&mut Self as TrimNormal>::trim_and_normalize(&mut self);
+		self
+	}
+}
+
+impl<const N: usize> TrimNormal for [char; N] {
+	/// # Output Type.
+	type Normalized = Vec<char>;
+
+	#[inline]
+	/// # Trim and Normalize Whitespace.
+	///
+	/// Trim the leading/trailing whitespace, and compact/normalize spans of
+	/// _inner_ whitespace to a single horizontal space.
+	///
+	/// Fixed-size arrays can't shrink, so the result is returned as a new
+	/// `Vec<char>`.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::TrimNormal;
+	///
+	/// // A fixed-width, space-padded text field.
+	/// let field = ['H', 'I', ' ', ' ', ' ', ' '];
+	/// assert_eq!(field.trim_and_normalize(), ['H', 'I']);
+	/// ```
+	fn trim_and_normalize(self) -> Self::Normalized {
+		self.as_slice().trim_and_normalize().into_owned()
+	}
+}
+
+
+
+/// # Trim and Normalize Whitespace, Then Shrink.
+///
+/// This trait adds a `trim_and_normalize_fit` method to `String` and
+/// `Vec<u8>` that works exactly like their (mutable) [`TrimNormal`]
+/// counterparts, except any excess capacity is released afterward.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::{TrimNormal, TrimNormalFit};
+///
+/// // In-place normalization never reallocates, so capacity sticks
+/// // around.
+/// let mut s = String::with_capacity(64);
+/// s.push_str("  Hello\u{3000}\u{3000}World  ");
+/// (&mut s).trim_and_normalize();
+/// assert_eq!(s, "Hello World");
+/// assert_eq!(s.capacity(), 64);
+///
+/// // Unless you ask for it not to.
+/// s.trim_and_normalize_fit();
+/// assert_eq!(s, "Hello World");
+/// assert_eq!(s.capacity(), s.len());
+/// ```
+pub trait TrimNormalFit {
+	/// # Trim and Normalize Whitespace, Then Shrink.
+	///
+	/// Trim the leading/trailing whitespace, compact/normalize spans of
+	/// _inner_ whitespace to a single horizontal space, then shrink the
+	/// capacity to fit.
+	fn trim_and_normalize_fit(&mut self);
+}
+
+impl TrimNormalFit for String {
+	#[inline]
+	fn trim_and_normalize_fit(&mut self) {
+		self.trim_and_normalize().shrink_to_fit();
+	}
+}
+
+impl TrimNormalFit for Vec<u8> {
+	#[inline]
+	fn trim_and_normalize_fit(&mut self) {
+		self.trim_and_normalize().shrink_to_fit();
+	}
+}
+
+
+
+/// # Trim and Normalize Whitespace, Into a Box (or Arc).
+///
+/// This trait adds `trim_and_normalize_boxed` and `trim_and_normalize_arc`
+/// finishing variants to owned and borrowed string and byte slices, producing
+/// tightly-sized `Box<str>`/`Box<[u8]>` and `Arc<str>`/`Arc<[u8]>` values
+/// directly — handy for caches storing large numbers of normalized
+/// snippets — without any excess capacity hanging around.
+///
+/// Clean borrowed sources are copied straight into their final home, with no
+/// intermediate `String` or `Vec`.
+///
+/// The `Arc` variant is only available on targets with pointer-sized
+/// atomics.
+///
+/// ## Examples
+///
+/// ```
+/// use std::sync::Arc;
+/// use trimothy::TrimNormalBoxed;
+///
+/// let boxed: Box<str> = " Hello\t\tWorld ".trim_and_normalize_boxed();
+/// assert_eq!(&*boxed, "Hello World");
+///
+/// let mut s = String::with_capacity(64);
+/// s.push_str(" Hello\t\tWorld ");
+/// let arc: Arc<str> = s.trim_and_normalize_arc();
+/// assert_eq!(&*arc, "Hello World");
+///
+/// let boxed: Box<[u8]> = b" Hello\t\tWorld ".as_slice().trim_and_normalize_boxed();
+/// assert_eq!(&*boxed, b"Hello World");
+/// ```
+pub trait TrimNormalBoxed {
+	/// # Unboxed Type.
+	///
+	/// The unsized contents of the box, i.e. `str` or `[u8]`.
+	type Unboxed: ?Sized;
+
+	/// # Trim and Normalize Whitespace, Into a Box.
+	///
+	/// Trim the leading/trailing whitespace, compact/normalize spans of
+	/// _inner_ whitespace to a single horizontal space, and return the result
+	/// as a tightly-sized box.
+	fn trim_and_normalize_boxed(self) -> Box<Self::Unboxed>;
+
+	#[cfg(target_has_atomic = "ptr")]
+	/// # Trim and Normalize Whitespace, Into an Arc.
+	///
+	/// Same as `trim_and_normalize_boxed`, but returns an `Arc`.
+	fn trim_and_normalize_arc(self) -> Arc<Self::Unboxed>;
+}
+
+/// # Helper: Boxed Implementations.
+macro_rules! boxed {
+	($unboxed:ty: $($ty:ty),+ $(,)?) => ($(
+		impl TrimNormalBoxed for $ty {
+			/// # Unboxed Type.
+			type Unboxed = $unboxed;
+
+			#[inline]
+			/// # Trim and Normalize Whitespace, Into a Box.
+			fn trim_and_normalize_boxed(self) -> Box<$unboxed> {
+				Box::from(self.trim_and_normalize())
+			}
+
+			#[cfg(target_has_atomic = "ptr")]
+			#[inline]
+			/// # Trim and Normalize Whitespace, Into an Arc.
+			fn trim_and_normalize_arc(self) -> Arc<$unboxed> {
+				Arc::from(self.trim_and_normalize())
+			}
+		}
+	)+);
+}
+
+boxed!(str: &str, &String, String, Cow<'_, str>);
+boxed!([u8]: &[u8], Vec<u8>, Cow<'_, [u8]>);
+
+
+
+/// # Is Normalized (Bytes)?
+///
+/// Returns `true` if the slice has no leading/trailing whitespace, and no
+/// inner whitespace other than lone horizontal spaces, i.e. if
+/// normalization would leave it unchanged.
+const fn is_normalized_bytes(mut src: &[u8]) -> bool {
+	// Edges.
+	if let [a, .., b] | [a @ b] = src {
+		if a.is_ascii_whitespace() || b.is_ascii_whitespace() { return false; }
+	}
+
+	// Innards.
+	let mut ws = false;
+	while let [b, rest @ ..] = src {
+		if b.is_ascii_whitespace() {
+			if ws || *b != b' ' { return false; }
+			ws = true;
+		}
+		else { ws = false; }
+		src = rest;
+	}
+
+	true
+}
+
+/// # Is Normalized (Chars)?
+///
+/// Returns `true` if the slice has no leading/trailing whitespace, and no
+/// inner whitespace other than lone horizontal spaces, i.e. if
+/// normalization would leave it unchanged.
+fn is_normalized_chars(src: &[char]) -> bool {
+	// Edges.
+	if let [a, .., b] | [a @ b] = src {
+		if a.is_whitespace() || b.is_whitespace() { return false; }
+	}
+
+	// Innards.
+	src.windows(2).all(|pair|
+		! pair[1].is_whitespace() ||
+		(pair[1] == ' ' && ! pair[0].is_whitespace())
+	)
+}
+
+/// # Is Normalized (String)?
+///
+/// Returns `true` if the string has no leading/trailing whitespace, and no
+/// inner whitespace other than lone horizontal spaces, i.e. if
+/// normalization would leave it unchanged.
+fn is_normalized_str(src: &str) -> bool {
+	// Edges.
+	if src.starts_with(char::is_whitespace) || src.ends_with(char::is_whitespace) {
+		return false;
+	}
+
+	// Innards.
+	let mut ws = false;
+	src.chars().all(|c|
+		if c.is_whitespace() {
+			if ws || c != ' ' { false }
+			else {
+				ws = true;
+				true
+			}
+		}
+		else {
+			ws = false;
+			true
+		}
+	)
+}
+
 
 
 #[cfg(test)]
@@ -685,12 +1389,43 @@ mod test {
 			let normal: Vec<u8> = raw.as_bytes().to_vec().trim_and_normalize();
 			assert_eq!(normal, expected.as_bytes());
 
+			let mut normal: Box<str> = Box::from(raw);
+			(&mut normal).trim_and_normalize();
+			assert_eq!(&*normal, expected);
+
+			let mut normal: Box<[u8]> = Box::from(raw.as_bytes());
+			(&mut normal).trim_and_normalize();
+			assert_eq!(&*normal, expected.as_bytes());
+
 			// Test the iterators too.
 			let normal: String = raw.chars().trim_and_normalize().collect();
 			assert_eq!(normal, expected);
 
+			let normal: String = raw.char_indices()
+				.trim_and_normalize()
+				.map(|(idx, c)| {
+					assert!(raw[idx..].starts_with(c) || raw[idx..].starts_with(char::is_whitespace));
+					c
+				})
+				.collect();
+			assert_eq!(normal, expected);
+
 			let normal: Vec<u8> = raw.bytes().trim_and_normalize().collect();
 			assert_eq!(normal, expected.as_bytes());
+
+			let normal: Vec<u8> = raw.as_bytes().iter().trim_and_normalize().collect();
+			assert_eq!(normal, expected.as_bytes());
+
+			let chars: Vec<char> = raw.chars().collect();
+			let normal: String = chars.iter().trim_and_normalize().collect();
+			assert_eq!(normal, expected);
+
+			// &[char].
+			let expected_chars: Vec<char> = expected.chars().collect();
+			let normal = chars.as_slice().trim_and_normalize();
+			assert_eq!(normal, expected_chars);
+			assert!(matches!(normal, Cow::Borrowed(_)));
+			assert_eq!(chars.trim_and_normalize(), expected_chars);
 		}
 
 		// Strings check a bit more.
@@ -707,6 +1442,11 @@ mod test {
 			let normal: String = String::from(raw).trim_and_normalize();
 			assert_eq!(normal, expected);
 
+			// Box<str>.
+			let mut normal: Box<str> = Box::from(raw);
+			(&mut normal).trim_and_normalize();
+			assert_eq!(&*normal, expected);
+
 			// Iterator.
 			let normal: String = raw.chars().trim_and_normalize().collect();
 			assert_eq!(normal, expected);
@@ -754,12 +1494,43 @@ mod test {
 			let normal: Vec<u8> = raw.as_bytes().to_vec().trim_and_normalize();
 			assert_eq!(normal, expected.as_bytes());
 
+			let mut normal: Box<str> = Box::from(raw);
+			(&mut normal).trim_and_normalize();
+			assert_eq!(&*normal, expected);
+
+			let mut normal: Box<[u8]> = Box::from(raw.as_bytes());
+			(&mut normal).trim_and_normalize();
+			assert_eq!(&*normal, expected.as_bytes());
+
 			// Test the iterators too.
 			let normal: String = raw.chars().trim_and_normalize().collect();
 			assert_eq!(normal, expected);
 
+			let normal: String = raw.char_indices()
+				.trim_and_normalize()
+				.map(|(idx, c)| {
+					assert!(raw[idx..].starts_with(c) || raw[idx..].starts_with(char::is_whitespace));
+					c
+				})
+				.collect();
+			assert_eq!(normal, expected);
+
 			let normal: Vec<u8> = raw.bytes().trim_and_normalize().collect();
 			assert_eq!(normal, expected.as_bytes());
+
+			let normal: Vec<u8> = raw.as_bytes().iter().trim_and_normalize().collect();
+			assert_eq!(normal, expected.as_bytes());
+
+			let chars: Vec<char> = raw.chars().collect();
+			let normal: String = chars.iter().trim_and_normalize().collect();
+			assert_eq!(normal, expected);
+
+			// &[char].
+			let expected_chars: Vec<char> = expected.chars().collect();
+			let normal = chars.as_slice().trim_and_normalize();
+			assert_eq!(normal, expected_chars);
+			assert!(matches!(normal, Cow::Owned(_)));
+			assert_eq!(chars.trim_and_normalize(), expected_chars);
 		}
 
 		// Strings check a bit more.
@@ -776,9 +1547,182 @@ mod test {
 			let normal: String = String::from(raw).trim_and_normalize();
 			assert_eq!(normal, expected);
 
+			// Box<str>.
+			let mut normal: Box<str> = Box::from(raw);
+			(&mut normal).trim_and_normalize();
+			assert_eq!(&*normal, expected);
+
 			// Iterator.
 			let normal: String = raw.chars().trim_and_normalize().collect();
 			assert_eq!(normal, expected);
 		}
 	}
+
+	#[test]
+	fn trim_and_normalize_in_place() {
+		for raw in [
+			"", " ", "\u{3000}", "Hello", "  Hello  ", "H\u{2003}\u{2003}I",
+			"\u{2003}\u{2003}HEL\u{2003} LO\r\u{2003}", "H\tE  L\n\rL\x0CO ",
+			" Björk\u{85}Guðmundsdóttir\u{a0}",
+		] {
+			let expected = raw.trim_and_normalize();
+
+			// Strings.
+			let mut s = String::with_capacity(64);
+			s.push_str(raw);
+			let ptr = s.as_ptr();
+			(&mut s).trim_and_normalize();
+			assert_eq!(s, expected, "{raw:?}");
+			assert_eq!(s.as_ptr(), ptr, "{raw:?}");
+			assert_eq!(s.capacity(), 64, "{raw:?}");
+
+			s.trim_and_normalize_fit();
+			assert_eq!(s, expected, "{raw:?}");
+			assert_eq!(s.capacity(), s.len(), "{raw:?}");
+
+			// Bytes.
+			let expected = raw.as_bytes().trim_and_normalize();
+			let mut v = Vec::with_capacity(64);
+			v.extend_from_slice(raw.as_bytes());
+			let ptr = v.as_ptr();
+			(&mut v).trim_and_normalize();
+			assert_eq!(v, *expected, "{raw:?}");
+			assert_eq!(v.as_ptr(), ptr, "{raw:?}");
+			assert_eq!(v.capacity(), 64, "{raw:?}");
+
+			v.trim_and_normalize_fit();
+			assert_eq!(v, *expected, "{raw:?}");
+			assert_eq!(v.capacity(), v.len(), "{raw:?}");
+
+			// Chars.
+			let expected: Vec<char> = raw.trim_and_normalize().chars().collect();
+			let mut v: Vec<char> = Vec::with_capacity(64);
+			v.extend(raw.chars());
+			let ptr = v.as_ptr();
+			(&mut v).trim_and_normalize();
+			assert_eq!(v, expected, "{raw:?}");
+			assert_eq!(v.as_ptr(), ptr, "{raw:?}");
+			assert_eq!(v.capacity(), 64, "{raw:?}");
+			assert_eq!(v.as_slice().trim_and_normalize(), expected, "{raw:?}");
+		}
+
+		// Fixed-size arrays.
+		assert_eq!([' ', 'H', '\u{3000}', '\u{3000}', 'I', ' '].trim_and_normalize(), ['H', ' ', 'I']);
+		assert!([' '; 8].trim_and_normalize().is_empty());
+		assert!([char::MIN; 0].trim_and_normalize().is_empty());
+	}
+
+	#[test]
+	fn normalized_whitespace_max() {
+		for raw in [
+			"", " ", "Hello", "  Hello  ", "H I", "H  I", "H   I", "H\t\t\t\tI",
+			" H\r\nE L  \u{3000}L\tO  ", "A.  B.\n\nC.   \t D",
+		] {
+			// A max of one is just regular normalization.
+			let expected: String = raw.chars().trim_and_normalize().collect();
+			let normal: String = raw.chars().normalized_whitespace_max(1).collect();
+			assert_eq!(normal, expected, "{raw:?}");
+
+			// Zero removes inner whitespace entirely.
+			let expected: String = raw.chars().filter(|c| ! c.is_whitespace()).collect();
+			let normal: String = raw.chars().normalized_whitespace_max(0).collect();
+			assert_eq!(normal, expected, "{raw:?}");
+
+			// Bigger maxes shouldn't touch clean or short runs, but should
+			// cap the rest.
+			for max in 2..6 {
+				let mut expected = String::new();
+				for (i, word) in raw.split(char::is_whitespace).filter(|w| ! w.is_empty()).enumerate() {
+					if i != 0 {
+						let start = word.as_ptr() as usize - raw.as_ptr() as usize;
+						let gap = raw[..start].chars().rev().take_while(|c| c.is_whitespace()).count();
+						expected.extend(core::iter::repeat(' ').take(gap.min(max)));
+					}
+					expected.push_str(word);
+				}
+
+				let normal: String = raw.chars().normalized_whitespace_max(max).collect();
+				assert_eq!(normal, expected, "{raw:?} {max}");
+
+				let chars: Vec<char> = raw.chars().collect();
+				let normal: String = chars.iter().normalized_whitespace_max(max).collect();
+				assert_eq!(normal, expected, "{raw:?} {max}");
+
+				if raw.is_ascii() {
+					let normal: Vec<u8> = raw.bytes().normalized_whitespace_max(max).collect();
+					assert_eq!(normal, expected.as_bytes(), "{raw:?} {max}");
+
+					let normal: Vec<u8> = raw.as_bytes().iter().normalized_whitespace_max(max).collect();
+					assert_eq!(normal, expected.as_bytes(), "{raw:?} {max}");
+				}
+			}
+		}
+
+		// Check the size hints are sane.
+		let mut iter = "A \t\t B".chars().normalized_whitespace_max(3);
+		assert_eq!(iter.next(), Some('A'));
+		assert_eq!(iter.next(), Some(' '));
+		assert_eq!(iter.size_hint().0, 3);
+		assert_eq!(iter.collect::<String>(), "  B");
+	}
+
+	#[test]
+	fn is_normalized() {
+		for raw in [
+			"", " ", "H", "HI", "H I", "H  I", " H", "H ", "H\tI", "H\nI",
+			"H E L L O", "\u{2003}", "H\u{2003}I", "Björk Guðmundsdóttir",
+		] {
+			let expected = raw.trim_and_normalize();
+			let clean = matches!(expected, Cow::Borrowed(s) if s.len() == raw.len());
+			assert_eq!(is_normalized_str(raw), clean, "{raw:?}");
+			let chars: Vec<char> = raw.chars().collect();
+			assert_eq!(is_normalized_chars(&chars), clean, "{raw:?}");
+
+			// Owned Cows should come back with their allocations intact.
+			let owned = String::from(raw);
+			let ptr = owned.as_ptr();
+			let normal = Cow::<str>::Owned(owned).trim_and_normalize();
+			assert_eq!(normal, expected);
+			if clean { assert_eq!(normal.as_ptr(), ptr); }
+
+			if raw.is_ascii() {
+				assert_eq!(is_normalized_bytes(raw.as_bytes()), clean, "{raw:?}");
+
+				let owned = raw.as_bytes().to_vec();
+				let ptr = owned.as_ptr();
+				let normal = Cow::<[u8]>::Owned(owned).trim_and_normalize();
+				assert_eq!(normal, expected.as_bytes());
+				if clean { assert_eq!(normal.as_ptr(), ptr); }
+			}
+		}
+	}
+
+	#[test]
+	fn trim_and_normalize_boxed() {
+		for (raw, expected) in [
+			("", ""),
+			("Hello World", "Hello World"),
+			(" \r\nHello\u{3000}\u{3000}World\t", "Hello World"),
+		] {
+			assert_eq!(&*raw.trim_and_normalize_boxed(), expected, "{raw:?}");
+			assert_eq!(&*String::from(raw).trim_and_normalize_boxed(), expected, "{raw:?}");
+			assert_eq!(&*Cow::Borrowed(raw).trim_and_normalize_arc(), expected, "{raw:?}");
+
+			// Owned sources shouldn't leave any slack.
+			let mut s = String::with_capacity(64);
+			s.push_str(raw);
+			let boxed = s.trim_and_normalize_boxed();
+			assert_eq!(&*boxed, expected, "{raw:?}");
+			assert_eq!(boxed.into_string().capacity(), expected.len(), "{raw:?}");
+
+			// Bytes only know about ASCII whitespace.
+			let expected = raw.as_bytes().trim_and_normalize();
+			let mut v = Vec::with_capacity(64);
+			v.extend_from_slice(raw.as_bytes());
+			let boxed = v.trim_and_normalize_boxed();
+			assert_eq!(*boxed, *expected, "{raw:?}");
+			assert_eq!(boxed.into_vec().capacity(), expected.len(), "{raw:?}");
+			assert_eq!(*raw.as_bytes().trim_and_normalize_arc(), *expected, "{raw:?}");
+		}
+	}
 }
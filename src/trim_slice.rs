@@ -4,6 +4,7 @@
 
 use alloc::{
 	boxed::Box,
+	string::String,
 	vec::Vec,
 };
 use crate::pattern::MatchPattern;
@@ -13,51 +14,90 @@ use crate::pattern::MatchPattern;
 /// # Trim Slice (Matches).
 ///
 /// The [`TrimSliceMatches`] trait brings arbitrary match-based trimming support
-/// to `&[u8]`, `Vec<u8>`, and `Box<[u8]>` types, very similar to the ones
+/// to `&[T]`, `Vec<T>`, and `Box<[T]>` types, very similar to the ones
 /// enjoyed by `String`/`&str`.
 ///
 /// The trait methods included are:
 ///
 /// | Method | Description |
 /// | ------ | ----------- |
-/// | `trim_matches` | Trim arbitrary leading and trailing bytes. |
-/// | `trim_start_matches` | Trim arbitrary leading bytes. |
-/// | `trim_end_matches` | Trim arbitrary trailing bytes. |
-///
-/// Each of these match methods accept either:
-/// * A single `u8`;
-/// * An array or slice of `u8`;
-/// * A `&BTreeSet<u8>`;
-/// * A callback with the signature `Fn(u8) -> bool`;
-pub trait TrimSliceMatches {
+/// | `trim_matches` | Trim arbitrary leading and trailing elements. |
+/// | `trim_start_matches` | Trim arbitrary leading elements. |
+/// | `trim_end_matches` | Trim arbitrary trailing elements. |
+/// | `trim_matches_counted` | Trim arbitrary leading and trailing elements, returning the counts too. |
+/// | `trim_seq` | Trim leading and trailing repetitions of a sequence. |
+/// | `trim_start_seq` | Trim leading repetitions of a sequence. |
+/// | `trim_end_seq` | Trim trailing repetitions of a sequence. |
+///
+/// Each of the `matches` methods accept either:
+/// * A single `T`;
+/// * An array or slice of `T`;
+/// * A `&BTreeSet<T>`;
+/// * A [`SortedSlice`](crate::SortedSlice) of `T`;
+/// * A [`Predicate`](crate::Predicate) wrapping an `Fn(T) -> bool`;
+///
+/// Byte slices — the default — additionally accept:
+/// * A [`ByteSet`](crate::ByteSet) or [`ClassTable`](crate::ClassTable);
+/// * A bare callback with the signature `Fn(u8) -> bool`;
+///
+/// ## Examples
+///
+/// Any `Copy + Eq + Ord` element type works, e.g. token IDs or sensor
+/// readings:
+///
+/// ```
+/// use trimothy::{Predicate, TrimSliceMatches};
+///
+/// const PAD: u32 = 0;
+/// let tokens: &[u32] = &[0, 0, 101, 7592, 102, 0];
+/// assert_eq!(tokens.trim_matches(PAD), &[101, 7592, 102]);
+///
+/// let readings = vec![-1_i16, -1, 20, 21, -1, 19, -1];
+/// assert_eq!(readings.trim_matches(Predicate(|r: i16| r < 0)), &[20, 21, -1, 19]);
+/// assert_eq!(readings.trim_end_seq(&[19, -1]), &[-1, -1, 20, 21, -1]);
+/// ```
+pub trait TrimSliceMatches<T: Copy + Eq + Ord + Sized = u8> {
 	/// # Trim Matches.
 	///
-	/// Trim arbitrary leading and trailing bytes as determined by the provided
-	/// pattern, which can be:
-	/// * A single `u8`;
-	/// * An array or slice of `u8`;
-	/// * A `&BTreeSet<u8>`;
-	/// * A callback with the signature `Fn(u8) -> bool`;
+	/// Trim arbitrary leading and trailing elements as determined by the
+	/// provided pattern, which can be:
+	/// * A single `T`;
+	/// * An array or slice of `T`;
+	/// * A `&BTreeSet<T>`;
+	/// * A [`SortedSlice`](crate::SortedSlice) of `T`;
+	/// * A [`Predicate`](crate::Predicate) wrapping an `Fn(T) -> bool`;
+	///
+	/// Byte slices also accept a [`ByteSet`](crate::ByteSet),
+	/// [`ClassTable`](crate::ClassTable), or bare `Fn(u8) -> bool` callback.
 	///
 	/// ```
-	/// use trimothy::TrimSliceMatches;
+	/// use trimothy::{Predicate, TrimSliceMatches};
 	///
 	/// let s: &[u8] = b"...Custom Trim!...";
 	/// assert_eq!(s.trim_matches(b'.'), b"Custom Trim!");
 	/// assert_eq!(s.trim_matches([b'.']), b"Custom Trim!");
 	/// assert_eq!(s.trim_matches(&[b'.']), b"Custom Trim!");
 	/// assert_eq!(s.trim_matches(|b| b'.' == b), b"Custom Trim!");
+	///
+	/// // UTF-16 with a BOM and NUL padding.
+	/// let w: &[u16] = &[0xFEFF, 0x48, 0x69, 0, 0];
+	/// assert_eq!(w.trim_matches([0xFEFF, 0]), &[0x48, 0x69]);
+	/// assert_eq!(w.trim_matches(Predicate(|u: u16| u == 0 || u == 0xFEFF)), &[0x48, 0x69]);
 	/// ```
-	fn trim_matches<P: MatchPattern<u8>>(&self, pat: P) -> &[u8];
+	fn trim_matches<P: MatchPattern<T>>(&self, pat: P) -> &[T];
 
 	/// # Trim Start Matches.
 	///
-	/// Trim arbitrary leading bytes as determined by the provided
+	/// Trim arbitrary leading elements as determined by the provided
 	/// pattern, which can be:
-	/// * A single `u8`;
-	/// * An array or slice of `u8`;
-	/// * A `&BTreeSet<u8>`;
-	/// * A callback with the signature `Fn(u8) -> bool`;
+	/// * A single `T`;
+	/// * An array or slice of `T`;
+	/// * A `&BTreeSet<T>`;
+	/// * A [`SortedSlice`](crate::SortedSlice) of `T`;
+	/// * A [`Predicate`](crate::Predicate) wrapping an `Fn(T) -> bool`;
+	///
+	/// Byte slices also accept a [`ByteSet`](crate::ByteSet),
+	/// [`ClassTable`](crate::ClassTable), or bare `Fn(u8) -> bool` callback.
 	///
 	/// ```
 	/// use trimothy::TrimSliceMatches;
@@ -67,17 +107,27 @@ pub trait TrimSliceMatches {
 	/// assert_eq!(s.trim_start_matches([b'.']), b"Custom Trim!...");
 	/// assert_eq!(s.trim_start_matches(&[b'.']), b"Custom Trim!...");
 	/// assert_eq!(s.trim_start_matches(|b| b'.' == b), b"Custom Trim!...");
+	///
+	/// #[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+	/// enum Token { Bos, Word(u32), Eos }
+	///
+	/// let t = vec![Token::Bos, Token::Bos, Token::Word(7), Token::Eos];
+	/// assert_eq!(t.trim_start_matches(Token::Bos), &[Token::Word(7), Token::Eos]);
 	/// ```
-	fn trim_start_matches<P: MatchPattern<u8>>(&self, pat: P) -> &[u8];
+	fn trim_start_matches<P: MatchPattern<T>>(&self, pat: P) -> &[T];
 
 	/// # Trim End Matches.
 	///
-	/// Trim arbitrary trailing bytes as determined by the provided
+	/// Trim arbitrary trailing elements as determined by the provided
 	/// pattern, which can be:
-	/// * A single `u8`;
-	/// * An array or slice of `u8`;
-	/// * A `&BTreeSet<u8>`;
-	/// * A callback with the signature `Fn(u8) -> bool`;
+	/// * A single `T`;
+	/// * An array or slice of `T`;
+	/// * A `&BTreeSet<T>`;
+	/// * A [`SortedSlice`](crate::SortedSlice) of `T`;
+	/// * A [`Predicate`](crate::Predicate) wrapping an `Fn(T) -> bool`;
+	///
+	/// Byte slices also accept a [`ByteSet`](crate::ByteSet),
+	/// [`ClassTable`](crate::ClassTable), or bare `Fn(u8) -> bool` callback.
 	///
 	/// ```
 	/// use trimothy::TrimSliceMatches;
@@ -87,25 +137,85 @@ pub trait TrimSliceMatches {
 	/// assert_eq!(s.trim_end_matches([b'.']), b"...Custom Trim!");
 	/// assert_eq!(s.trim_end_matches(&[b'.']), b"...Custom Trim!");
 	/// assert_eq!(s.trim_end_matches(|b| b'.' == b), b"...Custom Trim!");
+	///
+	/// let samples = Box::<[i32]>::from([3, -7, i32::MIN, i32::MIN]);
+	/// assert_eq!(samples.trim_end_matches(i32::MIN), &[3, -7]);
+	/// ```
+	fn trim_end_matches<P: MatchPattern<T>>(&self, pat: P) -> &[T];
+
+	/// # Trim Matches (Counted).
+	///
+	/// Same as [`TrimSliceMatches::trim_matches`], but also returns the
+	/// number of leading and trailing elements that were removed, handy for
+	/// advancing external cursors without recomputing lengths.
+	///
 	/// ```
-	fn trim_end_matches<P: MatchPattern<u8>>(&self, pat: P) -> &[u8];
+	/// use trimothy::TrimSliceMatches;
+	///
+	/// let s: &[u8] = b"..Custom Trim!...";
+	/// assert_eq!(
+	///     s.trim_matches_counted(b'.'),
+	///     (&b"Custom Trim!"[..], 2, 3),
+	/// );
+	/// ```
+	fn trim_matches_counted<P: MatchPattern<T>>(&self, pat: P) -> (&[T], usize, usize);
+
+	/// # Trim Sequence.
+	///
+	/// Trim every leading and trailing repetition of the sequence `needle`,
+	/// e.g. for stripping repeated `\r\n`s or boundary markers
+	/// from protocol frames. Empty needles are ignored.
+	///
+	/// ```
+	/// use trimothy::TrimSliceMatches;
+	///
+	/// let s: &[u8] = b"\r\n\r\nHello\r\n\r\n\r\n";
+	/// assert_eq!(s.trim_seq(b"\r\n"), b"Hello");
+	/// ```
+	fn trim_seq(&self, needle: &[T]) -> &[T];
+
+	/// # Trim Start Sequence.
+	///
+	/// Trim every leading repetition of the sequence `needle`. Empty needles
+	/// are ignored.
+	///
+	/// ```
+	/// use trimothy::TrimSliceMatches;
+	///
+	/// let s: &[u8] = b"--xx--x--";
+	/// assert_eq!(s.trim_start_seq(b"--"), b"xx--x--");
+	/// ```
+	fn trim_start_seq(&self, needle: &[T]) -> &[T];
+
+	/// # Trim End Sequence.
+	///
+	/// Trim every trailing repetition of the sequence `needle`. Empty needles
+	/// are ignored.
+	///
+	/// ```
+	/// use trimothy::TrimSliceMatches;
+	///
+	/// let s: &[u8] = b"--xx--x--";
+	/// assert_eq!(s.trim_end_seq(b"--"), b"--xx--x");
+	/// ```
+	fn trim_end_seq(&self, needle: &[T]) -> &[T];
 }
 
 
 /// # Helper: Trim Slice Matches.
 macro_rules! trim_slice {
 	($($ty:ty),+ $(,)?) => ($(
-		impl TrimSliceMatches for $ty {
+		impl<T: Copy + Eq + Ord + Sized> TrimSliceMatches<T> for $ty {
 			/// # Trim Matches.
 			///
-			/// Trim arbitrary leading and trailing bytes as determined by the provided
+			/// Trim arbitrary leading and trailing elements as determined by the provided
 			/// pattern, which can be:
-			/// * A single `u8`;
-			/// * An array or slice of `u8`;
-			/// * A `&BTreeSet<u8>`;
-			/// * A callback with the signature `Fn(u8) -> bool`;
-			fn trim_matches<P: MatchPattern<u8>>(&self, pat: P) -> &[u8] {
-				let mut src: &[u8] = &self;
+			/// * A single `T`;
+			/// * An array or slice of `T`;
+			/// * A `&BTreeSet<T>`;
+			/// * A [`Predicate`](crate::Predicate) callback (or for bytes, a bare `Fn(u8) -> bool`);
+			fn trim_matches<P: MatchPattern<T>>(&self, pat: P) -> &[T] {
+				let mut src: &[T] = &self;
 				while let [first, rest @ ..] = src {
 					if pat.is_match(*first) { src = rest; }
 					else { break; }
@@ -120,14 +230,14 @@ macro_rules! trim_slice {
 
 			/// # Trim Start Matches.
 			///
-			/// Trim arbitrary leading bytes as determined by the provided
+			/// Trim arbitrary leading elements as determined by the provided
 			/// pattern, which can be:
-			/// * A single `u8`;
-			/// * An array or slice of `u8`;
-			/// * A `&BTreeSet<u8>`;
-			/// * A callback with the signature `Fn(u8) -> bool`;
-			fn trim_start_matches<P: MatchPattern<u8>>(&self, pat: P) -> &[u8] {
-				let mut src: &[u8] = &self;
+			/// * A single `T`;
+			/// * An array or slice of `T`;
+			/// * A `&BTreeSet<T>`;
+			/// * A [`Predicate`](crate::Predicate) callback (or for bytes, a bare `Fn(u8) -> bool`);
+			fn trim_start_matches<P: MatchPattern<T>>(&self, pat: P) -> &[T] {
+				let mut src: &[T] = &self;
 				while let [first, rest @ ..] = src {
 					if pat.is_match(*first) { src = rest; }
 					else { break; }
@@ -135,27 +245,355 @@ macro_rules! trim_slice {
 				src
 			}
 
-			/// # Trim Start Matches.
+			/// # Trim End Matches.
 			///
-			/// Trim arbitrary trailing bytes as determined by the provided
+			/// Trim arbitrary trailing elements as determined by the provided
 			/// pattern, which can be:
-			/// * A single `u8`;
-			/// * An array or slice of `u8`;
-			/// * A `&BTreeSet<u8>`;
-			/// * A callback with the signature `Fn(u8) -> bool`;
-			fn trim_end_matches<P: MatchPattern<u8>>(&self, pat: P) -> &[u8] {
-				let mut src: &[u8] = &self;
+			/// * A single `T`;
+			/// * An array or slice of `T`;
+			/// * A `&BTreeSet<T>`;
+			/// * A [`Predicate`](crate::Predicate) callback (or for bytes, a bare `Fn(u8) -> bool`);
+			fn trim_end_matches<P: MatchPattern<T>>(&self, pat: P) -> &[T] {
+				let mut src: &[T] = &self;
 				while let [rest @ .., last] = src {
 					if pat.is_match(*last) { src = rest; }
 					else { break; }
 				}
 				src
 			}
+
+			/// # Trim Matches (Counted).
+			///
+			/// Same as `trim_matches`, but also returns the number of leading
+			/// and trailing elements that were removed.
+			fn trim_matches_counted<P: MatchPattern<T>>(&self, pat: P) -> (&[T], usize, usize) {
+				let src: &[T] = &self;
+				let trimmed = src.trim_start_matches(pat);
+				let start = src.len() - trimmed.len();
+				let trimmed = trimmed.trim_end_matches(pat);
+				(trimmed, start, src.len() - start - trimmed.len())
+			}
+
+			/// # Trim Sequence.
+			///
+			/// Trim every leading and trailing repetition of the sequence
+			/// `needle`.
+			fn trim_seq(&self, needle: &[T]) -> &[T] {
+				self.trim_start_seq(needle).trim_end_seq(needle)
+			}
+
+			/// # Trim Start Sequence.
+			///
+			/// Trim every leading repetition of the sequence `needle`.
+			fn trim_start_seq(&self, needle: &[T]) -> &[T] {
+				let mut src: &[T] = &self;
+				if ! needle.is_empty() {
+					while let Some(rest) = src.strip_prefix(needle) { src = rest; }
+				}
+				src
+			}
+
+			/// # Trim End Sequence.
+			///
+			/// Trim every trailing repetition of the sequence `needle`.
+			fn trim_end_seq(&self, needle: &[T]) -> &[T] {
+				let mut src: &[T] = &self;
+				if ! needle.is_empty() {
+					while let Some(rest) = src.strip_suffix(needle) { src = rest; }
+				}
+				src
+			}
 		}
 	)+);
 }
 
-trim_slice!([u8], Box<[u8]>, Vec<u8>);
+trim_slice!([T], Box<[T]>, Vec<T>);
+
+
+
+/// # Trim Slice (UTF-8 Safe Matches).
+///
+/// The [`TrimSliceMatchesUtf8`] trait adds a byte-pattern trim to `&[u8]`,
+/// `Vec<u8>`, and `Box<[u8]>` buffers holding UTF-8 text that never leaves
+/// a partial `char` behind.
+///
+/// | Method | Description |
+/// | ------ | ----------- |
+/// | `trim_matches_utf8_safe` | Trim arbitrary leading and trailing bytes without splitting UTF-8 sequences. |
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::{TrimSliceMatches, TrimSliceMatchesUtf8};
+///
+/// let s = "…Hello…".as_bytes();
+/// let cont = |b: u8| (0x80..=0xBF).contains(&b);
+///
+/// // The regular version leaves a dangling lead byte.
+/// assert!(std::str::from_utf8(s.trim_matches(cont)).is_err());
+///
+/// // The safe version doesn't.
+/// assert_eq!(s.trim_matches_utf8_safe(cont), s);
+/// ```
+pub trait TrimSliceMatchesUtf8 {
+	/// # Trim Matches (UTF-8 Safe).
+	///
+	/// Same as [`TrimSliceMatches::trim_matches`], but if trimming would
+	/// split a multi-byte sequence — e.g. because the pattern matches
+	/// continuation bytes — the partial `char` is kept whole instead, so
+	/// valid input stays valid.
+	///
+	/// Characters that match in their entirety are still trimmed.
+	fn trim_matches_utf8_safe<P: MatchPattern<u8>>(&self, pat: P) -> &[u8];
+}
+
+/// # Helper: Trim Slice UTF-8 Safe Matches.
+macro_rules! trim_slice_utf8 {
+	($($ty:ty),+ $(,)?) => ($(
+		impl TrimSliceMatchesUtf8 for $ty {
+			/// # Trim Matches (UTF-8 Safe).
+			///
+			/// Trim arbitrary leading and trailing bytes as determined by the
+			/// provided pattern, without splitting UTF-8 sequences.
+			fn trim_matches_utf8_safe<P: MatchPattern<u8>>(&self, pat: P) -> &[u8] {
+				let src: &[u8] = &self;
+
+				// Back up to the start of the char if we stopped inside one.
+				let mut start = src.iter().position(|&b| ! pat.is_match(b)).unwrap_or(src.len());
+				while start != 0 && src.get(start).is_some_and(|&b| is_continuation(b)) {
+					start -= 1;
+				}
+				let src = &src[start..];
+
+				// Likewise, move forward to the end of the char.
+				let mut end = src.iter().rposition(|&b| ! pat.is_match(b)).map_or(0, |idx| idx + 1);
+				while src.get(end).is_some_and(|&b| is_continuation(b)) { end += 1; }
+				&src[..end]
+			}
+		}
+	)+);
+}
+
+trim_slice_utf8!([u8], Box<[u8]>, Vec<u8>);
+
+
+
+/// # Trim UTF-8 Slice (Char Matches).
+///
+/// The [`TrimUtf8Matches`] trait bridges the gap between `char` patterns and
+/// byte sources, allowing `&[u8]`, `Vec<u8>`, and `Box<[u8]>` buffers known
+/// to hold UTF-8 to be trimmed by any [`MatchPattern<char>`], e.g. to strip
+/// an ellipsis or NBSP without a `str` round trip.
+///
+/// | Method | Description |
+/// | ------ | ----------- |
+/// | `trim_utf8_matches` | Trim arbitrary leading and trailing chars. |
+/// | `trim_start_utf8_matches` | Trim arbitrary leading chars. |
+/// | `trim_end_utf8_matches` | Trim arbitrary trailing chars. |
+///
+/// Only the edges are decoded, one `char` at a time, and only for as long
+/// as the pattern keeps matching. If an edge is not valid UTF-8, trimming
+/// simply stops there.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::TrimUtf8Matches;
+///
+/// let s = "\u{a0}…Hello World…\u{a0}".as_bytes();
+/// assert_eq!(s.trim_utf8_matches(['…', '\u{a0}']), b"Hello World");
+/// assert_eq!(s.trim_start_utf8_matches(char::is_whitespace), "…Hello World…\u{a0}".as_bytes());
+/// assert_eq!(s.trim_end_utf8_matches(|c: char| ! c.is_alphabetic()), "\u{a0}…Hello World".as_bytes());
+///
+/// // Invalid UTF-8 is never trimmed.
+/// assert_eq!(b"\xFF \xFF".trim_utf8_matches(' '), b"\xFF \xFF");
+/// ```
+pub trait TrimUtf8Matches {
+	/// # Trim UTF-8 Matches.
+	///
+	/// Trim arbitrary leading and trailing chars as determined by the
+	/// provided pattern.
+	fn trim_utf8_matches<P: MatchPattern<char>>(&self, pat: P) -> &[u8];
+
+	/// # Trim Start UTF-8 Matches.
+	///
+	/// Trim arbitrary leading chars as determined by the provided pattern.
+	fn trim_start_utf8_matches<P: MatchPattern<char>>(&self, pat: P) -> &[u8];
+
+	/// # Trim End UTF-8 Matches.
+	///
+	/// Trim arbitrary trailing chars as determined by the provided pattern.
+	fn trim_end_utf8_matches<P: MatchPattern<char>>(&self, pat: P) -> &[u8];
+}
+
+/// # Helper: Trim UTF-8 Matches.
+macro_rules! trim_utf8 {
+	($($ty:ty),+ $(,)?) => ($(
+		impl TrimUtf8Matches for $ty {
+			/// # Trim UTF-8 Matches.
+			///
+			/// Trim arbitrary leading and trailing chars as determined by the
+			/// provided pattern.
+			fn trim_utf8_matches<P: MatchPattern<char>>(&self, pat: P) -> &[u8] {
+				self.trim_start_utf8_matches(pat).trim_end_utf8_matches(pat)
+			}
+
+			/// # Trim Start UTF-8 Matches.
+			///
+			/// Trim arbitrary leading chars as determined by the provided
+			/// pattern.
+			fn trim_start_utf8_matches<P: MatchPattern<char>>(&self, pat: P) -> &[u8] {
+				let mut src: &[u8] = &self;
+				while let Some((c, len)) = first_char(src) {
+					if pat.is_match(c) { src = &src[len..]; }
+					else { break; }
+				}
+				src
+			}
+
+			/// # Trim End UTF-8 Matches.
+			///
+			/// Trim arbitrary trailing chars as determined by the provided
+			/// pattern.
+			fn trim_end_utf8_matches<P: MatchPattern<char>>(&self, pat: P) -> &[u8] {
+				let mut src: &[u8] = &self;
+				while let Some((c, len)) = last_char(src) {
+					if pat.is_match(c) { src = &src[..src.len() - len]; }
+					else { break; }
+				}
+				src
+			}
+		}
+	)+);
+}
+
+trim_utf8!([u8], Box<[u8]>, Vec<u8>);
+
+
+
+/// # Trim String (Byte Matches).
+///
+/// The [`TrimStrByteMatches`] trait is the converse of [`TrimUtf8Matches`],
+/// allowing a [`MatchPattern<u8>`] — such as an ASCII
+/// [`ByteSet`](crate::ByteSet) — to drive the trimming of `str`, `String`,
+/// and `Box<str>` types, so a single pattern can serve both string and byte
+/// call sites.
+///
+/// | Method | Description |
+/// | ------ | ----------- |
+/// | `trim_byte_matches` | Trim arbitrary leading and trailing ASCII bytes. |
+/// | `trim_start_byte_matches` | Trim arbitrary leading ASCII bytes. |
+/// | `trim_end_byte_matches` | Trim arbitrary trailing ASCII bytes. |
+///
+/// Only ASCII bytes are ever trimmed — non-ASCII bytes are never passed to
+/// the pattern — so the results always fall on `char` boundaries.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::{ByteSet, TrimSliceMatches, TrimStrByteMatches};
+///
+/// const PUNCT: ByteSet = ByteSet::from_bytes(b".,;!? ");
+///
+/// assert_eq!("..Björk!".trim_byte_matches(PUNCT), "Björk");
+/// assert_eq!(b"..Bjork!".trim_matches(PUNCT), b"Bjork");
+///
+/// // Non-ASCII bytes never match.
+/// assert_eq!("é".trim_byte_matches(|_| true), "é");
+/// ```
+pub trait TrimStrByteMatches {
+	/// # Trim Byte Matches.
+	///
+	/// Trim arbitrary leading and trailing ASCII bytes as determined by the
+	/// provided pattern.
+	fn trim_byte_matches<P: MatchPattern<u8>>(&self, pat: P) -> &str;
+
+	/// # Trim Start Byte Matches.
+	///
+	/// Trim arbitrary leading ASCII bytes as determined by the provided
+	/// pattern.
+	fn trim_start_byte_matches<P: MatchPattern<u8>>(&self, pat: P) -> &str;
+
+	/// # Trim End Byte Matches.
+	///
+	/// Trim arbitrary trailing ASCII bytes as determined by the provided
+	/// pattern.
+	fn trim_end_byte_matches<P: MatchPattern<u8>>(&self, pat: P) -> &str;
+}
+
+/// # Helper: Trim String Byte Matches.
+macro_rules! trim_str_bytes {
+	($($ty:ty),+ $(,)?) => ($(
+		impl TrimStrByteMatches for $ty {
+			/// # Trim Byte Matches.
+			///
+			/// Trim arbitrary leading and trailing ASCII bytes as determined
+			/// by the provided pattern.
+			fn trim_byte_matches<P: MatchPattern<u8>>(&self, pat: P) -> &str {
+				self.trim_start_byte_matches(pat).trim_end_byte_matches(pat)
+			}
+
+			/// # Trim Start Byte Matches.
+			///
+			/// Trim arbitrary leading ASCII bytes as determined by the
+			/// provided pattern.
+			fn trim_start_byte_matches<P: MatchPattern<u8>>(&self, pat: P) -> &str {
+				let src: &str = &self;
+				let start = src.bytes()
+					.position(|b| ! (b.is_ascii() && pat.is_match(b)))
+					.unwrap_or(src.len());
+				&src[start..]
+			}
+
+			/// # Trim End Byte Matches.
+			///
+			/// Trim arbitrary trailing ASCII bytes as determined by the
+			/// provided pattern.
+			fn trim_end_byte_matches<P: MatchPattern<u8>>(&self, pat: P) -> &str {
+				let src: &str = &self;
+				let end = src.bytes()
+					.rposition(|b| ! (b.is_ascii() && pat.is_match(b)))
+					.map_or(0, |idx| idx + 1);
+				&src[..end]
+			}
+		}
+	)+);
+}
+
+trim_str_bytes!(str, Box<str>, String);
+
+
+
+/// # First Char.
+///
+/// Decode the first `char` from a UTF-8 slice, returning it along with its
+/// byte length, or `None` if the slice is empty or invalid.
+fn first_char(src: &[u8]) -> Option<(char, usize)> {
+	let len = match src.first()? {
+		0x00..=0x7F => 1,
+		0xC0..=0xDF => 2,
+		0xE0..=0xEF => 3,
+		0xF0..=0xF7 => 4,
+		_ => return None,
+	};
+	let chunk = src.get(..len)?;
+	core::str::from_utf8(chunk).ok()?.chars().next().map(|c| (c, len))
+}
+
+/// # Is UTF-8 Continuation Byte?
+const fn is_continuation(b: u8) -> bool { b & 0b1100_0000 == 0b1000_0000 }
+
+/// # Last Char.
+///
+/// Decode the last `char` from a UTF-8 slice, returning it along with its
+/// byte length, or `None` if the slice is empty or invalid.
+fn last_char(src: &[u8]) -> Option<(char, usize)> {
+	// Chars are at most four bytes; find where the last one begins.
+	let tail = &src[src.len().saturating_sub(4)..];
+	let start = tail.iter().rposition(|&b| ! is_continuation(b))?;
+	let chunk = &tail[start..];
+	core::str::from_utf8(chunk).ok()?.chars().next().map(|c| (c, chunk.len()))
+}
 
 
 
@@ -218,6 +656,73 @@ mod tests {
 		assert_eq!(arr.trim_ascii(), &[b'.']);
 	}
 
+	#[test]
+	fn t_trim_counted() {
+		for (raw, expected, start, end) in [
+			(T_EMPTY, T_EMPTY, 0, 0),
+			(b"  ", T_EMPTY, 2, 0),
+			(T_HELLO, T_HELLO, 0, 0),
+			(T_HELLO_E, T_HELLO, 0, 1),
+			(b" \thello\n", T_HELLO, 2, 1),
+		] {
+			let expected = (expected, start, end);
+			assert_eq!(raw.trim_matches_counted(|b: u8| b.is_ascii_whitespace()), expected);
+			assert_eq!(raw.to_vec().trim_matches_counted(|b: u8| b.is_ascii_whitespace()), expected);
+			assert_eq!(
+				Box::<[u8]>::from(raw).trim_matches_counted(|b: u8| b.is_ascii_whitespace()),
+				expected,
+			);
+		}
+	}
+
+	#[test]
+	fn t_trim_seq() {
+		for (raw, needle, start, end, both) in [
+			(T_EMPTY, &b"ab"[..], T_EMPTY, T_EMPTY, T_EMPTY),
+			(b"abab", b"ab", T_EMPTY, T_EMPTY, T_EMPTY),
+			(b"ababa", b"ab", b"a", b"ababa", b"a"),
+			(b"aab", b"ab", b"aab", b"a", b"a"),
+			(b"ab", b"", b"ab", b"ab", b"ab"),
+			(b"\r\n\r\nHello\r\n\n", b"\r\n", b"Hello\r\n\n", b"\r\n\r\nHello\r\n\n", b"Hello\r\n\n"),
+		] {
+			assert_eq!(raw.trim_start_seq(needle), start, "{raw:?} {needle:?}");
+			assert_eq!(raw.trim_end_seq(needle), end, "{raw:?} {needle:?}");
+			assert_eq!(raw.trim_seq(needle), both, "{raw:?} {needle:?}");
+			assert_eq!(raw.to_vec().trim_seq(needle), both, "{raw:?} {needle:?}");
+			assert_eq!(Box::<[u8]>::from(raw).trim_seq(needle), both, "{raw:?} {needle:?}");
+		}
+	}
+
+	#[test]
+	fn t_trim_generic() {
+		use crate::{Negated, Predicate, SortedSlice};
+
+		let raw: &[i32] = &[-1, 0, 5, -3, 7, 0, -1];
+		let set = BTreeSet::from([-1, 0]);
+		for pat in [&[-1, 0][..], &[0, -1]] {
+			assert_eq!(raw.trim_matches(pat), &[5, -3, 7]);
+			assert_eq!(raw.trim_start_matches(pat), &[5, -3, 7, 0, -1]);
+			assert_eq!(raw.trim_end_matches(pat), &[-1, 0, 5, -3, 7]);
+		}
+		assert_eq!(raw.trim_matches(&set), &[5, -3, 7]);
+		assert_eq!(raw.trim_matches(SortedSlice::new(&[-1, 0][..]).unwrap()), &[5, -3, 7]);
+		assert_eq!(raw.trim_matches(Predicate(|n: i32| n < 1)), &[5, -3, 7]);
+		assert_eq!(raw.trim_matches(Negated(Predicate(|n: i32| n == 7))), &[7]);
+		assert_eq!(raw.trim_matches(-1), &[0, 5, -3, 7, 0]);
+		assert_eq!(raw.trim_matches_counted(-1), (&[0, 5, -3, 7, 0][..], 1, 1));
+		assert_eq!(raw.trim_seq(&[]), raw);
+		assert_eq!(raw.trim_end_seq(&[0, -1]), &[-1, 0, 5, -3, 7]);
+
+		// Owned types too.
+		assert_eq!(raw.to_vec().trim_matches([-1, 0]), &[5, -3, 7]);
+		assert_eq!(Box::<[i32]>::from(raw).trim_matches([-1, 0]), &[5, -3, 7]);
+
+		// Non-primitives.
+		let raw = ["", "a", "", "b", ""];
+		assert_eq!(raw.trim_matches(""), &["a", "", "b"]);
+		assert_eq!(raw.trim_matches(Predicate(str::is_empty)), &["a", "", "b"]);
+	}
+
 	#[test]
 	fn t_trim_start() {
 		let tests: [(&str, &str); 6] = [
@@ -307,4 +812,91 @@ mod tests {
 		assert_eq!(Box::<[u8]>::from(T_HELLO_E).trim_matches(&set), T_HELLO);
 		assert_eq!(T_HELLO_E.to_vec().trim_matches(&set), T_HELLO);
 	}
+
+	#[test]
+	fn t_trim_utf8() {
+		for raw in [
+			"", " ", "\u{a0}\u{3000}", "Hello", " Hello ", "\u{a0}Björk\u{2003}",
+			"…🌎…", "\u{3000}\n🌎 Hello 🌍\u{85}",
+		] {
+			let src = raw.as_bytes();
+			for pat in [' ', '\u{a0}', '…', '🌎'] {
+				assert_eq!(src.trim_utf8_matches(pat), raw.trim_matches(pat).as_bytes(), "{raw:?}");
+				assert_eq!(src.trim_start_utf8_matches(pat), raw.trim_start_matches(pat).as_bytes(), "{raw:?}");
+				assert_eq!(src.trim_end_utf8_matches(pat), raw.trim_end_matches(pat).as_bytes(), "{raw:?}");
+			}
+
+			let ws = |c: char| c.is_whitespace();
+			assert_eq!(src.trim_utf8_matches(ws), raw.trim().as_bytes(), "{raw:?}");
+			assert_eq!(src.to_vec().trim_utf8_matches(ws), raw.trim().as_bytes(), "{raw:?}");
+			assert_eq!(Box::<[u8]>::from(src).trim_utf8_matches(ws), raw.trim().as_bytes(), "{raw:?}");
+		}
+
+		// Invalid and truncated sequences stop the trimming.
+		for raw in [&b"\xFF"[..], b"\xE2\x80", b"\x80 ", b" \xF0\x9F\x8C"] {
+			assert_eq!(raw.trim_utf8_matches(|_| true), raw.trim_ascii(), "{raw:?}");
+		}
+	}
+
+	#[test]
+	fn t_trim_utf8_safe() {
+		type Case = (&'static str, fn(u8) -> bool, &'static str);
+
+		let cont: fn(u8) -> bool = |b| (0x80..=0xBF).contains(&b);
+		let high: fn(u8) -> bool = |b| ! b.is_ascii();
+		let tests: [Case; 9] = [
+			("", cont, ""),
+			("Hello", cont, "Hello"),
+			("…Hello…", cont, "…Hello…"),
+			("é", cont, "é"),
+			("éHelloé", high, "Hello"),
+			("🌎 Hello 🌎", high, " Hello "),
+			("🌎", high, ""),
+			("aéa", |b| b == b'a' || b == 0xC3, "é"),
+			("aéa", |b| b == b'a' || b == 0xA9, "é"),
+		];
+		for (raw, pat, expected) in tests {
+			let src = raw.as_bytes();
+			let out = src.trim_matches_utf8_safe(pat);
+			assert_eq!(out, expected.as_bytes(), "{raw:?}");
+			assert!(core::str::from_utf8(out).is_ok(), "{raw:?}");
+			assert_eq!(src.to_vec().trim_matches_utf8_safe(pat), out);
+			assert_eq!(Box::<[u8]>::from(src).trim_matches_utf8_safe(pat), out);
+		}
+
+		// ASCII patterns should match the regular version.
+		for raw in ["", " ", " Hello ", "\tBjörk\n", " 🌎 "] {
+			let src = raw.as_bytes();
+			assert_eq!(src.trim_matches_utf8_safe(b' '), src.trim_matches(b' '), "{raw:?}");
+		}
+	}
+
+	#[test]
+	fn t_trim_str_bytes() {
+		use crate::ByteSet;
+
+		for raw in ["", " ", " \t\n", "Hello", " Hello ", "\u{a0}Björk\u{a0}", " Björk\n"] {
+			let ws = |b: u8| b.is_ascii_whitespace();
+			let expected = raw.trim_matches(|c: char| c.is_ascii_whitespace());
+			assert_eq!(raw.trim_byte_matches(ws), expected, "{raw:?}");
+			assert_eq!(String::from(raw).trim_byte_matches(ws), expected, "{raw:?}");
+			assert_eq!(Box::<str>::from(raw).trim_byte_matches(ws), expected, "{raw:?}");
+			assert_eq!(
+				raw.trim_start_byte_matches(ws),
+				raw.trim_start_matches(|c: char| c.is_ascii_whitespace()),
+				"{raw:?}",
+			);
+			assert_eq!(
+				raw.trim_end_byte_matches(ws),
+				raw.trim_end_matches(|c: char| c.is_ascii_whitespace()),
+				"{raw:?}",
+			);
+		}
+
+		// Non-ASCII bytes are off limits, even if the pattern would match.
+		let all = |_: u8| true;
+		assert_eq!("ébé".trim_byte_matches(all), "ébé");
+		assert_eq!("aéa".trim_byte_matches(all), "é");
+		assert_eq!("🌎".trim_byte_matches(ByteSet::from_bytes(b"\xF0\x8E")), "🌎");
+	}
 }
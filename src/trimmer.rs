@@ -0,0 +1,472 @@
+/*!
+# Trimothy: Trimmer.
+*/
+
+use alloc::{
+	borrow::Cow,
+	collections::BTreeSet,
+	string::String,
+};
+use crate::{
+	CharSet,
+	pattern::MatchPattern,
+	TrimMatchesMut,
+	TrimObserver,
+};
+
+
+
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+/// # Trim Edges.
+///
+/// This determines which end(s) of a string a [`Trimmer`] trims.
+pub enum Edges {
+	#[default]
+	/// # Both Ends.
+	Both,
+
+	/// # Start Only.
+	Start,
+
+	/// # End Only.
+	End,
+
+	/// # Neither End.
+	///
+	/// This is only useful in combination with [`Trimmer::with_collapse`].
+	None,
+}
+
+impl Edges {
+	#[must_use]
+	/// # Trim Start?
+	pub const fn start(self) -> bool { matches!(self, Self::Both | Self::Start) }
+
+	#[must_use]
+	/// # Trim End?
+	pub const fn end(self) -> bool { matches!(self, Self::Both | Self::End) }
+}
+
+
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+/// # Trimmer.
+///
+/// A `Trimmer` is a reusable, runtime-configurable trimming (and optional
+/// collapsing) policy for strings, combining:
+///
+/// | Setting | Description | Default |
+/// | ------- | ----------- | ------- |
+/// | `chars` | Explicit characters to match. | None |
+/// | `classes` | Character classes to match. | [`CharSet::WHITESPACE`] |
+/// | `edges` | Which end(s) to trim. | [`Edges::Both`] |
+/// | `collapse` | Collapse (remaining) runs of matches to this character. | None |
+///
+/// With the `serde` crate feature enabled, trimmers can also be
+/// (de)serialized, allowing sanitization rules to live in configuration files
+/// rather than code. Omitted fields fall back to their defaults.
+///
+/// References to `Trimmer`s can also be used as `char` match patterns in
+/// their own right.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::{CharSet, Edges, Trimmer};
+///
+/// let trimmer = Trimmer::new()
+///     .with_chars(['-', '_'])
+///     .with_collapse(Some(' '));
+///
+/// assert_eq!(trimmer.trim("__Hello \t-_World!--\n"), "Hello World!");
+///
+/// // Only the end this time.
+/// let trimmer = Trimmer::new()
+///     .with_classes(CharSet::WHITESPACE | CharSet::ASCII_PUNCTUATION)
+///     .with_edges(Edges::End);
+///
+/// assert_eq!(trimmer.trim("  Hello World!?! "), "  Hello World");
+/// ```
+///
+/// With the `serde` feature enabled, the same sort of thing can be
+/// described in (and deserialized from) JSON, TOML, etc.:
+///
+/// ```toml
+/// chars = ["-", "_"]
+/// classes = ["whitespace", "control"]
+/// edges = "both" # Or "start", "end", "none".
+/// collapse = " "
+/// ```
+pub struct Trimmer {
+	/// # Explicit Characters.
+	chars: BTreeSet<char>,
+
+	/// # Character Classes.
+	classes: CharSet,
+
+	/// # Edges.
+	edges: Edges,
+
+	/// # Collapse Runs.
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	collapse: Option<char>,
+}
+
+impl Default for Trimmer {
+	#[inline]
+	fn default() -> Self { Self::new() }
+}
+
+impl MatchPattern<char> for &Trimmer {
+	#[inline]
+	/// # Match Trimmer.
+	fn is_match(self, thing: char) -> bool { Trimmer::is_match(self, thing) }
+}
+
+/// ## Setup.
+impl Trimmer {
+	#[must_use]
+	/// # New.
+	///
+	/// Return a new instance that trims whitespace from both ends, the same
+	/// as [`str::trim`], with no collapsing.
+	pub const fn new() -> Self {
+		Self {
+			chars: BTreeSet::new(),
+			classes: CharSet::WHITESPACE,
+			edges: Edges::Both,
+			collapse: None,
+		}
+	}
+
+	#[must_use]
+	/// # With Characters.
+	///
+	/// Add explicit characters to the match set.
+	pub fn with_chars<I: IntoIterator<Item=char>>(mut self, chars: I) -> Self {
+		self.chars.extend(chars);
+		self
+	}
+
+	#[must_use]
+	/// # With Classes.
+	///
+	/// Replace the character classes in the match set.
+	pub const fn with_classes(mut self, classes: CharSet) -> Self {
+		self.classes = classes;
+		self
+	}
+
+	#[must_use]
+	/// # With Edges.
+	///
+	/// Set which end(s) should be trimmed.
+	pub const fn with_edges(mut self, edges: Edges) -> Self {
+		self.edges = edges;
+		self
+	}
+
+	#[must_use]
+	/// # With Collapse.
+	///
+	/// When `Some`, any (remaining) runs of matching characters — i.e. those
+	/// in the middle, or on untrimmed edges — will be replaced with a single
+	/// copy of the given character.
+	pub const fn with_collapse(mut self, collapse: Option<char>) -> Self {
+		self.collapse = collapse;
+		self
+	}
+}
+
+/// ## Getters.
+impl Trimmer {
+	#[must_use]
+	/// # Explicit Characters.
+	pub const fn chars(&self) -> &BTreeSet<char> { &self.chars }
+
+	#[must_use]
+	/// # Character Classes.
+	pub const fn classes(&self) -> CharSet { self.classes }
+
+	#[must_use]
+	/// # Edges.
+	pub const fn edges(&self) -> Edges { self.edges }
+
+	#[must_use]
+	/// # Collapse Character.
+	pub const fn collapse(&self) -> Option<char> { self.collapse }
+
+	#[must_use]
+	/// # Is Match?
+	///
+	/// Returns `true` if the character is in the explicit set or belongs to
+	/// one of the classes.
+	pub fn is_match(&self, c: char) -> bool {
+		self.classes.matches_char(c) || self.chars.contains(&c)
+	}
+}
+
+/// ## Trimming.
+impl Trimmer {
+	#[must_use]
+	/// # Trim.
+	///
+	/// Trim (and maybe collapse) a string slice according to the policy,
+	/// returning a borrowed slice whenever possible.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::Trimmer;
+	///
+	/// let trimmer = Trimmer::new().with_chars(['*']);
+	/// assert_eq!(trimmer.trim(" ** Hello * World! ** "), "Hello * World!");
+	/// ```
+	pub fn trim<'a>(&self, src: &'a str) -> Cow<'a, str> {
+		self.trim_observed(src, &mut ())
+	}
+
+	#[must_use]
+	/// # Trim (Observed).
+	///
+	/// Same as [`Trimmer::trim`], but reports any trimming and collapsing to
+	/// the [`TrimObserver`] along the way.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::{Trimmer, TrimStats};
+	///
+	/// let trimmer = Trimmer::new().with_collapse(Some('-'));
+	/// let mut stats = TrimStats::new();
+	/// assert_eq!(trimmer.trim_observed(" Hello \t World ", &mut stats), "Hello-World");
+	/// assert_eq!(stats.trimmed, 2);
+	/// assert_eq!(stats.runs, 1);
+	/// assert_eq!(stats.collapsed, 2);
+	/// ```
+	pub fn trim_observed<'a, O>(&self, src: &'a str, obs: &mut O) -> Cow<'a, str>
+	where O: TrimObserver + ?Sized {
+		let len = src.len();
+		let mut src = src;
+		if self.edges.start() { src = src.trim_start_matches(|c| self.is_match(c)); }
+		if self.edges.end() { src = src.trim_end_matches(|c| self.is_match(c)); }
+		if src.len() != len { obs.trimmed(len - src.len()); }
+
+		self.collapse.map_or(Cow::Borrowed(src), |rep| self.collapse_runs(src, rep, obs))
+	}
+
+	/// # Trim Mut.
+	///
+	/// Trim (and maybe collapse) a string in-place according to the policy.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::Trimmer;
+	///
+	/// let trimmer = Trimmer::new().with_collapse(Some(' '));
+	/// let mut s = String::from(" \tHello \n World! ");
+	/// trimmer.trim_mut(&mut s);
+	/// assert_eq!(s, "Hello World!");
+	/// ```
+	pub fn trim_mut(&self, src: &mut String) { self.trim_mut_observed(src, &mut ()); }
+
+	/// # Trim Mut (Observed).
+	///
+	/// Same as [`Trimmer::trim_mut`], but reports any trimming and collapsing
+	/// to the [`TrimObserver`] along the way.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::{Trimmer, TrimStats};
+	///
+	/// let trimmer = Trimmer::new();
+	/// let mut stats = TrimStats::new();
+	/// let mut s = String::from(" \tHello World! ");
+	/// trimmer.trim_mut_observed(&mut s, &mut stats);
+	/// assert_eq!(s, "Hello World!");
+	/// assert_eq!(stats.trimmed, 3);
+	/// ```
+	pub fn trim_mut_observed<O>(&self, src: &mut String, obs: &mut O)
+	where O: TrimObserver + ?Sized {
+		let len = src.len();
+		match self.edges {
+			Edges::Both => { src.trim_matches_mut(self); },
+			Edges::Start => { src.trim_start_matches_mut(self); },
+			Edges::End => { src.trim_end_matches_mut(self); },
+			Edges::None => {},
+		}
+		if src.len() != len { obs.trimmed(len - src.len()); }
+
+		if let Some(rep) = self.collapse {
+			let out = match self.collapse_runs(src, rep, obs) {
+				Cow::Owned(out) => Some(out),
+				Cow::Borrowed(_) => None,
+			};
+			if let Some(out) = out { *src = out; }
+		}
+	}
+
+	/// # Collapse Runs.
+	///
+	/// Replace each run of matching characters with a single `rep`, only
+	/// allocating if something actually needs to change.
+	fn collapse_runs<'a, O>(&self, src: &'a str, rep: char, obs: &mut O) -> Cow<'a, str>
+	where O: TrimObserver + ?Sized {
+		let mut out: Option<String> = None;
+		let mut run: Option<(usize, bool)> = None;
+		for (idx, c) in src.char_indices() {
+			if self.is_match(c) {
+				// Subsequent matches are dropped.
+				if let Some((_, changed)) = &mut run {
+					*changed = true;
+					out.get_or_insert_with(|| String::from(&src[..idx]));
+				}
+				// The first is kept, but might need to be replaced.
+				else {
+					run = Some((idx, c != rep));
+					if c == rep {
+						if let Some(out) = &mut out { out.push(c); }
+					}
+					else {
+						out.get_or_insert_with(|| String::from(&src[..idx])).push(rep);
+					}
+				}
+			}
+			else {
+				if let Some((start, true)) = run.take() {
+					obs.collapsed(idx - start, rep.len_utf8());
+				}
+				if let Some(out) = &mut out { out.push(c); }
+			}
+		}
+		if let Some((start, true)) = run {
+			obs.collapsed(src.len() - start, rep.len_utf8());
+		}
+
+		out.map_or(Cow::Borrowed(src), Cow::Owned)
+	}
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn t_trimmer() {
+		// The default should work just like str::trim.
+		let trimmer = Trimmer::default();
+		for v in ["", " ", "Hello", " \tHello World\n", "\u{3000}Björk\u{2003}"] {
+			assert_eq!(trimmer.trim(v), v.trim());
+			assert!(matches!(trimmer.trim(v), Cow::Borrowed(_)));
+
+			let mut s = String::from(v);
+			trimmer.trim_mut(&mut s);
+			assert_eq!(s, v.trim());
+		}
+
+		// Edges and chars.
+		let trimmer = Trimmer::new().with_chars(['-', '_']);
+		for (edges, expected) in [
+			(Edges::Both, "Hello - World"),
+			(Edges::Start, "Hello - World_ "),
+			(Edges::End, " -_Hello - World"),
+			(Edges::None, " -_Hello - World_ "),
+		] {
+			let trimmer = trimmer.clone().with_edges(edges);
+			assert_eq!(trimmer.trim(" -_Hello - World_ "), expected);
+
+			let mut s = String::from(" -_Hello - World_ ");
+			trimmer.trim_mut(&mut s);
+			assert_eq!(s, expected);
+		}
+
+		// No whitespace.
+		let trimmer = Trimmer::new().with_classes(CharSet::NONE).with_chars(['-']);
+		assert_eq!(trimmer.trim("-- Hello --"), " Hello ");
+		assert!(trimmer.is_match('-'));
+		assert!(! trimmer.is_match(' '));
+	}
+
+	#[test]
+	fn t_trimmer_collapse() {
+		let trimmer = Trimmer::new().with_collapse(Some(' '));
+		for (raw, expected, borrowed) in [
+			("", "", true),
+			("Hello World", "Hello World", true),
+			(" Hello World ", "Hello World", true),
+			("Hello  World", "Hello World", false),
+			("Hello\tWorld", "Hello World", false),
+			("Hello \n World \t Björk", "Hello World Björk", false),
+		] {
+			let out = trimmer.trim(raw);
+			assert_eq!(out, expected);
+			assert_eq!(matches!(out, Cow::Borrowed(_)), borrowed, "Borrowed mismatch: {raw:?}");
+
+			let mut s = String::from(raw);
+			trimmer.trim_mut(&mut s);
+			assert_eq!(s, expected);
+		}
+
+		// Collapse on the untrimmed edge.
+		let trimmer = trimmer.with_edges(Edges::End).with_chars(['-']);
+		assert_eq!(trimmer.trim(" - Hello--World!--"), " Hello World!");
+
+		// Custom replacement.
+		let trimmer = Trimmer::new().with_collapse(Some('_'));
+		assert_eq!(trimmer.trim(" Hello World\t \n!"), "Hello_World_!");
+	}
+
+	#[cfg(feature = "serde")]
+	#[test]
+	fn t_trimmer_serde() {
+		let trimmer: Trimmer = serde_json::from_str(r#"{
+			"chars": ["-", "_"],
+			"classes": ["whitespace", "control"],
+			"edges": "start",
+			"collapse": " "
+		}"#).expect("Deserialization failed.");
+		assert_eq!(
+			trimmer,
+			Trimmer::new()
+				.with_chars(['-', '_'])
+				.with_classes(CharSet::WHITESPACE | CharSet::CONTROL)
+				.with_edges(Edges::Start)
+				.with_collapse(Some(' ')),
+		);
+		assert_eq!(trimmer.trim("\0 --Hello__World!  "), "Hello World! ");
+
+		// Round trip.
+		let json = serde_json::to_string(&trimmer).expect("Serialization failed.");
+		assert_eq!(
+			json,
+			r#"{"chars":["-","_"],"classes":["whitespace","control"],"edges":"start","collapse":" "}"#,
+		);
+		let trimmer2: Trimmer = serde_json::from_str(&json).expect("Deserialization failed.");
+		assert_eq!(trimmer, trimmer2);
+
+		// Defaults.
+		let trimmer: Trimmer = serde_json::from_str("{}").expect("Deserialization failed.");
+		assert_eq!(trimmer, Trimmer::new());
+
+		// Alphanumeric shorthand.
+		let trimmer: Trimmer = serde_json::from_str(r#"{"classes": ["Alphanumeric"]}"#)
+			.expect("Deserialization failed.");
+		assert_eq!(trimmer.classes(), CharSet::ALPHANUMERIC);
+
+		// Bad class.
+		assert!(serde_json::from_str::<Trimmer>(r#"{"classes": ["nope"]}"#).is_err());
+
+		// Bad field.
+		assert!(serde_json::from_str::<Trimmer>(r#"{"nope": true}"#).is_err());
+	}
+}
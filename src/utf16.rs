@@ -0,0 +1,161 @@
+/*!
+# Trimothy: UTF-16.
+*/
+
+use alloc::{
+	borrow::Cow,
+	string::String,
+	vec::Vec,
+};
+
+
+
+#[must_use]
+/// # Trim and Normalize (UTF-16).
+///
+/// Trim the leading/trailing whitespace from a UTF-16 buffer — such as a
+/// JavaScript string received via `wasm-bindgen` — and compact/normalize
+/// spans of _inner_ whitespace to a single horizontal space, borrowing if
+/// the source is already clean, and allocating only if it is not.
+///
+/// As with string slices, "whitespace" means [`char::is_whitespace`].
+///
+/// Every whitespace character lives in the Basic Multilingual Plane, so
+/// surrogates — paired or otherwise — are never mistaken for whitespace, or
+/// split apart. Unpaired surrogates are passed through as-is; see
+/// [`trim_and_normalize_utf16_lossy`] if you'd rather replace them.
+///
+/// ## Examples
+///
+/// ```
+/// # extern crate alloc;
+/// # use alloc::borrow::Cow;
+/// use trimothy::trim_and_normalize_utf16;
+///
+/// let raw: Vec<u16> = " Hello\u{3000}\u{3000}🌎! ".encode_utf16().collect();
+/// let normal = trim_and_normalize_utf16(&raw);
+/// assert_eq!(String::from_utf16(&normal).unwrap(), "Hello 🌎!");
+///
+/// // Clean sources are borrowed.
+/// let raw: Vec<u16> = " Hello World ".encode_utf16().collect();
+/// assert!(matches!(trim_and_normalize_utf16(&raw), Cow::Borrowed(_)));
+///
+/// // Unpaired surrogates are left alone.
+/// let raw = [0x20, 0xD83C, 0x20, 0x20, 0x61];
+/// assert_eq!(*trim_and_normalize_utf16(&raw), [0xD83C, 0x20, 0x61]);
+/// ```
+pub fn trim_and_normalize_utf16(src: &[u16]) -> Cow<'_, [u16]> {
+	// Trim the edges.
+	let start = src.iter().position(|&u| ! is_ws(u)).unwrap_or(src.len());
+	let end = src.iter().rposition(|&u| ! is_ws(u)).map_or(start, |idx| idx + 1);
+	let src = &src[start..end];
+
+	// Look for anything that needs changing.
+	let mut ws = false;
+	let dirty = src.iter().position(|&u|
+		if is_ws(u) {
+			// Redundant or weird inner whitespace.
+			if ws || u != SPACE { true }
+			else {
+				ws = true;
+				false
+			}
+		}
+		else {
+			ws = false;
+			false
+		}
+	);
+
+	// It was fine!
+	let Some(dirty) = dirty else { return Cow::Borrowed(src); };
+
+	// Copy the good part, then process the remainder unit-by-unit.
+	let mut out = Vec::with_capacity(src.len());
+	out.extend_from_slice(&src[..dirty]);
+	for &u in &src[dirty..] {
+		if is_ws(u) {
+			if ! ws {
+				ws = true;
+				out.push(SPACE);
+			}
+		}
+		else {
+			ws = false;
+			out.push(u);
+		}
+	}
+
+	Cow::Owned(out)
+}
+
+#[must_use]
+/// # Trim and Normalize (UTF-16, Lossy).
+///
+/// Same as [`trim_and_normalize_utf16`], but decodes the result into a
+/// `String`, replacing any unpaired surrogates with `U+FFFD`.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::trim_and_normalize_utf16_lossy;
+///
+/// let raw = [0x20, 0xD83C, 0x20, 0x20, 0x61, 0x0A];
+/// assert_eq!(trim_and_normalize_utf16_lossy(&raw), "\u{FFFD} a");
+/// ```
+pub fn trim_and_normalize_utf16_lossy(src: &[u16]) -> String {
+	String::from_utf16_lossy(&trim_and_normalize_utf16(src))
+}
+
+
+
+/// # Horizontal Space.
+const SPACE: u16 = 0x20;
+
+#[inline]
+/// # Is Whitespace?
+///
+/// Returns `true` if the code unit is a (BMP) whitespace character.
+/// Surrogates fail the `char` conversion, so are never whitespace.
+fn is_ws(u: u16) -> bool {
+	char::from_u32(u32::from(u)).is_some_and(char::is_whitespace)
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::TrimNormal;
+
+	#[test]
+	fn t_trim_and_normalize_utf16() {
+		for raw in [
+			"", " ", "\u{3000}\n", "Hello", " Hello World ", "Hello  World",
+			"\u{2003}\u{2003}HEL\u{2003} LO\r\u{2003}", "H\tE  L\n\rL\x0CO ",
+			" 🌎\u{a0}🌍\u{85}🌏 ", "Björk\u{feff}",
+		] {
+			let expected = raw.trim_and_normalize();
+			let src: Vec<u16> = raw.encode_utf16().collect();
+			let out = trim_and_normalize_utf16(&src);
+			assert_eq!(String::from_utf16(&out).unwrap(), expected, "{raw:?}");
+			assert_eq!(
+				matches!(out, Cow::Borrowed(_)),
+				matches!(expected, Cow::Borrowed(_)),
+				"{raw:?}",
+			);
+			assert_eq!(trim_and_normalize_utf16_lossy(&src), expected, "{raw:?}");
+		}
+
+		// Unpaired surrogates.
+		for (raw, expected, lossy) in [
+			(&[0xD800][..], &[0xD800][..], "\u{FFFD}"),
+			(&[0x20, 0xDC00, 0x09], &[0xDC00], "\u{FFFD}"),
+			(&[0xD83C, 0x3000, 0x0A, 0xDF0E], &[0xD83C, 0x20, 0xDF0E], "\u{FFFD} \u{FFFD}"),
+			(&[0x61, 0x20, 0xD83C, 0xDF0E, 0xD83C], &[0x61, 0x20, 0xD83C, 0xDF0E, 0xD83C], "a 🌎\u{FFFD}"),
+		] {
+			assert_eq!(*trim_and_normalize_utf16(raw), *expected, "{raw:?}");
+			assert_eq!(trim_and_normalize_utf16_lossy(raw), lossy, "{raw:?}");
+		}
+	}
+}
@@ -0,0 +1,134 @@
+/*!
+# Trimothy: WebAssembly.
+
+The `#[wasm_bindgen]` attribute expands to `unsafe` glue code. Because a
+`forbid` can't be overridden, the crate root downgrades its `unsafe_code`
+lint to `deny` whenever the `capi`, `pyo3`, or `wasm` feature is enabled;
+this module then `allow`s it for the generated exports.
+*/
+
+#![allow(unsafe_code, reason = "The wasm32 glue generated by wasm_bindgen requires it.")]
+
+use alloc::string::{
+	String,
+	ToString,
+};
+use crate::{
+	NormalizedCompare,
+	Sanitizer,
+	TrimNormal,
+};
+use wasm_bindgen::prelude::wasm_bindgen;
+
+
+
+#[must_use]
+#[wasm_bindgen(js_name = trimAndNormalize)]
+/// # Trim and Normalize (JavaScript).
+///
+/// This is a `wasm_bindgen` export of [`TrimNormal::trim_and_normalize`],
+/// allowing the exact same normalization logic to be shared between Rust
+/// and JavaScript.
+///
+/// It is available to JavaScript as `trimAndNormalize`.
+///
+/// This function is only available when the `wasm` crate feature is
+/// enabled.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::trim_and_normalize_js;
+///
+/// assert_eq!(trim_and_normalize_js(" Hello\r\n\tWorld! "), "Hello World!");
+/// ```
+pub fn trim_and_normalize_js(src: &str) -> String {
+	src.trim_and_normalize().into_owned()
+}
+
+#[must_use]
+#[wasm_bindgen(js_name = normalizedEq)]
+/// # Normalized Equality (JavaScript).
+///
+/// This is a `wasm_bindgen` export of [`NormalizedCompare::normalized_eq`],
+/// allowing the exact same comparison logic to be shared between Rust and
+/// JavaScript.
+///
+/// It is available to JavaScript as `normalizedEq`.
+///
+/// This function is only available when the `wasm` crate feature is
+/// enabled.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::normalized_eq_js;
+///
+/// assert!(normalized_eq_js(" Hello\r\n\tWorld! ", "Hello World!"));
+/// assert!(! normalized_eq_js("Hello World!", "HelloWorld!"));
+/// ```
+pub fn normalized_eq_js(a: &str, b: &str) -> bool { a.normalized_eq(b) }
+
+#[wasm_bindgen(js_name = sanitizeText)]
+/// # Sanitize Text (JavaScript).
+///
+/// This is a `wasm_bindgen` export of [`Sanitizer::try_sanitize`], using
+/// the default [`Sanitizer`] configuration, allowing the exact same
+/// validation logic to be shared between Rust and JavaScript.
+///
+/// It is available to JavaScript as `sanitizeText`, which throws on
+/// failure.
+///
+/// This function is only available when the `wasm` crate feature is
+/// enabled.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::sanitize_text_js;
+///
+/// assert_eq!(sanitize_text_js(" Hello\r\n\tWorld! ").unwrap(), "Hello World!");
+/// assert_eq!(
+///     sanitize_text_js("Hello\u{202E}World").unwrap_err(),
+///     "bidi formatting character at byte 5",
+/// );
+/// ```
+///
+/// ## Errors
+///
+/// Returns the (stringified) [`SanitizeError`](crate::SanitizeError) if
+/// the source breaks any of the rules.
+pub fn sanitize_text_js(src: &str) -> Result<String, String> {
+	Sanitizer::new()
+		.try_sanitize(src)
+		.map(alloc::borrow::Cow::into_owned)
+		.map_err(|e| e.to_string())
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn t_js() {
+		for (raw, expected) in [
+			("", ""),
+			(" Hello World ", "Hello World"),
+			("\u{3000}Björk\n\nGuðmundsdóttir\t", "Björk Guðmundsdóttir"),
+		] {
+			assert_eq!(trim_and_normalize_js(raw), expected, "{raw:?}");
+			assert!(normalized_eq_js(raw, expected), "{raw:?}");
+			assert!(normalized_eq_js(expected, raw), "{raw:?}");
+		}
+		assert!(! normalized_eq_js("Hello", "Hello World"));
+
+		// Sanitization.
+		assert_eq!(
+			sanitize_text_js("\u{3000}Björk\n\nGuðmundsdóttir\t").unwrap(),
+			"Björk Guðmundsdóttir",
+		);
+		assert_eq!(sanitize_text_js("Hello\x07").unwrap_err(), "control character at byte 5");
+	}
+}
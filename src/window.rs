@@ -0,0 +1,285 @@
+/*!
+# Trimothy: Trimmed Windows.
+*/
+
+use alloc::{
+	string::String,
+	vec::Vec,
+};
+use core::ops::{
+	Deref,
+	Range,
+};
+use crate::{
+	pattern::MatchPattern,
+	TrimSliceMatches,
+};
+
+
+
+#[derive(Debug)]
+/// # Trimmed Window.
+///
+/// This is a lazily-trimmed view over a mutably-borrowed `String` or
+/// `Vec<u8>`. Trimming operations merely narrow the window — recording the
+/// head and tail offsets — without moving or reallocating any data, making
+/// them effectively free for workflows that only need to _read_ the result.
+///
+/// The window dereferences to the trimmed `str` or `[u8]` slice, and if
+/// the trimmed value needs to stick, [`TrimmedWindow::commit`] can be used
+/// to materialize it in the underlying buffer with a single move.
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::TrimmedWindow;
+///
+/// let mut buf = b"  \"Hello World!\"\n".to_vec();
+///
+/// let mut window = TrimmedWindow::new(&mut buf);
+/// window.trim().trim_matches(b'"');
+/// assert_eq!(&*window, b"Hello World!");
+/// assert_eq!(window.range(), 3..15);
+///
+/// // Make it permanent.
+/// window.commit();
+/// assert_eq!(buf, b"Hello World!");
+/// ```
+pub struct TrimmedWindow<'a, T> {
+	/// # Source.
+	src: &'a mut T,
+
+	/// # Start.
+	start: usize,
+
+	/// # End.
+	end: usize,
+}
+
+impl<'a, T: AsRef<[u8]>> TrimmedWindow<'a, T> {
+	#[must_use]
+	/// # New.
+	///
+	/// Open a window spanning the entire `String` or `Vec<u8>`.
+	pub fn new(src: &'a mut T) -> Self {
+		let end = src.as_ref().len();
+		Self { src, start: 0, end }
+	}
+
+	#[must_use]
+	/// # Range.
+	///
+	/// Return the byte range of the window relative to the underlying
+	/// buffer.
+	pub const fn range(&self) -> Range<usize> { self.start..self.end }
+}
+
+impl<'a> From<&'a mut String> for TrimmedWindow<'a, String> {
+	#[inline]
+	fn from(src: &'a mut String) -> Self { Self::new(src) }
+}
+
+impl<'a> From<&'a mut Vec<u8>> for TrimmedWindow<'a, Vec<u8>> {
+	#[inline]
+	fn from(src: &'a mut Vec<u8>) -> Self { Self::new(src) }
+}
+
+impl Deref for TrimmedWindow<'_, String> {
+	type Target = str;
+
+	#[inline]
+	fn deref(&self) -> &Self::Target { &self.src[self.start..self.end] }
+}
+
+impl Deref for TrimmedWindow<'_, Vec<u8>> {
+	type Target = [u8];
+
+	#[inline]
+	fn deref(&self) -> &Self::Target { &self.src[self.start..self.end] }
+}
+
+impl TrimmedWindow<'_, String> {
+	/// # Trim.
+	///
+	/// Narrow the window to exclude leading and trailing whitespace.
+	pub fn trim(&mut self) -> &mut Self {
+		self.trim_matches(char::is_whitespace)
+	}
+
+	/// # Trim Matches.
+	///
+	/// Narrow the window to exclude leading and trailing chars matching the
+	/// pattern.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::TrimmedWindow;
+	///
+	/// let mut s = String::from("--Björk--");
+	/// let mut window = TrimmedWindow::new(&mut s);
+	/// window.trim_matches('-');
+	/// assert_eq!(&*window, "Björk");
+	/// ```
+	pub fn trim_matches<P: MatchPattern<char>>(&mut self, pat: P) -> &mut Self {
+		self.trim_start_matches(pat).trim_end_matches(pat)
+	}
+
+	/// # Trim Start Matches.
+	///
+	/// Narrow the window to exclude leading chars matching the pattern.
+	pub fn trim_start_matches<P: MatchPattern<char>>(&mut self, pat: P) -> &mut Self {
+		let src = &self.src[self.start..self.end];
+		self.start = self.end - src.trim_start_matches(|c| pat.is_match(c)).len();
+		self
+	}
+
+	/// # Trim End Matches.
+	///
+	/// Narrow the window to exclude trailing chars matching the pattern.
+	pub fn trim_end_matches<P: MatchPattern<char>>(&mut self, pat: P) -> &mut Self {
+		let src = &self.src[self.start..self.end];
+		self.end = self.start + src.trim_end_matches(|c| pat.is_match(c)).len();
+		self
+	}
+
+	/// # Commit.
+	///
+	/// Apply the trimming to the underlying string.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::TrimmedWindow;
+	///
+	/// let mut s = String::from("  Hello World  ");
+	/// let mut window = TrimmedWindow::new(&mut s);
+	/// window.trim();
+	/// window.commit();
+	/// assert_eq!(s, "Hello World");
+	/// ```
+	pub fn commit(self) {
+		self.src.truncate(self.end);
+		if self.start != 0 { self.src.replace_range(..self.start, ""); }
+	}
+}
+
+impl TrimmedWindow<'_, Vec<u8>> {
+	/// # Trim.
+	///
+	/// Narrow the window to exclude leading and trailing (ASCII) whitespace.
+	pub fn trim(&mut self) -> &mut Self {
+		self.trim_matches(|b: u8| b.is_ascii_whitespace())
+	}
+
+	/// # Trim Matches.
+	///
+	/// Narrow the window to exclude leading and trailing bytes matching the
+	/// pattern.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::TrimmedWindow;
+	///
+	/// let mut v = b"--Hello--".to_vec();
+	/// let mut window = TrimmedWindow::new(&mut v);
+	/// window.trim_matches(b'-');
+	/// assert_eq!(&*window, b"Hello");
+	/// ```
+	pub fn trim_matches<P: MatchPattern<u8>>(&mut self, pat: P) -> &mut Self {
+		let (_, start, end) = self.src[self.start..self.end].trim_matches_counted(pat);
+		self.start += start;
+		self.end -= end;
+		self
+	}
+
+	/// # Trim Start Matches.
+	///
+	/// Narrow the window to exclude leading bytes matching the pattern.
+	pub fn trim_start_matches<P: MatchPattern<u8>>(&mut self, pat: P) -> &mut Self {
+		let src = &self.src[self.start..self.end];
+		self.start = self.end - src.trim_start_matches(pat).len();
+		self
+	}
+
+	/// # Trim End Matches.
+	///
+	/// Narrow the window to exclude trailing bytes matching the pattern.
+	pub fn trim_end_matches<P: MatchPattern<u8>>(&mut self, pat: P) -> &mut Self {
+		let src = &self.src[self.start..self.end];
+		self.end = self.start + src.trim_end_matches(pat).len();
+		self
+	}
+
+	/// # Commit.
+	///
+	/// Apply the trimming to the underlying vector.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use trimothy::TrimmedWindow;
+	///
+	/// let mut v = b"  Hello World  ".to_vec();
+	/// let mut window = TrimmedWindow::new(&mut v);
+	/// window.trim();
+	/// window.commit();
+	/// assert_eq!(v, b"Hello World");
+	/// ```
+	pub fn commit(self) {
+		if self.start != 0 { self.src.copy_within(self.start..self.end, 0); }
+		self.src.truncate(self.end - self.start);
+	}
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn t_trimmed_window() {
+		for raw in ["", " ", "  \t", "Hello", " Hello ", "\"Hello\"", " \" Hello World \" "] {
+			let expected = raw.trim().trim_matches('"');
+
+			// Strings.
+			let mut s = String::from(raw);
+			let mut window = TrimmedWindow::from(&mut s);
+			window.trim().trim_matches('"');
+			assert_eq!(&*window, expected, "{raw:?}");
+			assert_eq!(&raw[window.range()], expected, "{raw:?}");
+			window.commit();
+			assert_eq!(s, expected, "{raw:?}");
+
+			// Bytes.
+			let mut v = raw.as_bytes().to_vec();
+			let mut window = TrimmedWindow::from(&mut v);
+			window.trim().trim_matches(b'"');
+			assert_eq!(&*window, expected.as_bytes(), "{raw:?}");
+			window.commit();
+			assert_eq!(v, expected.as_bytes(), "{raw:?}");
+
+			// One side at a time.
+			let expected = raw.trim_start();
+			let mut s = String::from(raw);
+			let mut window = TrimmedWindow::new(&mut s);
+			window.trim_start_matches(char::is_whitespace);
+			assert_eq!(&*window, expected, "{raw:?}");
+
+			let mut v = raw.as_bytes().to_vec();
+			let mut window = TrimmedWindow::new(&mut v);
+			window.trim_end_matches(b' ').trim_start_matches(b' ');
+			assert_eq!(&*window, raw.trim_matches(' ').as_bytes(), "{raw:?}");
+		}
+
+		// Unicode.
+		let mut s = String::from("\u{3000}Björk\u{3000}");
+		let mut window = TrimmedWindow::new(&mut s);
+		window.trim();
+		assert_eq!(window.range(), 3..9);
+		window.commit();
+		assert_eq!(s, "Björk");
+	}
+}
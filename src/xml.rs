@@ -0,0 +1,149 @@
+/*!
+# Trimothy: XML.
+*/
+
+use alloc::{
+	borrow::Cow,
+	string::String,
+};
+
+
+
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, PartialEq)]
+/// # XML Attribute Type.
+///
+/// This determines which of the XML 1.0 attribute-value normalization rules
+/// [`xml_attr_normalize`] applies.
+pub enum XmlAttrType {
+	#[default]
+	/// # CDATA.
+	///
+	/// Each whitespace character — or `\r\n` pair — is replaced with a
+	/// single space, but nothing is trimmed or collapsed.
+	///
+	/// This is the type of any attribute not declared otherwise.
+	Cdata,
+
+	/// # Tokenized (Non-CDATA).
+	///
+	/// Same as [`XmlAttrType::Cdata`], but leading and trailing spaces are
+	/// then trimmed, and inner runs collapsed to a single space. This
+	/// applies to `ID`, `IDREF(S)`, `ENTITY`/`ENTITIES`, `NMTOKEN(S)`, and
+	/// enumerated attributes.
+	Tokenized,
+}
+
+
+
+#[must_use]
+/// # XML Attribute-Value Normalization.
+///
+/// Apply the whitespace rules of [XML 1.0 §3.3.3](https://www.w3.org/TR/xml/#AVNormalize)
+/// to a raw attribute value, borrowing if nothing needs to change.
+///
+/// Only the four XML whitespace characters — space, tab, line feed, and
+/// carriage return — are affected; other Unicode whitespace, like
+/// `\u{A0}`, is significant in XML and left alone.
+///
+/// Character and entity references are _not_ expanded; if the value has
+/// any, they should be resolved first. (Note that a reference like `&#9;`
+/// expands to a literal tab that the spec says should be kept, so in that
+/// case, normalize before expanding.)
+///
+/// ## Examples
+///
+/// ```
+/// use trimothy::{xml_attr_normalize, XmlAttrType};
+///
+/// let raw = "  Hello\r\n\tWorld  ";
+/// assert_eq!(xml_attr_normalize(raw, XmlAttrType::Cdata), "  Hello  World  ");
+/// assert_eq!(xml_attr_normalize(raw, XmlAttrType::Tokenized), "Hello World");
+///
+/// // Non-XML whitespace is preserved.
+/// assert_eq!(
+///     xml_attr_normalize("\tHello\u{A0}World\n", XmlAttrType::Tokenized),
+///     "Hello\u{A0}World",
+/// );
+/// ```
+pub fn xml_attr_normalize(src: &str, kind: XmlAttrType) -> Cow<'_, str> {
+	match kind {
+		XmlAttrType::Cdata => normalize_cdata(src),
+		XmlAttrType::Tokenized => normalize_tokenized(src),
+	}
+}
+
+
+
+/// # Is XML Whitespace?
+const fn is_xml_ws(c: char) -> bool { matches!(c, ' ' | '\t' | '\n' | '\r') }
+
+/// # Normalize (CDATA).
+fn normalize_cdata(src: &str) -> Cow<'_, str> {
+	if ! src.contains(['\t', '\n', '\r']) { return Cow::Borrowed(src); }
+
+	let mut out = String::with_capacity(src.len());
+	let mut chars = src.chars().peekable();
+	while let Some(c) = chars.next() {
+		if is_xml_ws(c) {
+			// Line-ending pairs count as one.
+			if c == '\r' { chars.next_if_eq(&'\n'); }
+			out.push(' ');
+		}
+		else { out.push(c); }
+	}
+	Cow::Owned(out)
+}
+
+/// # Normalize (Tokenized).
+fn normalize_tokenized(src: &str) -> Cow<'_, str> {
+	let src = src.trim_matches(is_xml_ws);
+	if ! src.contains(['\t', '\n', '\r']) && ! src.contains("  ") {
+		return Cow::Borrowed(src);
+	}
+
+	let mut out = String::with_capacity(src.len());
+	for word in src.split(is_xml_ws).filter(|w| ! w.is_empty()) {
+		if ! out.is_empty() { out.push(' '); }
+		out.push_str(word);
+	}
+	Cow::Owned(out)
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn t_xml_attr_normalize() {
+		for (raw, cdata, tokenized) in [
+			("", "", ""),
+			(" ", " ", ""),
+			("Hello", "Hello", "Hello"),
+			("Hello World", "Hello World", "Hello World"),
+			("  Hello  World  ", "  Hello  World  ", "Hello World"),
+			("\tHello\nWorld\r", " Hello World ", "Hello World"),
+			("Hello\r\nWorld", "Hello World", "Hello World"),
+			("Hello\n\rWorld", "Hello  World", "Hello World"),
+			("Hello\r\r\nWorld", "Hello  World", "Hello World"),
+			("Hello\u{A0}\u{3000}World", "Hello\u{A0}\u{3000}World", "Hello\u{A0}\u{3000}World"),
+			(" \u{A0} ", " \u{A0} ", "\u{A0}"),
+		] {
+			let out = xml_attr_normalize(raw, XmlAttrType::Cdata);
+			assert_eq!(out, cdata, "{raw:?}");
+			assert_eq!(matches!(out, Cow::Borrowed(_)), raw == cdata, "{raw:?}");
+
+			let out = xml_attr_normalize(raw, XmlAttrType::Tokenized);
+			assert_eq!(out, tokenized, "{raw:?}");
+			assert_eq!(
+				matches!(out, Cow::Borrowed(_)),
+				raw.trim_matches(is_xml_ws) == tokenized,
+				"{raw:?}",
+			);
+		}
+
+		// Default.
+		assert_eq!(XmlAttrType::default(), XmlAttrType::Cdata);
+	}
+}